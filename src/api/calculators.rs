@@ -0,0 +1,375 @@
+//! Every calculator of the site as an endpoint: the inputs it takes, the outputs it
+//! returns, and its evaluation from a JSON request.
+//!
+//! Values are given as a plain number in SI units, or as `{ "value": 2, "unit": "mm" }`.
+//! Results are always returned in SI units.
+
+use crate::api::types::{ApiError, ApiValue, CalculationRequest, CalculationResponse, PartRequest};
+use crate::calculations::errors::CalculationError;
+use crate::cards::area::CALCULATORS;
+use crate::cards::calculator::{
+    AnyQuantity, AnyUnit, Calculator, CalculatorInput, Measure, Parts, SolveMode, CARDS,
+};
+use std::collections::BTreeMap;
+
+impl ApiValue {
+    /// A result in the SI unit of its measure.
+    fn si(quantity: AnyQuantity, measure: Measure) -> Self {
+        let value = quantity.si();
+        match AnyUnit::si(measure).symbol() {
+            Some(unit) => ApiValue::Quantity {
+                value,
+                unit: unit.to_string(),
+            },
+            None => ApiValue::Number(value),
+        }
+    }
+
+    /// The quantity of an input, checked against its rule.
+    fn quantity(&self, input: &CalculatorInput) -> Result<AnyQuantity, CalculationError> {
+        let quantity = match self {
+            ApiValue::Number(value) => AnyUnit::si(input.measure).quantity(*value),
+            ApiValue::Quantity { value, unit } => {
+                AnyUnit::parse(input.measure, input.symbol, unit)?.quantity(*value)
+            }
+        };
+        input.rule.check(input.symbol, quantity.si())?;
+        Ok(quantity)
+    }
+}
+
+impl ApiError {
+    pub fn new(error: &str, parameter: Option<&str>, message: impl Into<String>) -> Self {
+        ApiError {
+            error: error.to_string(),
+            parameter: parameter.map(str::to_string),
+            message: message.into(),
+        }
+    }
+
+    /// The kinds of error a calculation can fail with, as `error` names them.
+    pub const KINDS: [&'static str; 10] = [
+        "unknown_calculator",
+        "unknown_mode",
+        "unknown_kind",
+        "missing_input",
+        "invalid_request",
+        "non_positive",
+        "out_of_domain",
+        "inconsistent",
+        "not_finite",
+        "unit_mismatch",
+    ];
+
+    /// HTTP status of the error.
+    pub fn status(&self) -> u16 {
+        match self.error.as_str() {
+            "unknown_calculator" => 404,
+            "invalid_request" => 400,
+            _ => 422,
+        }
+    }
+
+    fn missing_input(symbol: &str) -> Self {
+        ApiError::new(
+            "missing_input",
+            Some(symbol),
+            format!("{symbol} is missing"),
+        )
+    }
+}
+
+impl From<CalculationError> for ApiError {
+    fn from(err: CalculationError) -> Self {
+        let error = err.i18n_key().trim_start_matches("error_");
+        ApiError::new(error, Some(err.parameter()), err.to_string())
+    }
+}
+
+/// Reads the value of every input from the values of a request, by symbol.
+fn read_inputs<'a>(
+    inputs: impl IntoIterator<Item = &'a CalculatorInput>,
+    values: &BTreeMap<String, ApiValue>,
+) -> Result<Vec<AnyQuantity>, ApiError> {
+    inputs
+        .into_iter()
+        .map(|input| match values.get(input.symbol) {
+            Some(value) => Ok(value.quantity(input)?),
+            None => Err(ApiError::missing_input(input.symbol)),
+        })
+        .collect()
+}
+
+/// An output of an endpoint.
+#[derive(Clone, Copy)]
+pub struct ApiOutput {
+    pub symbol: &'static str,
+    pub measure: Measure,
+}
+
+/// A calculator that can be called over HTTP.
+#[derive(Clone)]
+pub struct Endpoint {
+    /// Id of the endpoint in its path, such as `rectangle` or `section-i`.
+    pub id: String,
+    pub calculator: &'static Calculator,
+}
+
+impl Endpoint {
+    /// Key of the name of the calculation in `I18n`.
+    pub fn title_key(&self) -> &'static str {
+        self.calculator.title_key
+    }
+
+    /// Index of the solve mode with the given id.
+    fn mode(&self, id: &str) -> Result<usize, ApiError> {
+        self.calculator
+            .solve_modes
+            .iter()
+            .position(|mode: &SolveMode| mode.id == id)
+            .ok_or_else(|| ApiError::new("unknown_mode", Some("mode"), format!("no mode {id}")))
+    }
+
+    /// The results of the endpoint: the inputs any solve mode finds, then the
+    /// labelled outputs of the calculator.
+    pub fn outputs(&self) -> Vec<ApiOutput> {
+        let calculator = self.calculator;
+        let unknowns = calculator
+            .solve_modes
+            .iter()
+            .flat_map(|mode| mode.unknowns)
+            .filter_map(|symbol| calculator.inputs.get(calculator.input_index(symbol)?));
+        let mut outputs: Vec<ApiOutput> = Vec::new();
+        for input in unknowns {
+            if !outputs.iter().any(|output| output.symbol == input.symbol) {
+                outputs.push(ApiOutput {
+                    symbol: input.symbol,
+                    measure: input.measure,
+                });
+            }
+        }
+        outputs.extend(
+            calculator
+                .outputs
+                .iter()
+                .filter(|output| output.label_key.is_some())
+                .map(|output| ApiOutput {
+                    symbol: output.symbol,
+                    measure: output.measure,
+                }),
+        );
+        outputs
+    }
+
+    pub fn calculate(&self, request: &CalculationRequest) -> Result<CalculationResponse, ApiError> {
+        let calculator = self.calculator;
+        let mode = request
+            .mode
+            .as_deref()
+            .map(|id| self.mode(id))
+            .transpose()?;
+        let values = match calculator.parts {
+            Some(parts) => {
+                let values = request.parts.iter().map(|part| part_values(parts, part));
+                values.collect::<Result<Vec<_>, _>>()?.concat()
+            }
+            None => read_inputs(calculator.entered_inputs(mode), &request.inputs)?,
+        };
+        let solution = calculator.solve(mode, values)?;
+
+        let unknowns = mode.map_or(&[][..], |index| calculator.solve_modes[index].unknowns);
+        let unknowns = unknowns.iter().filter_map(|symbol| {
+            let index = calculator.input_index(symbol)?;
+            let value = ApiValue::si(solution.dimensions[index], calculator.inputs[index].measure);
+            Some((symbol.to_string(), value))
+        });
+        let outputs = calculator
+            .outputs
+            .iter()
+            .zip(&solution.outputs)
+            .filter(|(output, _)| output.label_key.is_some())
+            .map(|(output, quantity)| {
+                (
+                    output.symbol.to_string(),
+                    ApiValue::si(*quantity, output.measure),
+                )
+            });
+        Ok(CalculationResponse {
+            calculator: self.id.clone(),
+            outputs: unknowns.chain(outputs).collect(),
+        })
+    }
+}
+
+/// The values of a part of a composite section, as [`Parts`] lays them out.
+fn part_values(parts: &Parts, part: &PartRequest) -> Result<Vec<AnyQuantity>, ApiError> {
+    let kind = parts
+        .kinds
+        .iter()
+        .position(|kind| kind.id == part.kind)
+        .ok_or_else(|| {
+            ApiError::new(
+                "unknown_kind",
+                Some("kind"),
+                format!("no section {}", part.kind),
+            )
+        })?;
+    let mut values = vec![
+        AnyQuantity::Count(kind as f64),
+        AnyQuantity::Count(if part.hole { 1.0 } else { 0.0 }),
+    ];
+    values.extend(read_inputs(parts.kinds[kind].inputs, &part.inputs)?);
+    for (input, value) in parts.placement.iter().zip([&part.x, &part.y]) {
+        values.push(value.quantity(input)?);
+    }
+    Ok(values)
+}
+
+/// Path under which every endpoint is served, followed by its id.
+pub const PREFIX: &str = "/api/v1/";
+
+/// All endpoints: every calculator of every card, with the id of the card in front of
+/// the id of its kind when the card has several.
+pub fn endpoints() -> Vec<Endpoint> {
+    CARDS
+        .iter()
+        .flat_map(|group| {
+            group.kinds.iter().map(move |calculator| {
+                let id = if group.kinds.len() == 1 {
+                    calculator.id.to_string()
+                } else {
+                    format!("{}-{}", group.card.id, calculator.id)
+                };
+                Endpoint { id, calculator }
+            })
+        })
+        .collect()
+}
+
+fn unknown_calculator(id: &str) -> ApiError {
+    ApiError::new("unknown_calculator", None, format!("no calculator {id}"))
+}
+
+/// Calculates the endpoint with the given id.
+pub fn calculate(id: &str, request: &CalculationRequest) -> Result<CalculationResponse, ApiError> {
+    endpoints()
+        .into_iter()
+        .find(|endpoint| endpoint.id == id)
+        .ok_or_else(|| unknown_calculator(id))?
+        .calculate(request)
+}
+
+/// Calculates an area calculator, which must be one of [`CALCULATORS`].
+pub fn calculate_area_by_id(
+    id: &str,
+    request: &CalculationRequest,
+) -> Result<CalculationResponse, ApiError> {
+    let calculator = CALCULATORS
+        .into_iter()
+        .find(|calculator| calculator.id == id)
+        .ok_or_else(|| unknown_calculator(id))?;
+    Endpoint {
+        id: id.to_string(),
+        calculator,
+    }
+    .calculate(request)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(json: &str) -> CalculationRequest {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn si(response: &CalculationResponse, symbol: &str) -> f64 {
+        match &response.outputs[symbol] {
+            ApiValue::Number(value) | ApiValue::Quantity { value, .. } => *value,
+        }
+    }
+
+    #[test]
+    fn test_area() {
+        let rectangle = request(r#"{"inputs": {"w": {"value": 200, "unit": "cm"}, "h": 3}}"#);
+        let response = calculate("rectangle", &rectangle).unwrap();
+        assert_eq!(
+            response.outputs["A"],
+            ApiValue::Quantity {
+                value: 6.0,
+                unit: "m²".to_string()
+            }
+        );
+
+        let width = request(r#"{"mode": "width", "inputs": {"A": 6, "h": 3}}"#);
+        let response = calculate_area_by_id("rectangle", &width).unwrap();
+        assert_eq!(si(&response, "w"), 2.0);
+
+        let errors = [
+            ("rectangle", r#"{"inputs": {"w": 2}}"#, "missing_input"),
+            (
+                "rectangle",
+                r#"{"inputs": {"w": -2, "h": 3}}"#,
+                "non_positive",
+            ),
+            (
+                "rectangle",
+                r#"{"inputs": {"w": {"value": 2, "unit": "kg"}, "h": 3}}"#,
+                "unit_mismatch",
+            ),
+            ("rectangle", r#"{"mode": "depth"}"#, "unknown_mode"),
+            ("hexagon", "{}", "unknown_calculator"),
+        ];
+        for (id, json, error) in errors {
+            let err = calculate(id, &request(json)).unwrap_err();
+            assert_eq!(err.error, error, "{json}");
+            assert!(ApiError::KINDS.contains(&err.error.as_str()));
+        }
+        assert!(calculate_area_by_id("section-i", &CalculationRequest::default()).is_err());
+    }
+
+    #[test]
+    fn test_section_solid_composite() {
+        let plate = request(r#"{"inputs": {"b": 0.1, "h": 0.2}}"#);
+        let section = calculate("section-rectangle", &plate).unwrap();
+        assert!((si(&section, "I_x") - 0.1 * 0.2f64.powi(3) / 12.0).abs() < 1e-12);
+
+        let cube = request(r#"{"inputs": {"l": 2, "w": 2, "h": 2, "ρ": 1000}}"#);
+        let solid = calculate("solid-cuboid", &cube).unwrap();
+        assert_eq!(si(&solid, "V"), 8.0);
+        assert_eq!(si(&solid, "m"), 8000.0);
+        let without_density = request(r#"{"inputs": {"l": 2, "w": 2, "h": 2}}"#);
+        let err = calculate("solid-cuboid", &without_density).unwrap_err();
+        assert_eq!(err.parameter.as_deref(), Some("ρ"));
+
+        let plates = request(
+            r#"{"parts": [
+                {"kind": "rectangle", "x": 0, "y": 0, "inputs": {"b": 0.1, "h": 0.2}},
+                {"kind": "rectangle", "x": 0.1, "y": 0, "inputs": {"b": 0.1, "h": 0.2}}
+            ]}"#,
+        );
+        let composite = calculate("composite", &plates).unwrap();
+        assert!((si(&composite, "A") - 0.04).abs() < 1e-12);
+        assert!((si(&composite, "c_x") - 0.1).abs() < 1e-12);
+        let hexagon = request(r#"{"parts": [{"kind": "hexagon", "x": 0, "y": 0, "inputs": {}}]}"#);
+        assert_eq!(
+            calculate("composite", &hexagon).unwrap_err().error,
+            "unknown_kind"
+        );
+    }
+
+    #[test]
+    fn test_endpoints() {
+        let endpoints = endpoints();
+        let mut ids: Vec<&str> = endpoints
+            .iter()
+            .map(|endpoint| endpoint.id.as_str())
+            .collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), endpoints.len());
+        assert!(ids.contains(&"section-i") && ids.contains(&"solid-cuboid"));
+        let error = ApiError::new("missing_input", Some("w"), "w is missing");
+        assert_eq!(error.to_string().parse::<ApiError>().unwrap(), error);
+    }
+}
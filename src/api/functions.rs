@@ -0,0 +1,45 @@
+//! Server functions for the calculations, so the same Rust code can be called over
+//! HTTP.
+
+use crate::api::types::{ApiError, CalculationRequest, CalculationResponse};
+use leptos::prelude::*;
+use leptos::server_fn::codec::Json;
+
+/// Calculates one of the area calculators, by its id.
+#[server(prefix = "/api/fn", input = Json)]
+pub async fn calculate_area(
+    calculator: String,
+    request: CalculationRequest,
+) -> Result<CalculationResponse, ServerFnError<ApiError>> {
+    crate::api::calculators::calculate_area_by_id(&calculator, &request)
+        .map_err(ServerFnError::WrappedServerError)
+}
+
+/// Calculates the properties of a section, by the id of its kind.
+#[server(prefix = "/api/fn", input = Json)]
+pub async fn calculate_section(
+    kind: String,
+    request: CalculationRequest,
+) -> Result<CalculationResponse, ServerFnError<ApiError>> {
+    crate::api::calculators::calculate(&format!("section-{kind}"), &request)
+        .map_err(ServerFnError::WrappedServerError)
+}
+
+/// Calculates the volume, surface area and mass of a solid, by the id of its kind.
+#[server(prefix = "/api/fn", input = Json)]
+pub async fn calculate_solid(
+    kind: String,
+    request: CalculationRequest,
+) -> Result<CalculationResponse, ServerFnError<ApiError>> {
+    crate::api::calculators::calculate(&format!("solid-{kind}"), &request)
+        .map_err(ServerFnError::WrappedServerError)
+}
+
+/// Calculates the properties of a section composed of the parts of the request.
+#[server(prefix = "/api/fn", input = Json)]
+pub async fn calculate_composite(
+    request: CalculationRequest,
+) -> Result<CalculationResponse, ServerFnError<ApiError>> {
+    crate::api::calculators::calculate("composite", &request)
+        .map_err(ServerFnError::WrappedServerError)
+}
@@ -0,0 +1,9 @@
+//! Access to the calculations over HTTP, served by the server only: Leptos server
+//! functions, and a plain JSON REST endpoint at `/api/v1/<calculator>` for scripts,
+//! described by an OpenAPI document.
+
+pub mod calculators;
+pub mod functions;
+pub mod openapi;
+pub mod rest;
+pub mod types;
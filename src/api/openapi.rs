@@ -0,0 +1,360 @@
+//! OpenAPI 3 description of the REST API, generated from the endpoints so it can't
+//! drift from what they accept.
+
+use crate::api::calculators::{endpoints, Endpoint, PREFIX};
+use crate::api::types::ApiError;
+use crate::cards::calculator::{AnyUnit, Calculator, CalculatorInput, Measure, Parts};
+use crate::cards::validation::Rule;
+use crate::locales::i18n::I18n;
+use serde_json::{json, Map, Value};
+
+/// Path the document is served at.
+pub const PATH: &str = "/api/openapi.json";
+
+/// Measures that have units, in the order of their schemas.
+const MEASURES: [Measure; 7] = [
+    Measure::Length,
+    Measure::Area,
+    Measure::Volume,
+    Measure::SecondMomentOfArea,
+    Measure::Angle,
+    Measure::Mass,
+    Measure::Density,
+];
+
+/// The OpenAPI document of every endpoint, described in English.
+pub fn document() -> Value {
+    let i18n = I18n::new("en");
+    let paths: Map<String, Value> = endpoints()
+        .iter()
+        .map(|endpoint| {
+            let path = format!("{PREFIX}{}", endpoint.id);
+            (path, json!({ "post": operation(endpoint, &i18n) }))
+        })
+        .collect();
+
+    let mut schemas: Map<String, Value> = MEASURES
+        .into_iter()
+        .map(|measure| (quantity_name(measure), quantity_schema(measure)))
+        .collect();
+    schemas.insert("ApiError".to_string(), error_schema());
+
+    json!({
+        "openapi": "3.0.3",
+        "info": {
+            "title": "engineertools",
+            "version": env!("CARGO_PKG_VERSION"),
+            "description": "The calculations of engineertools. Values are a number in SI \
+                units, or an object with a value and the symbol of its unit. Constraints \
+                apply to the value in SI units. Results are always in SI units.",
+        },
+        "paths": paths,
+        "components": { "schemas": schemas },
+    })
+}
+
+fn operation(endpoint: &Endpoint, i18n: &I18n) -> Value {
+    let calculator = endpoint.calculator;
+    let request = match calculator.parts {
+        Some(parts) => json!({
+            "type": "object",
+            "required": ["parts"],
+            "properties": {
+                "parts": { "type": "array", "items": parts_schema(parts, i18n) },
+            },
+        }),
+        None if calculator.solve_modes.is_empty() => mode_schema(calculator, None, i18n),
+        None => {
+            let dimensions = mode_schema(calculator, None, i18n);
+            let solve_modes = (0..calculator.solve_modes.len())
+                .map(|index| mode_schema(calculator, Some(index), i18n));
+            json!({ "oneOf": std::iter::once(dimensions).chain(solve_modes).collect::<Vec<_>>() })
+        }
+    };
+    let outputs: Map<String, Value> = endpoint
+        .outputs()
+        .iter()
+        .map(|output| {
+            (
+                output.symbol.to_string(),
+                value_schema(output.measure, None),
+            )
+        })
+        .collect();
+    let response = json!({
+        "type": "object",
+        "required": ["calculator", "outputs"],
+        "properties": {
+            "calculator": { "type": "string", "enum": [endpoint.id] },
+            "outputs": { "type": "object", "properties": outputs },
+        },
+    });
+    json!({
+        "operationId": endpoint.id,
+        "summary": i18n.t(endpoint.title_key()),
+        "requestBody": {
+            "required": true,
+            "content": { "application/json": { "schema": request } },
+        },
+        "responses": {
+            "200": {
+                "description": "The results, in SI units.",
+                "content": { "application/json": { "schema": response } },
+            },
+            "400": error_response("The body is not a calculation request."),
+            "405": error_response("The method is not POST."),
+            "413": error_response("The body is larger than 64 KiB."),
+            "422": error_response("The inputs can't be calculated."),
+        },
+    })
+}
+
+fn error_response(description: &str) -> Value {
+    json!({
+        "description": description,
+        "content": {
+            "application/json": { "schema": { "$ref": "#/components/schemas/ApiError" } },
+        },
+    })
+}
+
+/// Request of a solve mode, or of the dimensions without one: its mode and the inputs
+/// it needs.
+fn mode_schema(calculator: &Calculator, mode: Option<usize>, i18n: &I18n) -> Value {
+    let mut properties = Map::new();
+    let mut required = vec!["inputs"];
+    if let Some(index) = mode {
+        let id = calculator.solve_modes[index].id;
+        properties.insert(
+            "mode".to_string(),
+            json!({ "type": "string", "enum": [id] }),
+        );
+        required.push("mode");
+    }
+    // The constraints are checked between the dimensions, which a solve mode finds
+    let constraints = match mode {
+        Some(_) => &[][..],
+        None => calculator.constraints,
+    };
+    let inputs = inputs_schema(calculator, mode, constraints, i18n);
+    properties.insert("inputs".to_string(), inputs);
+    json!({ "type": "object", "required": required, "properties": properties })
+}
+
+/// The inputs of a calculator in a solve mode, all required, with the constraints
+/// between them.
+fn inputs_schema(
+    calculator: &Calculator,
+    mode: Option<usize>,
+    constraints: &[&str],
+    i18n: &I18n,
+) -> Value {
+    let inputs = calculator.entered_inputs(mode);
+    let properties: Map<String, Value> = inputs
+        .iter()
+        .map(|input| {
+            let description = variable_key(calculator, mode, input.symbol).map(|key| i18n.t(key));
+            (
+                input.symbol.to_string(),
+                input_schema(input, description.as_deref()),
+            )
+        })
+        .collect();
+    let required: Vec<&str> = inputs.iter().map(|input| input.symbol).collect();
+    let mut schema = json!({ "type": "object", "required": required, "properties": properties });
+    if !constraints.is_empty() {
+        merge(
+            &mut schema,
+            json!({
+                "description": format!("The inputs must satisfy {}.", constraints.join(", ")),
+                "x-constraints": constraints,
+            }),
+        );
+    }
+    schema
+}
+
+/// A part of a calculator with parts: one of its kinds with the inputs of that kind,
+/// and its placement.
+fn parts_schema(parts: &Parts, i18n: &I18n) -> Value {
+    let kinds: Vec<Value> = parts
+        .kinds
+        .iter()
+        .map(|kind| {
+            let mut properties = Map::new();
+            properties.insert(
+                "kind".to_string(),
+                json!({ "type": "string", "enum": [kind.id] }),
+            );
+            for input in parts.placement {
+                properties.insert(input.symbol.to_string(), input_schema(input, None));
+            }
+            properties.insert(
+                "hole".to_string(),
+                json!({ "type": "boolean", "default": false }),
+            );
+            properties.insert(
+                "inputs".to_string(),
+                inputs_schema(kind, None, kind.constraints, i18n),
+            );
+            let mut required = vec!["kind", "inputs"];
+            required.extend(parts.placement.iter().map(|input| input.symbol));
+            json!({
+                "type": "object",
+                "title": i18n.t(kind.title_key),
+                "required": required,
+                "properties": properties,
+            })
+        })
+        .collect();
+    json!({ "oneOf": kinds })
+}
+
+/// Key of the explanation of an input, from the variables of the calculator or its
+/// solve mode.
+fn variable_key(
+    calculator: &Calculator,
+    mode: Option<usize>,
+    symbol: &str,
+) -> Option<&'static str> {
+    let mode_variables = mode.map_or(&[][..], |index| calculator.solve_modes[index].variables);
+    calculator
+        .variables
+        .iter()
+        .chain(mode_variables)
+        .find(|(symbols, _)| symbols.split(", ").any(|variable| variable == symbol))
+        .map(|(_, key)| *key)
+}
+
+/// An input: a number in SI units with the constraints of its rule, or a quantity.
+fn input_schema(input: &CalculatorInput, description: Option<&str>) -> Value {
+    let mut number = json!({ "type": "number" });
+    let constraints = match input.rule {
+        Rule::Finite => json!({}),
+        Rule::Positive => json!({ "minimum": 0, "exclusiveMinimum": true }),
+        Rule::NonNegative => json!({ "minimum": 0 }),
+        Rule::Between(min, max) => json!({
+            "minimum": min,
+            "exclusiveMinimum": true,
+            "maximum": max,
+            "exclusiveMaximum": true,
+        }),
+        Rule::Count { min, max } => json!({ "type": "integer", "minimum": min, "maximum": max }),
+    };
+    merge(&mut number, constraints);
+    let mut schema = value_schema(input.measure, Some(number));
+    if let Some(description) = description {
+        merge(&mut schema, json!({ "description": description }));
+    }
+    schema
+}
+
+/// A value of a measure: a number in SI units, or a quantity in one of its units.
+fn value_schema(measure: Measure, number: Option<Value>) -> Value {
+    let number = number.unwrap_or_else(|| json!({ "type": "number" }));
+    match measure {
+        Measure::Count => number,
+        measure => json!({
+            "oneOf": [number, { "$ref": format!("#/components/schemas/{}", quantity_name(measure)) }],
+        }),
+    }
+}
+
+fn quantity_name(measure: Measure) -> String {
+    format!("{measure:?}")
+}
+
+fn quantity_schema(measure: Measure) -> Value {
+    let units: Vec<&str> = AnyUnit::all(measure)
+        .into_iter()
+        .filter_map(AnyUnit::symbol)
+        .collect();
+    json!({
+        "type": "object",
+        "required": ["value", "unit"],
+        "properties": {
+            "value": { "type": "number" },
+            "unit": {
+                "type": "string",
+                "enum": units,
+                "default": AnyUnit::si(measure).symbol(),
+            },
+        },
+    })
+}
+
+fn error_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["error", "message"],
+        "properties": {
+            "error": { "type": "string", "enum": ApiError::KINDS },
+            "parameter": {
+                "type": "string",
+                "description": "Symbol of the input that caused the error, or the \
+                    constructor parameter a calculation failed on.",
+            },
+            "message": { "type": "string" },
+        },
+    })
+}
+
+/// Adds the fields of an object to another.
+fn merge(value: &mut Value, fields: Value) {
+    if let (Value::Object(value), Value::Object(fields)) = (value, fields) {
+        value.extend(fields);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_document() {
+        let document = document();
+        let paths = document["paths"].as_object().unwrap();
+        assert_eq!(paths.len(), endpoints().len());
+
+        let schema = |path: &str| {
+            paths[path]["post"]["requestBody"]["content"]["application/json"]["schema"].clone()
+        };
+        let rectangle = schema("/api/v1/rectangle");
+        let width = &rectangle["oneOf"][0]["properties"]["inputs"]["properties"]["w"];
+        assert_eq!(width["oneOf"][0]["exclusiveMinimum"], json!(true));
+        assert_eq!(width["oneOf"][1]["$ref"], "#/components/schemas/Length");
+        let units = &document["components"]["schemas"]["Length"]["properties"]["unit"];
+        assert!(units["enum"].as_array().unwrap().contains(&json!("mm")));
+
+        // Constraints between inputs apply to the dimensions, not to the solve modes
+        let triangle = schema("/api/v1/triangle");
+        let constraints = &triangle["oneOf"][0]["properties"]["inputs"]["x-constraints"];
+        assert_eq!(constraints, &json!(["a < b + c", "b < a + c", "c < a + b"]));
+        assert!(triangle["oneOf"][1]["properties"]["inputs"]
+            .get("x-constraints")
+            .is_none());
+        let i_profile = schema("/api/v1/section-i");
+        assert_eq!(
+            i_profile["properties"]["inputs"]["x-constraints"],
+            json!(["tw ≤ b", "2·tf < h"])
+        );
+        let composite = schema("/api/v1/composite");
+        let tube = &composite["properties"]["parts"]["items"]["oneOf"][2];
+        assert_eq!(tube["properties"]["kind"]["enum"], json!(["tube"]));
+        assert_eq!(
+            tube["properties"]["inputs"]["x-constraints"],
+            json!(["t < d/2"])
+        );
+        assert!(paths.contains_key("/api/v1/solid-tube"));
+
+        // Every reference points at a schema of the document
+        let text = document.to_string();
+        for reference in text.split("\"#/components/schemas/").skip(1) {
+            let name = &reference[..reference.find('"').unwrap()];
+            assert!(
+                document["components"]["schemas"].get(name).is_some(),
+                "{name}"
+            );
+        }
+    }
+}
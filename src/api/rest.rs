@@ -0,0 +1,97 @@
+//! The JSON REST endpoint `/api/v1/<calculator>`, answered directly by the Spin
+//! component without going through the Leptos handler.
+
+use crate::api::calculators::calculate;
+pub use crate::api::calculators::PREFIX;
+use crate::api::types::{ApiError, CalculationRequest};
+use leptos_wasi::handler::HandlerError;
+use leptos_wasi::prelude::{IncomingRequest, ResponseOutparam};
+use serde::Serialize;
+use wasi::http::types::{Fields, IncomingBody, Method, OutgoingBody, OutgoingResponse};
+use wasi::io::streams::StreamError;
+
+/// Bytes read or written at a time.
+const CHUNK_SIZE: usize = 4096;
+
+/// Largest request body read; a calculation request is far smaller.
+const MAX_BODY_SIZE: usize = 64 * 1024;
+
+/// Answers a `POST` of a [`CalculationRequest`] to the calculator with the given id.
+pub fn handle(
+    id: &str,
+    request: IncomingRequest,
+    response_out: ResponseOutparam,
+) -> Result<(), HandlerError> {
+    if !matches!(request.method(), Method::Post) {
+        let error = ApiError::new("invalid_request", None, "only POST is allowed");
+        return respond_json(response_out, 405, &error);
+    }
+    let Some(body) = read_body(request)? else {
+        let message = format!("the body is larger than {MAX_BODY_SIZE} bytes");
+        let error = ApiError::new("invalid_request", None, message);
+        return respond_json(response_out, 413, &error);
+    };
+    let result = serde_json::from_slice::<CalculationRequest>(&body)
+        .map_err(|e| ApiError::new("invalid_request", None, e.to_string()))
+        .and_then(|calculation| calculate(id, &calculation));
+    match result {
+        Ok(response) => respond_json(response_out, 200, &response),
+        Err(error) => respond_json(response_out, error.status(), &error),
+    }
+}
+
+/// Reads the body of a request, or `None` once it grows past [`MAX_BODY_SIZE`].
+fn read_body(request: IncomingRequest) -> Result<Option<Vec<u8>>, HandlerError> {
+    // The body and its stream can be taken only once; failing means they are gone
+    let incoming_body = request.consume().map_err(|()| StreamError::Closed)?;
+    let stream = incoming_body.stream().map_err(|()| StreamError::Closed)?;
+    let mut bytes = Vec::new();
+    loop {
+        match stream.blocking_read(CHUNK_SIZE as u64) {
+            Ok(data) if bytes.len() + data.len() > MAX_BODY_SIZE => return Ok(None),
+            Ok(data) => bytes.extend(data),
+            Err(StreamError::Closed) => break,
+            Err(e) => return Err(e.into()),
+        }
+    }
+    drop(stream);
+    IncomingBody::finish(incoming_body);
+    Ok(Some(bytes))
+}
+
+/// Responds with a value as JSON.
+pub fn respond_json(
+    response_out: ResponseOutparam,
+    status: u16,
+    value: &impl Serialize,
+) -> Result<(), HandlerError> {
+    let body = serde_json::to_vec(value).expect("API values serialize to JSON");
+    respond(response_out, status, "application/json", &body)
+}
+
+/// Responds with a body of the given content type.
+pub fn respond(
+    response_out: ResponseOutparam,
+    status: u16,
+    content_type: &str,
+    body: &[u8],
+) -> Result<(), HandlerError> {
+    let headers =
+        Fields::from_list(&[("content-type".to_string(), content_type.as_bytes().to_vec())])
+            .expect("content type is a valid header");
+    let response = OutgoingResponse::new(headers);
+    response
+        .set_status_code(status)
+        .expect("status is a valid HTTP status code");
+    let outgoing_body = response.body().expect("unable to take response body");
+    ResponseOutparam::set(response_out, Ok(response));
+
+    let stream = outgoing_body
+        .write()
+        .expect("unable to open writable stream on body");
+    for chunk in body.chunks(CHUNK_SIZE) {
+        stream.blocking_write_and_flush(chunk)?;
+    }
+    drop(stream);
+    OutgoingBody::finish(outgoing_body, None).map_err(HandlerError::WasiResponseBody)
+}
@@ -0,0 +1,80 @@
+//! Bodies of the API's requests and responses, which the server functions take and
+//! return as well.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// A number in a request or response, in SI units or in the unit given with it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApiValue {
+    Number(f64),
+    Quantity { value: f64, unit: String },
+}
+
+/// Body of a calculation request.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CalculationRequest {
+    /// Id of the solve mode; without it the dimensions are given.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+    /// Values by symbol, such as `w` and `h` of a rectangle.
+    #[serde(default)]
+    pub inputs: BTreeMap<String, ApiValue>,
+    /// Parts of a composite section.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parts: Vec<PartRequest>,
+}
+
+/// A part of a composite section, placed with the bottom-left corner of its bounding box.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PartRequest {
+    /// Id of the kind of section, such as `rectangle` or `i`.
+    pub kind: String,
+    pub x: ApiValue,
+    pub y: ApiValue,
+    /// Whether the part removes material, such as a hole.
+    #[serde(default)]
+    pub hole: bool,
+    pub inputs: BTreeMap<String, ApiValue>,
+}
+
+/// Body of a successful calculation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalculationResponse {
+    pub calculator: String,
+    /// Results by symbol, in SI units.
+    pub outputs: BTreeMap<String, ApiValue>,
+}
+
+/// Body of a failed calculation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiError {
+    /// Kind of error: `unknown_calculator`, `unknown_mode`, `unknown_kind`,
+    /// `missing_input`, `invalid_request`, or the kind of calculation error, such as
+    /// `non_positive`.
+    pub error: String,
+    /// Symbol of the input that caused the error, or the constructor parameter a
+    /// calculation failed on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parameter: Option<String>,
+    pub message: String,
+}
+
+// Server functions carry the error as text
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let json = serde_json::to_string(self).map_err(|_| fmt::Error)?;
+        f.write_str(&json)
+    }
+}
+
+impl FromStr for ApiError {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s)
+    }
+}
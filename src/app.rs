@@ -1,9 +1,25 @@
-use crate::calculations::shapes_2d::*;
-use crate::cards::area::{CircleCard, RectangleCard};
+use crate::cards::area::{
+    CircleCard, EllipseCard, ParallelogramCard, RectangleCard, RegularPolygonCard, TrapezoidCard,
+    TriangleCard,
+};
+use crate::cards::composite::CompositeSectionCard;
+use crate::cards::conversion::{
+    AngleConversionCard, AreaConversionCard, EnergyConversionCard, FlowRateConversionCard,
+    ForceConversionCard, LengthConversionCard, MassConversionCard, PowerConversionCard,
+    PressureConversionCard, TemperatureConversionCard, TorqueConversionCard, VolumeConversionCard,
+};
+use crate::cards::history::{HistoryPanel, HistoryStore};
+use crate::cards::section::SectionPropertiesCard;
+use crate::cards::volume::SolidCard;
+use crate::cards::workspace::WorkspaceStore;
 use crate::dom::storage::*;
 use crate::locales::i18n::I18n;
+use crate::locales::numbers::Rounding;
 use crate::pages::basics::BasicsPage;
+use crate::pages::conversions::ConversionsPage;
 use crate::pages::home::HomePage;
+use crate::pages::report::ReportPage;
+use crate::pages::workspaces::WorkspacesPage;
 use components::{Route, Router, Routes};
 use leptos::prelude::*;
 use leptos_meta::*;
@@ -11,9 +27,6 @@ use leptos_router::*;
 
 #[cfg(feature = "ssr")]
 pub fn shell(options: LeptosOptions) -> impl IntoView {
-    // add <link rel="stylesheet" href="https://cdn.jsdelivr.net/npm/katex@0.16.8/dist/katex.min.css">
-    // <script defer src="https://cdn.jsdelivr.net/npm/katex@0.16.8/dist/katex.min.js"></script>
-    // to the head of the document
     view! {
         <!DOCTYPE html>
         <html lang="en">
@@ -24,8 +37,6 @@ pub fn shell(options: LeptosOptions) -> impl IntoView {
                 <AutoReload options=options.clone() />
                 <HydrationScripts options=options.clone() root=""/>
                 <MetaTags/>
-                <link rel="stylesheet" href="https://cdn.jsdelivr.net/npm/katex@0.16.8/dist/katex.min.css"></link>
-                <script defer src="https://cdn.jsdelivr.net/npm/katex@0.16.8/dist/katex.min.js"></script>
             </head>
             <body>
                 <App/>
@@ -46,10 +57,54 @@ pub fn PageHeader() -> impl IntoView {
                 <button on:click=move |_| set_lang.set("en".to_string())>"EN"</button>
                 <button on:click=move |_| set_lang.set("nl".to_string())>"NL"</button>
             </div>
+            <RoundingSettings/>
         </header>
     }
 }
 
+/// Lets the user choose how all results are rounded.
+#[allow(non_snake_case)]
+#[component]
+pub fn RoundingSettings() -> impl IntoView {
+    let i18n = use_context::<Memo<I18n>>().expect("I18n context not found");
+    let rounding = use_context::<RwSignal<Rounding>>().expect("Rounding context not found");
+
+    view! {
+        <div class="rounding">
+            <label for="rounding">{move || i18n.get().t("rounding").to_string()}</label>
+            <select
+                id="rounding"
+                prop:value=move || rounding.get().id()
+                on:change=move |ev| {
+                    rounding.update(|rounding| *rounding = rounding.with_id(&event_target_value(&ev)))
+                }
+            >
+                {Rounding::IDS
+                    .into_iter()
+                    .map(|id| {
+                        view! {
+                            <option value=id>
+                                {move || i18n.get().t(&format!("rounding_{id}")).to_string()}
+                            </option>
+                        }
+                    })
+                    .collect_view()}
+            </select>
+            <input
+                type="number"
+                min="0"
+                max="15"
+                prop:value=move || rounding.get().digits().to_string()
+                on:change=move |ev| {
+                    if let Ok(digits) = event_target_value(&ev).parse::<usize>() {
+                        rounding.update(|rounding| *rounding = rounding.with_digits(digits));
+                    }
+                }
+            />
+        </div>
+    }
+}
+
 #[allow(non_snake_case)]
 #[component]
 pub fn NavBar() -> impl IntoView {
@@ -74,6 +129,20 @@ pub fn NavBar() -> impl IntoView {
                         "menu__item"
                     }
                 } href="/basics">{move || i18n.get().t("menu_basics").to_string()}</a>
+            <a class=move || {
+                    if path_is_active("/conversions") {
+                        "menu__item--active"
+                    } else {
+                        "menu__item"
+                    }
+                } href="/conversions">{move || i18n.get().t("menu_conversions").to_string()}</a>
+            <a class=move || {
+                    if path_is_active("/workspaces") {
+                        "menu__item--active"
+                    } else {
+                        "menu__item"
+                    }
+                } href="/workspaces">{move || i18n.get().t("menu_workspaces").to_string()}</a>
         </div>
     }
 }
@@ -85,11 +154,35 @@ pub fn Content() -> impl IntoView {
     view! {
         <main class="content">
             <Router>
+                <HistoryPanel/>
                 <Routes fallback>
                    <Route path=path!("") view=HomePage/>
                    <Route path=path!("/basics") view=BasicsPage/>
+                   <Route path=path!("/conversions") view=ConversionsPage/>
+                   <Route path=path!("/workspaces") view=WorkspacesPage/>
+                   <Route path=path!("/report") view=ReportPage/>
                    <Route path=path!("/cards/area/circle") view=CircleCard/>
                    <Route path=path!("/cards/area/rectangle") view=RectangleCard/>
+                   <Route path=path!("/cards/area/triangle") view=TriangleCard/>
+                   <Route path=path!("/cards/area/trapezoid") view=TrapezoidCard/>
+                   <Route path=path!("/cards/area/ellipse") view=EllipseCard/>
+                   <Route path=path!("/cards/area/regular-polygon") view=RegularPolygonCard/>
+                   <Route path=path!("/cards/area/parallelogram") view=ParallelogramCard/>
+                   <Route path=path!("/cards/section/properties") view=SectionPropertiesCard/>
+                   <Route path=path!("/cards/section/composite") view=CompositeSectionCard/>
+                   <Route path=path!("/cards/volume/solid") view=SolidCard/>
+                   <Route path=path!("/cards/conversion/length") view=LengthConversionCard/>
+                   <Route path=path!("/cards/conversion/area") view=AreaConversionCard/>
+                   <Route path=path!("/cards/conversion/volume") view=VolumeConversionCard/>
+                   <Route path=path!("/cards/conversion/mass") view=MassConversionCard/>
+                   <Route path=path!("/cards/conversion/force") view=ForceConversionCard/>
+                   <Route path=path!("/cards/conversion/pressure") view=PressureConversionCard/>
+                   <Route path=path!("/cards/conversion/energy") view=EnergyConversionCard/>
+                   <Route path=path!("/cards/conversion/power") view=PowerConversionCard/>
+                   <Route path=path!("/cards/conversion/torque") view=TorqueConversionCard/>
+                   <Route path=path!("/cards/conversion/temperature") view=TemperatureConversionCard/>
+                   <Route path=path!("/cards/conversion/flow-rate") view=FlowRateConversionCard/>
+                   <Route path=path!("/cards/conversion/angle") view=AngleConversionCard/>
                    <Route path=path!("/*any") view=NotFound/>
                 </Routes>
             </Router>
@@ -133,12 +226,23 @@ pub fn App() -> impl IntoView {
         }
     });
 
-    // Create a memoized I18n that updates when lang changes
-    let i18n = Memo::new(move |_| I18n::new(&lang.get()));
+    // Retrieve the stored rounding of results from localStorage on the browser
+    let rounding = RwSignal::new(Rounding::default());
+    Effect::new(move |_| {
+        if let Some(stored_rounding) = get_local_storage_json::<Rounding>("rounding") {
+            rounding.set(stored_rounding);
+        }
+    });
+
+    // Create a memoized I18n that updates when lang or the rounding changes
+    let i18n = Memo::new(move |_| I18n::new(&lang.get()).with_rounding(rounding.get()));
 
     // Provide the memo as context
     provide_context(i18n);
     provide_context(set_lang);
+    provide_context(rounding);
+    HistoryStore::provide();
+    WorkspaceStore::provide();
 
     // when lang in i18n changes, update localStorage
     Effect::new(move |_| {
@@ -147,6 +251,11 @@ pub fn App() -> impl IntoView {
             eprintln!("Error setting localStorage item: {:?}", e);
         }
     });
+    Effect::new(move |_| {
+        if let Err(e) = set_local_storage_json("rounding", &rounding.get()) {
+            leptos::logging::error!("Error setting localStorage item: {:?}", e);
+        }
+    });
 
     view! {
         <Stylesheet id="leptos" href="/pkg/engineertools.css"/>
@@ -0,0 +1,252 @@
+use crate::calculations::errors::*;
+use crate::calculations::shapes_2d::*;
+use crate::calculations::units::Length;
+
+/// A primitive section placed in a composite section. The position is the
+/// bottom-left corner of the part's bounding box.
+pub struct SectionPart {
+    pub section: Box<dyn SectionProperties>,
+    pub x: f64,
+    pub y: f64,
+    pub subtract: bool,
+}
+
+/// Section built up from positioned primitives, where subtracted parts act as holes.
+pub struct CompositeSection {
+    pub parts: Vec<SectionPart>,
+}
+
+impl SectionPart {
+    /// Places a section that adds material at (x, y).
+    pub fn added(section: Box<dyn SectionProperties>, x: Length, y: Length) -> Self {
+        SectionPart {
+            section,
+            x: x.si(),
+            y: y.si(),
+            subtract: false,
+        }
+    }
+
+    /// Places a section that removes material at (x, y), such as a hole.
+    pub fn subtracted(section: Box<dyn SectionProperties>, x: Length, y: Length) -> Self {
+        SectionPart {
+            section,
+            x: x.si(),
+            y: y.si(),
+            subtract: true,
+        }
+    }
+
+    fn sign(&self) -> f64 {
+        if self.subtract {
+            -1.0
+        } else {
+            1.0
+        }
+    }
+
+    /// Centroid of the part in composite coordinates.
+    fn global_centroid(&self) -> (f64, f64) {
+        let (cx, cy) = self.section.centroid();
+        (self.x + cx, self.y + cy)
+    }
+}
+
+impl CompositeSection {
+    /// Creates a composite section from its parts.
+    pub fn new(parts: Vec<SectionPart>) -> Result<Self, CalculationError> {
+        if parts.iter().all(|part| part.subtract) {
+            return Err(CalculationError::Inconsistent {
+                parameter: "parts",
+                value: parts.len() as f64,
+            });
+        }
+        let composite = CompositeSection { parts };
+        let area = composite.area();
+        if area <= 0.0 {
+            return Err(CalculationError::Inconsistent {
+                parameter: "area",
+                value: area,
+            });
+        }
+        ensure_finite_section(&composite)?;
+        Ok(composite)
+    }
+
+    /// Lower-left corner of the bounding box of all added parts.
+    pub fn origin(&self) -> (f64, f64) {
+        self.added_parts()
+            .fold((f64::MAX, f64::MAX), |(x0, y0), part| {
+                (x0.min(part.x), y0.min(part.y))
+            })
+    }
+
+    fn added_parts(&self) -> impl Iterator<Item = &SectionPart> {
+        self.parts.iter().filter(|part| !part.subtract)
+    }
+
+    /// Centroid of the composite in the coordinates the parts were placed in.
+    fn global_centroid(&self) -> (f64, f64) {
+        let area = self.area();
+        let (sx, sy) = self.parts.iter().fold((0.0, 0.0), |(sx, sy), part| {
+            let a = part.sign() * part.section.area();
+            let (cx, cy) = part.global_centroid();
+            (sx + a * cx, sy + a * cy)
+        });
+        (sx / area, sy / area)
+    }
+}
+
+impl Geometric2D for CompositeSection {
+    fn area(&self) -> f64 {
+        self.parts
+            .iter()
+            .map(|part| part.sign() * part.section.area())
+            .sum()
+    }
+
+    /// Sum of the perimeters of all parts; edges shared by touching parts are counted twice.
+    fn perimeter(&self) -> f64 {
+        self.parts.iter().map(|part| part.section.perimeter()).sum()
+    }
+}
+
+impl SectionProperties for CompositeSection {
+    fn bounding_box(&self) -> (f64, f64) {
+        let (x0, y0) = self.origin();
+        let (x1, y1) = self
+            .added_parts()
+            .fold((f64::MIN, f64::MIN), |(x1, y1), part| {
+                let (w, h) = part.section.bounding_box();
+                (x1.max(part.x + w), y1.max(part.y + h))
+            });
+        (x1 - x0, y1 - y0)
+    }
+
+    fn centroid(&self) -> (f64, f64) {
+        let (x0, y0) = self.origin();
+        let (cx, cy) = self.global_centroid();
+        (cx - x0, cy - y0)
+    }
+
+    fn ix(&self) -> f64 {
+        let (_, cy) = self.global_centroid();
+        self.parts
+            .iter()
+            .map(|part| {
+                let dy = part.global_centroid().1 - cy;
+                part.sign() * (part.section.ix() + part.section.area() * dy.powi(2))
+            })
+            .sum()
+    }
+
+    fn iy(&self) -> f64 {
+        let (cx, _) = self.global_centroid();
+        self.parts
+            .iter()
+            .map(|part| {
+                let dx = part.global_centroid().0 - cx;
+                part.sign() * (part.section.iy() + part.section.area() * dx.powi(2))
+            })
+            .sum()
+    }
+
+    fn ixy(&self) -> f64 {
+        let (cx, cy) = self.global_centroid();
+        self.parts
+            .iter()
+            .map(|part| {
+                let (px, py) = part.global_centroid();
+                part.sign() * (part.section.ixy() + part.section.area() * (px - cx) * (py - cy))
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculations::sections::IProfile;
+
+    const EPS: f64 = 1e-6;
+
+    fn m(value: f64) -> Length {
+        Length::from_si(value)
+    }
+
+    #[test]
+    fn test_plates_match_i_profile() {
+        // IPE-like section assembled from three plates
+        let composite = CompositeSection::new(vec![
+            SectionPart::added(
+                Box::new(Rectangle::new(m(100.0), m(8.5)).unwrap()),
+                m(0.0),
+                m(0.0),
+            ),
+            SectionPart::added(
+                Box::new(Rectangle::new(m(5.6), m(183.0)).unwrap()),
+                m(47.2),
+                m(8.5),
+            ),
+            SectionPart::added(
+                Box::new(Rectangle::new(m(100.0), m(8.5)).unwrap()),
+                m(0.0),
+                m(191.5),
+            ),
+        ])
+        .unwrap();
+        let profile = IProfile::new(m(200.0), m(100.0), m(5.6), m(8.5)).unwrap();
+        assert!((composite.area() - profile.area()).abs() < EPS);
+        assert!((composite.ix() - profile.ix()).abs() < EPS);
+        assert!((composite.iy() - profile.iy()).abs() < EPS);
+        assert!(composite.ixy().abs() < EPS);
+        assert_eq!(composite.bounding_box(), (100.0, 200.0));
+    }
+
+    #[test]
+    fn test_hole_shifts_centroid() {
+        let composite = CompositeSection::new(vec![
+            SectionPart::added(
+                Box::new(Rectangle::new(m(100.0), m(100.0)).unwrap()),
+                m(-50.0),
+                m(-50.0),
+            ),
+            SectionPart::subtracted(Box::new(Circle::new(m(10.0)).unwrap()), m(10.0), m(-10.0)),
+        ])
+        .unwrap();
+        let hole = Circle::new(m(10.0)).unwrap();
+        assert!((composite.area() - (10000.0 - hole.area())).abs() < EPS);
+        let (cx, cy) = composite.centroid();
+        assert!(cx < 50.0);
+        assert!((cy - 50.0).abs() < EPS);
+        let ix = 100f64.powi(4) / 12.0 - hole.ix();
+        assert!((composite.ix() - ix).abs() < EPS);
+    }
+
+    #[test]
+    fn test_invalid_composites() {
+        assert!(CompositeSection::new(vec![]).is_err());
+        assert!(CompositeSection::new(vec![
+            SectionPart::added(
+                Box::new(Rectangle::new(m(1.0), m(1.0)).unwrap()),
+                m(0.0),
+                m(0.0)
+            ),
+            SectionPart::subtracted(
+                Box::new(Rectangle::new(m(2.0), m(2.0)).unwrap()),
+                m(0.0),
+                m(0.0)
+            ),
+        ])
+        .is_err());
+        // The parallel axis term overflows for a part placed far away
+        let unit_square = || Box::new(Rectangle::new(m(1.0), m(1.0)).unwrap());
+        assert!(matches!(
+            CompositeSection::new(vec![
+                SectionPart::added(unit_square(), m(0.0), m(0.0)),
+                SectionPart::added(unit_square(), m(0.0), m(1e200)),
+            ]),
+            Err(CalculationError::NotFinite { .. })
+        ));
+    }
+}
@@ -1,4 +1,118 @@
-#[derive(Debug)]
-pub struct CalculationError {
-    pub message: String,
+use std::fmt;
+
+/// Why a calculation could not be performed, with the parameter that caused it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CalculationError {
+    /// The parameter must be greater than zero.
+    NonPositive { parameter: &'static str, value: f64 },
+    /// The parameter lies outside the range the formula is defined for.
+    OutOfDomain { parameter: &'static str, value: f64 },
+    /// The parameter is valid on its own but contradicts the other inputs.
+    Inconsistent { parameter: &'static str, value: f64 },
+    /// The parameter, or a result derived from it, is infinite or not a number.
+    NotFinite { parameter: &'static str, value: f64 },
+    /// The parameter was given in a unit that does not belong to its dimension.
+    UnitMismatch {
+        parameter: &'static str,
+        unit: String,
+    },
+}
+
+impl CalculationError {
+    /// Name of the parameter that caused the error.
+    pub fn parameter(&self) -> &'static str {
+        match self {
+            CalculationError::NonPositive { parameter, .. }
+            | CalculationError::OutOfDomain { parameter, .. }
+            | CalculationError::Inconsistent { parameter, .. }
+            | CalculationError::NotFinite { parameter, .. }
+            | CalculationError::UnitMismatch { parameter, .. } => parameter,
+        }
+    }
+
+    /// Key of the translated message in `I18n`.
+    pub fn i18n_key(&self) -> &'static str {
+        match self {
+            CalculationError::NonPositive { .. } => "error_non_positive",
+            CalculationError::OutOfDomain { .. } => "error_out_of_domain",
+            CalculationError::Inconsistent { .. } => "error_inconsistent",
+            CalculationError::NotFinite { .. } => "error_not_finite",
+            CalculationError::UnitMismatch { .. } => "error_unit_mismatch",
+        }
+    }
+
+    /// The offending value as entered, formatted for display.
+    pub fn value(&self) -> String {
+        match self {
+            CalculationError::NonPositive { value, .. }
+            | CalculationError::OutOfDomain { value, .. }
+            | CalculationError::Inconsistent { value, .. }
+            | CalculationError::NotFinite { value, .. } => value.to_string(),
+            CalculationError::UnitMismatch { unit, .. } => unit.clone(),
+        }
+    }
+}
+
+impl fmt::Display for CalculationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self {
+            CalculationError::NonPositive { .. } => "must be greater than zero",
+            CalculationError::OutOfDomain { .. } => "is outside the valid range",
+            CalculationError::Inconsistent { .. } => "does not fit the other inputs",
+            CalculationError::NotFinite { .. } => "is not a finite number",
+            CalculationError::UnitMismatch { .. } => "has a unit of the wrong dimension",
+        };
+        write!(f, "{} {} (got {})", self.parameter(), reason, self.value())
+    }
+}
+
+impl std::error::Error for CalculationError {}
+
+/// Checks that a parameter is a finite number greater than zero.
+pub fn ensure_positive(parameter: &'static str, value: f64) -> Result<(), CalculationError> {
+    if !value.is_finite() {
+        Err(CalculationError::NotFinite { parameter, value })
+    } else if value <= 0.0 {
+        Err(CalculationError::NonPositive { parameter, value })
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that a result is a finite number, as results overflow for huge inputs.
+pub fn ensure_finite(parameter: &'static str, value: f64) -> Result<(), CalculationError> {
+    match value.is_finite() {
+        true => Ok(()),
+        false => Err(CalculationError::NotFinite { parameter, value }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ensure_positive() {
+        assert!(ensure_positive("width", 2.0).is_ok());
+        let err = ensure_positive("width", 0.0).unwrap_err();
+        assert_eq!(err.parameter(), "width");
+        assert_eq!(err.i18n_key(), "error_non_positive");
+        assert_eq!(err.to_string(), "width must be greater than zero (got 0)");
+        assert!(matches!(
+            ensure_positive("width", f64::NAN),
+            Err(CalculationError::NotFinite { .. })
+        ));
+        assert!(matches!(
+            ensure_positive("width", f64::INFINITY),
+            Err(CalculationError::NotFinite { .. })
+        ));
+        assert!(ensure_finite("A", -1.0).is_ok());
+        assert_eq!(
+            ensure_finite("A", f64::INFINITY),
+            Err(CalculationError::NotFinite {
+                parameter: "A",
+                value: f64::INFINITY
+            })
+        );
+    }
 }
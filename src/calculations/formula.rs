@@ -0,0 +1,166 @@
+//! Derivations that show how a calculation reached its results.
+//!
+//! A [`Step`] writes one value as its formula in symbols, the same formula with the
+//! known values substituted and the result, like `A = w \times h = 2 \times 3 = 6`.
+//! A [`Derivation`] holds the steps of a calculation with the values of the symbols
+//! they use, and is written as LaTeX with one line per step. Values are written by the
+//! caller, which knows the units and locale they are shown in.
+
+/// One step of a derivation, written `symbol = expression = substitution = result`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Step {
+    /// Symbol of the value the step finds, also the placeholder of its result.
+    pub symbol: &'static str,
+    /// LaTeX of the formula in symbols.
+    pub expression: &'static str,
+    /// The formula with `#symbol` where the value of a symbol is substituted, or empty
+    /// when there is nothing to substitute.
+    pub substitution: &'static str,
+    /// Whether the formula approximates the value, written with `\approx`.
+    pub approximate: bool,
+}
+
+impl Step {
+    pub const fn new(
+        symbol: &'static str,
+        expression: &'static str,
+        substitution: &'static str,
+    ) -> Self {
+        Step {
+            symbol,
+            expression,
+            substitution,
+            approximate: false,
+        }
+    }
+
+    /// A step whose formula approximates the value.
+    pub const fn approximate(
+        symbol: &'static str,
+        expression: &'static str,
+        substitution: &'static str,
+    ) -> Self {
+        Step {
+            approximate: true,
+            ..Step::new(symbol, expression, substitution)
+        }
+    }
+
+    /// Writes the step as LaTeX, with the values `value` writes for the placeholders.
+    pub fn latex(&self, value: impl Fn(&str) -> Option<String>) -> String {
+        let relation = if self.approximate { r"\approx" } else { "=" };
+        let mut latex = format!("{} {relation} {}", self.symbol, self.expression);
+        if !self.substitution.is_empty() {
+            latex.push_str(" = ");
+            latex.push_str(&fill_formula(self.substitution, &value));
+        }
+        latex.push_str(" = ");
+        latex.push_str(&fill_formula(&format!("#{}", self.symbol), &value));
+        latex
+    }
+}
+
+/// The steps of a calculation with the values of the symbols they use.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Derivation<V> {
+    pub steps: Vec<Step>,
+    values: Vec<(&'static str, V)>,
+}
+
+impl<V: Copy> Derivation<V> {
+    pub fn new(steps: &[Step]) -> Self {
+        Derivation {
+            steps: steps.to_vec(),
+            values: Vec::new(),
+        }
+    }
+
+    /// Adds the value of a symbol; a later value replaces an earlier one.
+    pub fn with_value(mut self, symbol: &'static str, value: V) -> Self {
+        self.values.retain(|(known, _)| *known != symbol);
+        self.values.push((symbol, value));
+        self
+    }
+
+    /// The value of a symbol.
+    pub fn value(&self, symbol: &str) -> Option<V> {
+        self.values
+            .iter()
+            .find(|(known, _)| *known == symbol)
+            .map(|(_, value)| *value)
+    }
+
+    /// Writes the derivation as LaTeX, one line per step. `write` gives the LaTeX of
+    /// the value of a symbol with its unit.
+    pub fn latex(&self, write: impl Fn(&str, V) -> String) -> String {
+        let value = |symbol: &str| self.value(symbol).map(|value| write(symbol, value));
+        self.steps
+            .iter()
+            .map(|step| step.latex(value))
+            .collect::<Vec<_>>()
+            .join(r" \\ ")
+    }
+}
+
+/// Replaces every `#symbol` in a formula template with the text `value` gives for it;
+/// placeholders it has no text for are left as they are.
+pub fn fill_formula(template: &str, value: impl Fn(&str) -> Option<String>) -> String {
+    let mut formula = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('#') {
+        formula.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let end = after
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(after.len());
+        let symbol = &after[..end];
+        match value(symbol) {
+            Some(text) => formula.push_str(&text),
+            None => {
+                formula.push('#');
+                formula.push_str(symbol);
+            }
+        }
+        rest = &after[end..];
+    }
+    formula.push_str(rest);
+    formula
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fill_formula() {
+        let value = |symbol: &str| match symbol {
+            "w" => Some("2".to_string()),
+            "A" => Some(r"6\,\text{m²}".to_string()),
+            _ => None,
+        };
+        assert_eq!(
+            fill_formula(r"A = w \times h \\ A = #w \times #h = #A", value),
+            r"A = w \times h \\ A = 2 \times #h = 6\,\text{m²}"
+        );
+        assert_eq!(fill_formula(r"\frac{#w}{2}", value), r"\frac{2}{2}");
+    }
+
+    #[test]
+    fn test_derivation() {
+        const STEPS: [Step; 2] = [
+            Step::new("A", r"w \times h", r"#w \times #h"),
+            Step::approximate("P", r"2 (w + h)", ""),
+        ];
+        let derivation = Derivation::new(&STEPS)
+            .with_value("w", 2.0)
+            .with_value("h", 3.0)
+            .with_value("A", 5.0)
+            .with_value("A", 6.0)
+            .with_value("P", 10.0);
+        assert_eq!(derivation.value("A"), Some(6.0));
+        assert_eq!(
+            derivation.latex(|symbol, value| format!("{value}_{symbol}")),
+            r"A = w \times h = 2_w \times 3_h = 6_A \\ P \approx 2 (w + h) = 10_P"
+        );
+    }
+}
@@ -1,2 +1,8 @@
+pub mod composite;
 pub mod errors;
+pub mod formula;
+pub mod sections;
 pub mod shapes_2d;
+pub mod shapes_3d;
+pub mod tolerance;
+pub mod units;
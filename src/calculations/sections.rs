@@ -0,0 +1,606 @@
+use crate::calculations::errors::*;
+use crate::calculations::shapes_2d::*;
+use crate::calculations::units::Length;
+use std::f64::consts::PI;
+
+/// Circular hollow section.
+pub struct Tube {
+    pub outer_diameter: f64,
+    pub wall_thickness: f64,
+}
+
+/// Rectangular hollow section with sharp corners.
+pub struct RectangularHollowSection {
+    pub width: f64,
+    pub height: f64,
+    pub wall_thickness: f64,
+}
+
+/// Doubly symmetric I-profile without root radii.
+pub struct IProfile {
+    pub height: f64,
+    pub flange_width: f64,
+    pub web_thickness: f64,
+    pub flange_thickness: f64,
+}
+
+/// T-profile with the flange at the top, without root radii.
+pub struct TProfile {
+    pub height: f64,
+    pub flange_width: f64,
+    pub web_thickness: f64,
+    pub flange_thickness: f64,
+}
+
+/// L-profile (angle) with legs along the left and bottom side.
+pub struct LProfile {
+    pub height: f64,
+    pub width: f64,
+    pub thickness: f64,
+}
+
+/// C-profile (channel) with the web on the left side, without root radii.
+pub struct CProfile {
+    pub height: f64,
+    pub flange_width: f64,
+    pub web_thickness: f64,
+    pub flange_thickness: f64,
+}
+
+/// Axis-aligned rectangle positioned by its bottom-left corner, used to build up
+/// the thin-walled profiles.
+struct RectPart {
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+}
+
+/// Area, centroid and centroidal second moments (Ix, Iy, Ixy) of a set of
+/// non-overlapping rectangles, combined with the parallel axis theorem.
+fn combine_parts(parts: &[RectPart]) -> (f64, (f64, f64), f64, f64, f64) {
+    let area: f64 = parts.iter().map(|p| p.width * p.height).sum();
+    let cx = parts
+        .iter()
+        .map(|p| p.width * p.height * (p.x + p.width / 2.0))
+        .sum::<f64>()
+        / area;
+    let cy = parts
+        .iter()
+        .map(|p| p.width * p.height * (p.y + p.height / 2.0))
+        .sum::<f64>()
+        / area;
+
+    let (mut ix, mut iy, mut ixy) = (0.0, 0.0, 0.0);
+    for p in parts {
+        let a = p.width * p.height;
+        let dx = p.x + p.width / 2.0 - cx;
+        let dy = p.y + p.height / 2.0 - cy;
+        ix += p.width * p.height.powi(3) / 12.0 + a * dy.powi(2);
+        iy += p.height * p.width.powi(3) / 12.0 + a * dx.powi(2);
+        ixy += a * dx * dy;
+    }
+    (area, (cx, cy), ix, iy, ixy)
+}
+
+impl Tube {
+    /// Creates a tube from its outer diameter and wall thickness.
+    pub fn new(outer_diameter: Length, wall_thickness: Length) -> Result<Self, CalculationError> {
+        let (outer_diameter, wall_thickness) = (outer_diameter.si(), wall_thickness.si());
+        ensure_positive("outer_diameter", outer_diameter)?;
+        ensure_positive("wall_thickness", wall_thickness)?;
+        if 2.0 * wall_thickness >= outer_diameter {
+            return Err(CalculationError::Inconsistent {
+                parameter: "wall_thickness",
+                value: wall_thickness,
+            });
+        }
+        Ok(Tube {
+            outer_diameter,
+            wall_thickness,
+        })
+    }
+
+    /// Returns the inner diameter.
+    pub fn inner_diameter(&self) -> f64 {
+        self.outer_diameter - 2.0 * self.wall_thickness
+    }
+}
+
+impl RectangularHollowSection {
+    /// Creates a rectangular hollow section from its outer dimensions and wall thickness.
+    pub fn new(
+        width: Length,
+        height: Length,
+        wall_thickness: Length,
+    ) -> Result<Self, CalculationError> {
+        let (width, height, wall_thickness) = (width.si(), height.si(), wall_thickness.si());
+        ensure_positive("width", width)?;
+        ensure_positive("height", height)?;
+        ensure_positive("wall_thickness", wall_thickness)?;
+        if 2.0 * wall_thickness >= width.min(height) {
+            return Err(CalculationError::Inconsistent {
+                parameter: "wall_thickness",
+                value: wall_thickness,
+            });
+        }
+        Ok(RectangularHollowSection {
+            width,
+            height,
+            wall_thickness,
+        })
+    }
+}
+
+impl IProfile {
+    /// Creates an I-profile from its height, flange width, web and flange thickness.
+    pub fn new(
+        height: Length,
+        flange_width: Length,
+        web_thickness: Length,
+        flange_thickness: Length,
+    ) -> Result<Self, CalculationError> {
+        let (height, flange_width, web_thickness, flange_thickness) = (
+            height.si(),
+            flange_width.si(),
+            web_thickness.si(),
+            flange_thickness.si(),
+        );
+        ensure_positive("height", height)?;
+        ensure_positive("flange_width", flange_width)?;
+        ensure_positive("web_thickness", web_thickness)?;
+        ensure_positive("flange_thickness", flange_thickness)?;
+        if 2.0 * flange_thickness >= height {
+            return Err(CalculationError::Inconsistent {
+                parameter: "flange_thickness",
+                value: flange_thickness,
+            });
+        }
+        if web_thickness > flange_width {
+            return Err(CalculationError::Inconsistent {
+                parameter: "web_thickness",
+                value: web_thickness,
+            });
+        }
+        Ok(IProfile {
+            height,
+            flange_width,
+            web_thickness,
+            flange_thickness,
+        })
+    }
+
+    fn parts(&self) -> [RectPart; 3] {
+        let web_height = self.height - 2.0 * self.flange_thickness;
+        [
+            RectPart {
+                x: 0.0,
+                y: 0.0,
+                width: self.flange_width,
+                height: self.flange_thickness,
+            },
+            RectPart {
+                x: (self.flange_width - self.web_thickness) / 2.0,
+                y: self.flange_thickness,
+                width: self.web_thickness,
+                height: web_height,
+            },
+            RectPart {
+                x: 0.0,
+                y: self.height - self.flange_thickness,
+                width: self.flange_width,
+                height: self.flange_thickness,
+            },
+        ]
+    }
+}
+
+impl TProfile {
+    /// Creates a T-profile from its height, flange width, web and flange thickness.
+    pub fn new(
+        height: Length,
+        flange_width: Length,
+        web_thickness: Length,
+        flange_thickness: Length,
+    ) -> Result<Self, CalculationError> {
+        let (height, flange_width, web_thickness, flange_thickness) = (
+            height.si(),
+            flange_width.si(),
+            web_thickness.si(),
+            flange_thickness.si(),
+        );
+        ensure_positive("height", height)?;
+        ensure_positive("flange_width", flange_width)?;
+        ensure_positive("web_thickness", web_thickness)?;
+        ensure_positive("flange_thickness", flange_thickness)?;
+        if flange_thickness >= height {
+            return Err(CalculationError::Inconsistent {
+                parameter: "flange_thickness",
+                value: flange_thickness,
+            });
+        }
+        if web_thickness > flange_width {
+            return Err(CalculationError::Inconsistent {
+                parameter: "web_thickness",
+                value: web_thickness,
+            });
+        }
+        Ok(TProfile {
+            height,
+            flange_width,
+            web_thickness,
+            flange_thickness,
+        })
+    }
+
+    fn parts(&self) -> [RectPart; 2] {
+        [
+            RectPart {
+                x: (self.flange_width - self.web_thickness) / 2.0,
+                y: 0.0,
+                width: self.web_thickness,
+                height: self.height - self.flange_thickness,
+            },
+            RectPart {
+                x: 0.0,
+                y: self.height - self.flange_thickness,
+                width: self.flange_width,
+                height: self.flange_thickness,
+            },
+        ]
+    }
+}
+
+impl LProfile {
+    /// Creates an (un)equal angle from its leg lengths and thickness.
+    pub fn new(height: Length, width: Length, thickness: Length) -> Result<Self, CalculationError> {
+        let (height, width, thickness) = (height.si(), width.si(), thickness.si());
+        ensure_positive("height", height)?;
+        ensure_positive("width", width)?;
+        ensure_positive("thickness", thickness)?;
+        if thickness >= height.min(width) {
+            return Err(CalculationError::Inconsistent {
+                parameter: "thickness",
+                value: thickness,
+            });
+        }
+        Ok(LProfile {
+            height,
+            width,
+            thickness,
+        })
+    }
+
+    fn parts(&self) -> [RectPart; 2] {
+        [
+            RectPart {
+                x: 0.0,
+                y: 0.0,
+                width: self.thickness,
+                height: self.height,
+            },
+            RectPart {
+                x: self.thickness,
+                y: 0.0,
+                width: self.width - self.thickness,
+                height: self.thickness,
+            },
+        ]
+    }
+}
+
+impl CProfile {
+    /// Creates a C-profile from its height, flange width, web and flange thickness.
+    pub fn new(
+        height: Length,
+        flange_width: Length,
+        web_thickness: Length,
+        flange_thickness: Length,
+    ) -> Result<Self, CalculationError> {
+        let (height, flange_width, web_thickness, flange_thickness) = (
+            height.si(),
+            flange_width.si(),
+            web_thickness.si(),
+            flange_thickness.si(),
+        );
+        ensure_positive("height", height)?;
+        ensure_positive("flange_width", flange_width)?;
+        ensure_positive("web_thickness", web_thickness)?;
+        ensure_positive("flange_thickness", flange_thickness)?;
+        if 2.0 * flange_thickness >= height {
+            return Err(CalculationError::Inconsistent {
+                parameter: "flange_thickness",
+                value: flange_thickness,
+            });
+        }
+        if web_thickness > flange_width {
+            return Err(CalculationError::Inconsistent {
+                parameter: "web_thickness",
+                value: web_thickness,
+            });
+        }
+        Ok(CProfile {
+            height,
+            flange_width,
+            web_thickness,
+            flange_thickness,
+        })
+    }
+
+    fn parts(&self) -> [RectPart; 3] {
+        let flange_length = self.flange_width - self.web_thickness;
+        [
+            RectPart {
+                x: 0.0,
+                y: 0.0,
+                width: self.web_thickness,
+                height: self.height,
+            },
+            RectPart {
+                x: self.web_thickness,
+                y: 0.0,
+                width: flange_length,
+                height: self.flange_thickness,
+            },
+            RectPart {
+                x: self.web_thickness,
+                y: self.height - self.flange_thickness,
+                width: flange_length,
+                height: self.flange_thickness,
+            },
+        ]
+    }
+}
+
+impl Geometric2D for Tube {
+    fn area(&self) -> f64 {
+        PI / 4.0 * (self.outer_diameter.powi(2) - self.inner_diameter().powi(2))
+    }
+
+    /// Total boundary length, inner and outer surface together.
+    fn perimeter(&self) -> f64 {
+        PI * (self.outer_diameter + self.inner_diameter())
+    }
+}
+
+impl SectionProperties for Tube {
+    fn bounding_box(&self) -> (f64, f64) {
+        (self.outer_diameter, self.outer_diameter)
+    }
+
+    fn centroid(&self) -> (f64, f64) {
+        (self.outer_diameter / 2.0, self.outer_diameter / 2.0)
+    }
+
+    fn ix(&self) -> f64 {
+        PI / 64.0 * (self.outer_diameter.powi(4) - self.inner_diameter().powi(4))
+    }
+
+    fn iy(&self) -> f64 {
+        self.ix()
+    }
+}
+
+impl Geometric2D for RectangularHollowSection {
+    fn area(&self) -> f64 {
+        let t = self.wall_thickness;
+        self.width * self.height - (self.width - 2.0 * t) * (self.height - 2.0 * t)
+    }
+
+    /// Total boundary length, inner and outer surface together.
+    fn perimeter(&self) -> f64 {
+        let t = self.wall_thickness;
+        2.0 * (self.width + self.height) + 2.0 * (self.width + self.height - 4.0 * t)
+    }
+}
+
+impl SectionProperties for RectangularHollowSection {
+    fn bounding_box(&self) -> (f64, f64) {
+        (self.width, self.height)
+    }
+
+    fn centroid(&self) -> (f64, f64) {
+        (self.width / 2.0, self.height / 2.0)
+    }
+
+    fn ix(&self) -> f64 {
+        let (bi, hi) = (
+            self.width - 2.0 * self.wall_thickness,
+            self.height - 2.0 * self.wall_thickness,
+        );
+        (self.width * self.height.powi(3) - bi * hi.powi(3)) / 12.0
+    }
+
+    fn iy(&self) -> f64 {
+        let (bi, hi) = (
+            self.width - 2.0 * self.wall_thickness,
+            self.height - 2.0 * self.wall_thickness,
+        );
+        (self.height * self.width.powi(3) - hi * bi.powi(3)) / 12.0
+    }
+}
+
+impl Geometric2D for IProfile {
+    fn area(&self) -> f64 {
+        combine_parts(&self.parts()).0
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * self.height + 4.0 * self.flange_width - 2.0 * self.web_thickness
+    }
+}
+
+impl SectionProperties for IProfile {
+    fn bounding_box(&self) -> (f64, f64) {
+        (self.flange_width, self.height)
+    }
+
+    fn centroid(&self) -> (f64, f64) {
+        (self.flange_width / 2.0, self.height / 2.0)
+    }
+
+    fn ix(&self) -> f64 {
+        combine_parts(&self.parts()).2
+    }
+
+    fn iy(&self) -> f64 {
+        combine_parts(&self.parts()).3
+    }
+}
+
+impl Geometric2D for TProfile {
+    fn area(&self) -> f64 {
+        combine_parts(&self.parts()).0
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * (self.height + self.flange_width)
+    }
+}
+
+impl SectionProperties for TProfile {
+    fn bounding_box(&self) -> (f64, f64) {
+        (self.flange_width, self.height)
+    }
+
+    fn centroid(&self) -> (f64, f64) {
+        combine_parts(&self.parts()).1
+    }
+
+    fn ix(&self) -> f64 {
+        combine_parts(&self.parts()).2
+    }
+
+    fn iy(&self) -> f64 {
+        combine_parts(&self.parts()).3
+    }
+}
+
+impl Geometric2D for LProfile {
+    fn area(&self) -> f64 {
+        combine_parts(&self.parts()).0
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * (self.height + self.width)
+    }
+}
+
+impl SectionProperties for LProfile {
+    fn bounding_box(&self) -> (f64, f64) {
+        (self.width, self.height)
+    }
+
+    fn centroid(&self) -> (f64, f64) {
+        combine_parts(&self.parts()).1
+    }
+
+    fn ix(&self) -> f64 {
+        combine_parts(&self.parts()).2
+    }
+
+    fn iy(&self) -> f64 {
+        combine_parts(&self.parts()).3
+    }
+
+    fn ixy(&self) -> f64 {
+        combine_parts(&self.parts()).4
+    }
+}
+
+impl Geometric2D for CProfile {
+    fn area(&self) -> f64 {
+        combine_parts(&self.parts()).0
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * self.height + 4.0 * self.flange_width - 2.0 * self.web_thickness
+    }
+}
+
+impl SectionProperties for CProfile {
+    fn bounding_box(&self) -> (f64, f64) {
+        (self.flange_width, self.height)
+    }
+
+    fn centroid(&self) -> (f64, f64) {
+        combine_parts(&self.parts()).1
+    }
+
+    fn ix(&self) -> f64 {
+        combine_parts(&self.parts()).2
+    }
+
+    fn iy(&self) -> f64 {
+        combine_parts(&self.parts()).3
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPS: f64 = 1e-6;
+
+    fn m(value: f64) -> Length {
+        Length::from_si(value)
+    }
+
+    #[test]
+    fn test_tube() {
+        let t = Tube::new(m(100.0), m(10.0)).unwrap();
+        assert!((t.area() - PI / 4.0 * (100f64.powi(2) - 80f64.powi(2))).abs() < EPS);
+        assert!((t.ix() - PI / 64.0 * (100f64.powi(4) - 80f64.powi(4))).abs() < EPS);
+        assert!(Tube::new(m(100.0), m(50.0)).is_err());
+    }
+
+    #[test]
+    fn test_rectangular_hollow_section() {
+        let rhs = RectangularHollowSection::new(m(100.0), m(200.0), m(10.0)).unwrap();
+        assert!((rhs.area() - (100.0 * 200.0 - 80.0 * 180.0)).abs() < EPS);
+        let ix = (100.0 * 200f64.powi(3) - 80.0 * 180f64.powi(3)) / 12.0;
+        assert!((rhs.ix() - ix).abs() < EPS);
+        assert!((rhs.section_modulus_x() - ix / 100.0).abs() < EPS);
+    }
+
+    #[test]
+    fn test_i_profile_matches_closed_form() {
+        // IPE 200 without root radii
+        let ipe = IProfile::new(m(200.0), m(100.0), m(5.6), m(8.5)).unwrap();
+        let ix = (100.0 * 200f64.powi(3) - (100.0 - 5.6) * (200.0 - 17.0f64).powi(3)) / 12.0;
+        assert!((ipe.ix() - ix).abs() < EPS);
+        assert!((ipe.area() - (2.0 * 100.0 * 8.5 + 183.0 * 5.6)).abs() < EPS);
+        assert_eq!(ipe.ixy(), 0.0);
+    }
+
+    #[test]
+    fn test_t_profile_centroid() {
+        let t = TProfile::new(m(100.0), m(100.0), m(10.0), m(10.0)).unwrap();
+        // flange: 1000 mm² at y = 95, web: 900 mm² at y = 45
+        let cy = (1000.0 * 95.0 + 900.0 * 45.0) / 1900.0;
+        assert!((t.centroid().1 - cy).abs() < EPS);
+        assert!((t.centroid().0 - 50.0).abs() < EPS);
+    }
+
+    #[test]
+    fn test_l_profile_is_symmetric_for_equal_legs() {
+        let l = LProfile::new(m(100.0), m(100.0), m(10.0)).unwrap();
+        let (cx, cy) = l.centroid();
+        assert!((cx - cy).abs() < EPS);
+        assert!((l.ix() - l.iy()).abs() < EPS);
+        assert!(l.ixy() < 0.0);
+    }
+
+    #[test]
+    fn test_c_profile() {
+        let c = CProfile::new(m(200.0), m(75.0), m(8.5), m(11.5)).unwrap();
+        assert!((c.centroid().1 - 100.0).abs() < EPS);
+        assert!(c.centroid().0 < 75.0 / 2.0);
+        assert!(CProfile::new(m(200.0), m(75.0), m(8.5), m(100.0)).is_err());
+        // A web as wide as the flanges leaves a solid rectangle, as for the other profiles
+        let solid = CProfile::new(m(200.0), m(75.0), m(75.0), m(11.5)).unwrap();
+        assert!((solid.area() - 200.0 * 75.0).abs() < EPS);
+        assert!(IProfile::new(m(200.0), m(75.0), m(75.0), m(11.5)).is_ok());
+        assert!(CProfile::new(m(200.0), m(75.0), m(76.0), m(11.5)).is_err());
+    }
+}
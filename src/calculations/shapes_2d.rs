@@ -1,6 +1,10 @@
 use crate::calculations::errors::*;
+use crate::calculations::units::{Angle, Area, Length};
 use std::f64::consts::PI;
 
+/// Most sides a regular polygon may have; with more it is a circle in all but name.
+pub const MAX_SIDES: u32 = 1000;
+
 pub struct Rectangle {
     pub width: f64,
     pub height: f64,
@@ -10,94 +14,150 @@ pub struct Circle {
     pub radius: f64,
 }
 
+/// Triangle described by its sides; side `a` lies opposite vertex A, etc.
+pub struct Triangle {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+}
+
+/// Isosceles trapezoid with parallel sides `bottom` and `top`.
+pub struct Trapezoid {
+    pub bottom: f64,
+    pub top: f64,
+    pub height: f64,
+}
+
+pub struct Ellipse {
+    pub semi_major: f64,
+    pub semi_minor: f64,
+}
+
+pub struct RegularPolygon {
+    pub sides: u32,
+    pub side_length: f64,
+}
+
+/// Parallelogram with `angle` (radians) between the base and the slanted side.
+pub struct Parallelogram {
+    pub base: f64,
+    pub side: f64,
+    pub angle: f64,
+}
+
 pub trait Geometric2D {
     fn area(&self) -> f64;
     fn perimeter(&self) -> f64;
 }
 
+/// Cross-section properties of a shape. Coordinates are measured from the
+/// bottom-left corner of the bounding box, with x to the right and y upwards.
+pub trait SectionProperties: Geometric2D {
+    /// Width and height of the bounding box.
+    fn bounding_box(&self) -> (f64, f64);
+
+    /// Centroid (x, y) of the section.
+    fn centroid(&self) -> (f64, f64);
+
+    /// Second moment of area about the horizontal centroidal axis.
+    fn ix(&self) -> f64;
+
+    /// Second moment of area about the vertical centroidal axis.
+    fn iy(&self) -> f64;
+
+    /// Product of inertia about the centroidal axes, zero for symmetric sections.
+    fn ixy(&self) -> f64 {
+        0.0
+    }
+
+    /// Polar moment of area about the centroid.
+    fn polar_moment(&self) -> f64 {
+        self.ix() + self.iy()
+    }
+
+    /// Elastic section modulus about the horizontal axis, using the extreme fibre.
+    fn section_modulus_x(&self) -> f64 {
+        let (_, height) = self.bounding_box();
+        let (_, cy) = self.centroid();
+        self.ix() / cy.max(height - cy)
+    }
+
+    /// Elastic section modulus about the vertical axis, using the extreme fibre.
+    fn section_modulus_y(&self) -> f64 {
+        let (width, _) = self.bounding_box();
+        let (cx, _) = self.centroid();
+        self.iy() / cx.max(width - cx)
+    }
+
+    /// Radius of gyration about the horizontal axis.
+    fn radius_of_gyration_x(&self) -> f64 {
+        (self.ix() / self.area()).sqrt()
+    }
+
+    /// Radius of gyration about the vertical axis.
+    fn radius_of_gyration_y(&self) -> f64 {
+        (self.iy() / self.area()).sqrt()
+    }
+}
+
+/// Checks that the properties of a section did not overflow, as its second moments
+/// of area grow with the fourth power of its dimensions.
+pub fn ensure_finite_section(section: &dyn SectionProperties) -> Result<(), CalculationError> {
+    ensure_finite("A", section.area())?;
+    ensure_finite("Ix", section.ix())?;
+    ensure_finite("Iy", section.iy())?;
+    ensure_finite("Ixy", section.ixy())?;
+    ensure_finite("Ip", section.polar_moment())
+}
+
 impl Rectangle {
     /// Creates a new rectangle with the given width and height.
-    pub fn new(width: f64, height: f64) -> Result<Self, CalculationError> {
-        if width <= 0.0 || height <= 0.0 {
-            return Err(CalculationError {
-                message: "Width and height must be positive.".to_string(),
-            });
-        }
+    pub fn new(width: Length, height: Length) -> Result<Self, CalculationError> {
+        let (width, height) = (width.si(), height.si());
+        ensure_positive("width", width)?;
+        ensure_positive("height", height)?;
         Ok(Rectangle { width, height })
     }
 
     /// Creates a rectangle from its area and width.
-    pub fn from_area_and_width(area: f64, width: f64) -> Result<Self, CalculationError> {
-        if area <= 0.0 || width <= 0.0 {
-            return Err(CalculationError {
-                message: "Area and width must be positive.".to_string(),
-            });
-        }
-        Ok(Rectangle {
-            width,
-            height: area / width,
-        })
+    pub fn from_area_and_width(area: Area, width: Length) -> Result<Self, CalculationError> {
+        ensure_positive("area", area.si())?;
+        ensure_positive("width", width.si())?;
+        Rectangle::new(width, area / width)
     }
 
     /// Creates a rectangle from its area and height.
-    pub fn from_area_and_height(area: f64, height: f64) -> Result<Self, CalculationError> {
-        if area <= 0.0 || height <= 0.0 {
-            return Err(CalculationError {
-                message: "Area and height must be positive.".to_string(),
-            });
-        }
-        Ok(Rectangle {
-            width: area / height,
-            height,
-        })
+    pub fn from_area_and_height(area: Area, height: Length) -> Result<Self, CalculationError> {
+        ensure_positive("area", area.si())?;
+        ensure_positive("height", height.si())?;
+        Rectangle::new(area / height, height)
     }
 }
 
 impl Circle {
     /// Creates a new circle with the given radius.
-    pub fn new(radius: f64) -> Result<Self, CalculationError> {
-        if radius <= 0.0 {
-            return Err(CalculationError {
-                message: "Radius must be positive.".to_string(),
-            });
-        }
+    pub fn new(radius: Length) -> Result<Self, CalculationError> {
+        let radius = radius.si();
+        ensure_positive("radius", radius)?;
         Ok(Circle { radius })
     }
 
     /// Creates a circle from its diameter.
-    pub fn from_diameter(diameter: f64) -> Result<Self, CalculationError> {
-        if diameter <= 0.0 {
-            return Err(CalculationError {
-                message: "Diameter must be positive.".to_string(),
-            });
-        }
-        Ok(Self {
-            radius: diameter / 2.0,
-        })
+    pub fn from_diameter(diameter: Length) -> Result<Self, CalculationError> {
+        ensure_positive("diameter", diameter.si())?;
+        Circle::new(diameter / 2.0)
     }
 
     /// Creates a circle from its circumference.
-    pub fn from_circumference(circumference: f64) -> Result<Self, CalculationError> {
-        if circumference <= 0.0 {
-            return Err(CalculationError {
-                message: "Circumference must be positive.".to_string(),
-            });
-        }
-        Ok(Self {
-            radius: circumference / (2.0 * PI),
-        })
+    pub fn from_circumference(circumference: Length) -> Result<Self, CalculationError> {
+        ensure_positive("circumference", circumference.si())?;
+        Circle::new(circumference / (2.0 * PI))
     }
 
     /// Creates a circle from its area.
-    pub fn from_area(area: f64) -> Result<Self, CalculationError> {
-        if area <= 0.0 {
-            return Err(CalculationError {
-                message: "Area must be positive.".to_string(),
-            });
-        }
-        Ok(Self {
-            radius: (area / PI).sqrt(),
-        })
+    pub fn from_area(area: Area) -> Result<Self, CalculationError> {
+        ensure_positive("area", area.si())?;
+        Circle::new(Length::from_si((area.si() / PI).sqrt()))
     }
 }
 
@@ -120,6 +180,24 @@ impl Geometric2D for Rectangle {
     }
 }
 
+impl SectionProperties for Rectangle {
+    fn bounding_box(&self) -> (f64, f64) {
+        (self.width, self.height)
+    }
+
+    fn centroid(&self) -> (f64, f64) {
+        (self.width / 2.0, self.height / 2.0)
+    }
+
+    fn ix(&self) -> f64 {
+        self.width * self.height.powi(3) / 12.0
+    }
+
+    fn iy(&self) -> f64 {
+        self.height * self.width.powi(3) / 12.0
+    }
+}
+
 impl Default for Circle {
     fn default() -> Self {
         Circle { radius: 1.0 }
@@ -135,3 +213,484 @@ impl Geometric2D for Circle {
         2.0 * PI * self.radius
     }
 }
+
+impl SectionProperties for Circle {
+    fn bounding_box(&self) -> (f64, f64) {
+        (2.0 * self.radius, 2.0 * self.radius)
+    }
+
+    fn centroid(&self) -> (f64, f64) {
+        (self.radius, self.radius)
+    }
+
+    fn ix(&self) -> f64 {
+        PI * self.radius.powi(4) / 4.0
+    }
+
+    fn iy(&self) -> f64 {
+        self.ix()
+    }
+}
+
+impl Triangle {
+    /// Creates a triangle from its three sides (SSS).
+    pub fn new(a: Length, b: Length, c: Length) -> Result<Self, CalculationError> {
+        let (a, b, c) = (a.si(), b.si(), c.si());
+        ensure_positive("a", a)?;
+        ensure_positive("b", b)?;
+        ensure_positive("c", c)?;
+        // Blame the longest side when the triangle inequality does not hold
+        let (parameter, longest) =
+            [("a", a), ("b", b), ("c", c)]
+                .into_iter()
+                .fold(
+                    ("a", a),
+                    |max, side| if side.1 > max.1 { side } else { max },
+                );
+        if 2.0 * longest >= a + b + c {
+            return Err(CalculationError::Inconsistent {
+                parameter,
+                value: longest,
+            });
+        }
+        Ok(Triangle { a, b, c })
+    }
+
+    /// Creates a triangle from two sides and the included angle (SAS).
+    /// The angle `gamma` lies between sides `a` and `b`.
+    pub fn from_sas(a: Length, gamma: Angle, b: Length) -> Result<Self, CalculationError> {
+        let (a, gamma, b) = (a.si(), gamma.si(), b.si());
+        ensure_positive("a", a)?;
+        ensure_positive("b", b)?;
+        if !(gamma > 0.0 && gamma < PI) {
+            return Err(CalculationError::OutOfDomain {
+                parameter: "gamma",
+                value: gamma,
+            });
+        }
+        let c = (a.powi(2) + b.powi(2) - 2.0 * a * b * gamma.cos()).sqrt();
+        Triangle::new(Length::from_si(a), Length::from_si(b), Length::from_si(c))
+    }
+
+    /// Creates a triangle from two angles and the included side (ASA).
+    /// The side `c` lies between the angles `alpha` and `beta`.
+    pub fn from_asa(alpha: Angle, c: Length, beta: Angle) -> Result<Self, CalculationError> {
+        let (alpha, c, beta) = (alpha.si(), c.si(), beta.si());
+        ensure_positive("c", c)?;
+        ensure_positive("alpha", alpha)?;
+        ensure_positive("beta", beta)?;
+        if alpha + beta >= PI {
+            return Err(CalculationError::Inconsistent {
+                parameter: "beta",
+                value: beta,
+            });
+        }
+        let gamma = PI - alpha - beta;
+        let a = c * alpha.sin() / gamma.sin();
+        let b = c * beta.sin() / gamma.sin();
+        Triangle::new(Length::from_si(a), Length::from_si(b), Length::from_si(c))
+    }
+
+    /// Creates an isosceles triangle from its base `c` and height.
+    pub fn from_base_and_height(base: Length, height: Length) -> Result<Self, CalculationError> {
+        ensure_positive("base", base.si())?;
+        ensure_positive("height", height.si())?;
+        let leg = Length::from_si((height.si().powi(2) + (base.si() / 2.0).powi(2)).sqrt());
+        Triangle::new(leg, leg, base)
+    }
+
+    /// Returns the height measured perpendicular to side `c`.
+    pub fn height(&self) -> f64 {
+        2.0 * self.area() / self.c
+    }
+
+    /// Returns the vertices A, B and C with side `c` on the x-axis, starting at the origin.
+    pub fn vertices(&self) -> [(f64, f64); 3] {
+        let x = (self.b.powi(2) + self.c.powi(2) - self.a.powi(2)) / (2.0 * self.c);
+        [(0.0, 0.0), (self.c, 0.0), (x, self.height())]
+    }
+}
+
+impl Trapezoid {
+    /// Creates an isosceles trapezoid with the given parallel sides and height.
+    pub fn new(bottom: Length, top: Length, height: Length) -> Result<Self, CalculationError> {
+        let (bottom, top, height) = (bottom.si(), top.si(), height.si());
+        ensure_positive("bottom", bottom)?;
+        ensure_positive("top", top)?;
+        ensure_positive("height", height)?;
+        Ok(Trapezoid {
+            bottom,
+            top,
+            height,
+        })
+    }
+
+    /// Creates a trapezoid from its area and both parallel sides.
+    pub fn from_area_and_bases(
+        area: Area,
+        bottom: Length,
+        top: Length,
+    ) -> Result<Self, CalculationError> {
+        ensure_positive("area", area.si())?;
+        ensure_positive("bottom", bottom.si())?;
+        ensure_positive("top", top.si())?;
+        Trapezoid::new(bottom, top, area * 2.0 / (bottom + top))
+    }
+
+    /// Returns the length of one of the (equal) legs.
+    pub fn leg(&self) -> f64 {
+        (self.height.powi(2) + ((self.bottom - self.top) / 2.0).powi(2)).sqrt()
+    }
+}
+
+impl Ellipse {
+    /// Creates a new ellipse with the given semi-axes.
+    pub fn new(semi_major: Length, semi_minor: Length) -> Result<Self, CalculationError> {
+        let (semi_major, semi_minor) = (semi_major.si(), semi_minor.si());
+        ensure_positive("semi_major", semi_major)?;
+        ensure_positive("semi_minor", semi_minor)?;
+        if semi_minor > semi_major {
+            return Err(CalculationError::Inconsistent {
+                parameter: "semi_minor",
+                value: semi_minor,
+            });
+        }
+        Ok(Ellipse {
+            semi_major,
+            semi_minor,
+        })
+    }
+
+    /// Creates an ellipse from its area and semi-major axis, which is too short when the
+    /// area needs a longer semi-minor axis.
+    pub fn from_area_and_semi_axis(
+        area: Area,
+        semi_major: Length,
+    ) -> Result<Self, CalculationError> {
+        ensure_positive("area", area.si())?;
+        ensure_positive("semi_major", semi_major.si())?;
+        let semi_minor = area / (semi_major * PI);
+        if semi_minor > semi_major {
+            return Err(CalculationError::Inconsistent {
+                parameter: "semi_major",
+                value: semi_major.si(),
+            });
+        }
+        Ellipse::new(semi_major, semi_minor)
+    }
+}
+
+impl RegularPolygon {
+    /// Reads an entered number of sides, a whole number from 3 to [`MAX_SIDES`].
+    pub fn sides(count: f64) -> Result<u32, CalculationError> {
+        if count.fract() == 0.0 && (3.0..=MAX_SIDES as f64).contains(&count) {
+            Ok(count as u32)
+        } else {
+            Err(CalculationError::OutOfDomain {
+                parameter: "sides",
+                value: count,
+            })
+        }
+    }
+
+    /// Creates a regular polygon with `sides` sides of the given length.
+    pub fn new(sides: u32, side_length: Length) -> Result<Self, CalculationError> {
+        RegularPolygon::sides(sides as f64)?;
+        let side_length = side_length.si();
+        ensure_positive("side_length", side_length)?;
+        Ok(RegularPolygon { sides, side_length })
+    }
+
+    /// Creates a regular polygon from its circumradius (centre to vertex).
+    pub fn from_circumradius(sides: u32, circumradius: Length) -> Result<Self, CalculationError> {
+        ensure_positive("circumradius", circumradius.si())?;
+        let side_length = circumradius * (2.0 * (PI / sides.max(1) as f64).sin());
+        RegularPolygon::new(sides, side_length)
+    }
+
+    /// Creates a regular polygon from its apothem (centre to middle of a side).
+    pub fn from_apothem(sides: u32, apothem: Length) -> Result<Self, CalculationError> {
+        ensure_positive("apothem", apothem.si())?;
+        let side_length = apothem * (2.0 * (PI / sides.max(1) as f64).tan());
+        RegularPolygon::new(sides, side_length)
+    }
+
+    /// Returns the distance from the centre to a vertex.
+    pub fn circumradius(&self) -> f64 {
+        self.side_length / (2.0 * (PI / self.sides as f64).sin())
+    }
+
+    /// Returns the distance from the centre to the middle of a side.
+    pub fn apothem(&self) -> f64 {
+        self.side_length / (2.0 * (PI / self.sides as f64).tan())
+    }
+}
+
+impl Parallelogram {
+    /// Creates a parallelogram from its base, slanted side and the angle between them.
+    pub fn new(base: Length, side: Length, angle: Angle) -> Result<Self, CalculationError> {
+        let (base, side, angle) = (base.si(), side.si(), angle.si());
+        ensure_positive("base", base)?;
+        ensure_positive("side", side)?;
+        if !(angle > 0.0 && angle < PI) {
+            return Err(CalculationError::OutOfDomain {
+                parameter: "angle",
+                value: angle,
+            });
+        }
+        Ok(Parallelogram { base, side, angle })
+    }
+
+    /// Creates a parallelogram from its base, height and the angle between base and side.
+    pub fn from_base_and_height(
+        base: Length,
+        height: Length,
+        angle: Angle,
+    ) -> Result<Self, CalculationError> {
+        ensure_positive("height", height.si())?;
+        // The side follows from the angle, so it must be valid before dividing by it
+        if !(angle.si() > 0.0 && angle.si() < PI) {
+            return Err(CalculationError::OutOfDomain {
+                parameter: "angle",
+                value: angle.si(),
+            });
+        }
+        Parallelogram::new(base, height / angle.si().sin(), angle)
+    }
+
+    /// Returns the height measured perpendicular to the base.
+    pub fn height(&self) -> f64 {
+        self.side * self.angle.sin()
+    }
+}
+
+impl Default for Triangle {
+    fn default() -> Self {
+        Triangle {
+            a: 1.0,
+            b: 1.0,
+            c: 1.0,
+        }
+    }
+}
+
+impl Geometric2D for Triangle {
+    fn area(&self) -> f64 {
+        // Heron's formula in its numerically stable form (sides sorted a >= b >= c)
+        let mut s = [self.a, self.b, self.c];
+        s.sort_by(|x, y| y.total_cmp(x));
+        let [a, b, c] = s;
+        0.25 * ((a + (b + c)) * (c - (a - b)) * (c + (a - b)) * (a + (b - c))).sqrt()
+    }
+
+    fn perimeter(&self) -> f64 {
+        self.a + self.b + self.c
+    }
+}
+
+impl Default for Trapezoid {
+    fn default() -> Self {
+        Trapezoid {
+            bottom: 1.0,
+            top: 1.0,
+            height: 1.0,
+        }
+    }
+}
+
+impl Geometric2D for Trapezoid {
+    fn area(&self) -> f64 {
+        (self.bottom + self.top) / 2.0 * self.height
+    }
+
+    fn perimeter(&self) -> f64 {
+        self.bottom + self.top + 2.0 * self.leg()
+    }
+}
+
+impl Default for Ellipse {
+    fn default() -> Self {
+        Ellipse {
+            semi_major: 1.0,
+            semi_minor: 1.0,
+        }
+    }
+}
+
+impl Geometric2D for Ellipse {
+    fn area(&self) -> f64 {
+        PI * self.semi_major * self.semi_minor
+    }
+
+    /// Ramanujan's second approximation of the circumference.
+    fn perimeter(&self) -> f64 {
+        let (a, b) = (self.semi_major, self.semi_minor);
+        let h = ((a - b) / (a + b)).powi(2);
+        PI * (a + b) * (1.0 + 3.0 * h / (10.0 + (4.0 - 3.0 * h).sqrt()))
+    }
+}
+
+impl Default for RegularPolygon {
+    fn default() -> Self {
+        RegularPolygon {
+            sides: 6,
+            side_length: 1.0,
+        }
+    }
+}
+
+impl Geometric2D for RegularPolygon {
+    fn area(&self) -> f64 {
+        self.sides as f64 * self.side_length * self.apothem() / 2.0
+    }
+
+    fn perimeter(&self) -> f64 {
+        self.sides as f64 * self.side_length
+    }
+}
+
+impl Default for Parallelogram {
+    fn default() -> Self {
+        Parallelogram {
+            base: 1.0,
+            side: 1.0,
+            angle: PI / 2.0,
+        }
+    }
+}
+
+impl Geometric2D for Parallelogram {
+    fn area(&self) -> f64 {
+        self.base * self.height()
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * (self.base + self.side)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPS: f64 = 1e-9;
+
+    fn m(value: f64) -> Length {
+        Length::from_si(value)
+    }
+
+    fn rad(value: f64) -> Angle {
+        Angle::from_si(value)
+    }
+
+    #[test]
+    fn test_rectangle_and_circle_section_properties() {
+        let r = Rectangle::new(m(100.0), m(200.0)).unwrap();
+        assert!((r.ix() - 100.0 * 200f64.powi(3) / 12.0).abs() < EPS);
+        assert!((r.section_modulus_x() - 100.0 * 200f64.powi(2) / 6.0).abs() < 1e-6);
+        assert!((r.radius_of_gyration_x() - 200.0 / 12f64.sqrt()).abs() < EPS);
+
+        let c = Circle::new(m(10.0)).unwrap();
+        assert!((c.polar_moment() - PI * 10f64.powi(4) / 2.0).abs() < 1e-6);
+        assert!((c.section_modulus_y() - PI * 10f64.powi(3) / 4.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_triangle_sss() {
+        let t = Triangle::new(m(3.0), m(4.0), m(5.0)).unwrap();
+        assert!((t.area() - 6.0).abs() < EPS);
+        assert!((t.perimeter() - 12.0).abs() < EPS);
+        assert_eq!(
+            Triangle::new(m(1.0), m(2.0), m(3.0)).err(),
+            Some(CalculationError::Inconsistent {
+                parameter: "c",
+                value: 3.0
+            })
+        );
+        assert_eq!(
+            Triangle::new(m(-1.0), m(2.0), m(2.0)).err(),
+            Some(CalculationError::NonPositive {
+                parameter: "a",
+                value: -1.0
+            })
+        );
+    }
+
+    #[test]
+    fn test_triangle_sas_asa_and_base_height() {
+        let t = Triangle::from_sas(m(3.0), rad(PI / 2.0), m(4.0)).unwrap();
+        assert!((t.c - 5.0).abs() < EPS);
+
+        let t = Triangle::from_asa(rad(PI / 3.0), m(2.0), rad(PI / 3.0)).unwrap();
+        assert!((t.a - 2.0).abs() < EPS && (t.b - 2.0).abs() < EPS);
+        assert!(Triangle::from_asa(rad(PI / 2.0), m(1.0), rad(PI / 2.0)).is_err());
+
+        let t = Triangle::from_base_and_height(m(6.0), m(4.0)).unwrap();
+        assert!((t.area() - 12.0).abs() < EPS);
+        assert!((t.height() - 4.0).abs() < EPS);
+        let [_, _, (x, y)] = t.vertices();
+        assert!((x - 3.0).abs() < EPS && (y - 4.0).abs() < EPS);
+    }
+
+    #[test]
+    fn test_trapezoid() {
+        let t = Trapezoid::new(m(10.0), m(4.0), m(4.0)).unwrap();
+        assert!((t.area() - 28.0).abs() < EPS);
+        assert!((t.perimeter() - 24.0).abs() < EPS);
+        let t = Trapezoid::from_area_and_bases(Area::from_si(28.0), m(10.0), m(4.0)).unwrap();
+        assert!((t.height - 4.0).abs() < EPS);
+    }
+
+    #[test]
+    fn test_ellipse() {
+        let e = Ellipse::new(m(2.0), m(2.0)).unwrap();
+        assert!((e.perimeter() - Circle::new(m(2.0)).unwrap().perimeter()).abs() < EPS);
+        let e = Ellipse::new(m(5.0), m(3.0)).unwrap();
+        assert!((e.area() - 15.0 * PI).abs() < EPS);
+        assert!((e.perimeter() - 25.526998863398).abs() < 1e-6);
+        let e = Ellipse::from_area_and_semi_axis(Area::from_si(15.0 * PI), m(5.0)).unwrap();
+        assert!((e.semi_minor - 3.0).abs() < EPS);
+        assert_eq!(
+            Ellipse::new(m(3.0), m(5.0)).map(|_| ()),
+            Err(CalculationError::Inconsistent {
+                parameter: "semi_minor",
+                value: 5.0
+            })
+        );
+        // 3 is the semi-minor axis of this area, so it cannot be the semi-major one
+        assert!(Ellipse::from_area_and_semi_axis(Area::from_si(15.0 * PI), m(3.0)).is_err());
+    }
+
+    #[test]
+    fn test_regular_polygon() {
+        let square = RegularPolygon::new(4, m(2.0)).unwrap();
+        assert!((square.area() - 4.0).abs() < EPS);
+        assert!((square.apothem() - 1.0).abs() < EPS);
+        let hexagon = RegularPolygon::from_circumradius(6, m(1.0)).unwrap();
+        assert!((hexagon.side_length - 1.0).abs() < EPS);
+        let square = RegularPolygon::from_apothem(4, m(1.0)).unwrap();
+        assert!((square.side_length - 2.0).abs() < EPS);
+        assert!(RegularPolygon::new(2, m(1.0)).is_err());
+        assert!(RegularPolygon::new(MAX_SIDES + 1, m(1.0)).is_err());
+        assert_eq!(RegularPolygon::sides(6.0).unwrap(), 6);
+        assert!(RegularPolygon::sides(4e9).is_err());
+        assert!(RegularPolygon::sides(4.5).is_err());
+    }
+
+    #[test]
+    fn test_parallelogram() {
+        let p = Parallelogram::new(m(4.0), m(2.0), rad(PI / 6.0)).unwrap();
+        assert!((p.area() - 4.0).abs() < EPS);
+        assert!((p.perimeter() - 12.0).abs() < EPS);
+        let p = Parallelogram::from_base_and_height(m(4.0), m(1.0), rad(PI / 6.0)).unwrap();
+        assert!((p.side - 2.0).abs() < EPS);
+        assert!(Parallelogram::new(m(4.0), m(2.0), rad(PI)).is_err());
+        assert!(matches!(
+            Parallelogram::from_base_and_height(m(4.0), m(1.0), rad(0.0)),
+            Err(CalculationError::OutOfDomain {
+                parameter: "angle",
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,486 @@
+use crate::calculations::errors::*;
+use crate::calculations::shapes_2d::*;
+use crate::calculations::units::{Area, Length, Measured2D, Volume};
+use std::f64::consts::PI;
+
+pub struct Cuboid {
+    pub length: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+pub struct Cylinder {
+    pub radius: f64,
+    pub height: f64,
+}
+
+pub struct Sphere {
+    pub radius: f64,
+}
+
+pub struct Cone {
+    pub radius: f64,
+    pub height: f64,
+}
+
+/// Frustum of a right circular cone.
+pub struct Frustum {
+    pub bottom_radius: f64,
+    pub top_radius: f64,
+    pub height: f64,
+}
+
+/// Right prism made by extruding a 2D shape over the given height.
+pub struct Prism<S: Geometric2D> {
+    pub base: S,
+    pub height: f64,
+}
+
+/// Circular hollow tube, such as a pipe.
+pub struct HollowTube {
+    pub outer_diameter: f64,
+    pub wall_thickness: f64,
+    pub length: f64,
+}
+
+pub trait Geometric3D {
+    fn volume(&self) -> f64;
+    fn surface_area(&self) -> f64;
+
+    /// Mass of the solid for the given density.
+    fn mass(&self, density: f64) -> f64 {
+        self.volume() * density
+    }
+}
+
+impl Cuboid {
+    /// Creates a new cuboid with the given length, width and height.
+    pub fn new(length: Length, width: Length, height: Length) -> Result<Self, CalculationError> {
+        let (length, width, height) = (length.si(), width.si(), height.si());
+        ensure_positive("length", length)?;
+        ensure_positive("width", width)?;
+        ensure_positive("height", height)?;
+        Ok(Cuboid {
+            length,
+            width,
+            height,
+        })
+    }
+
+    /// Creates a cuboid from its volume, length and width.
+    pub fn from_volume(
+        volume: Volume,
+        length: Length,
+        width: Length,
+    ) -> Result<Self, CalculationError> {
+        ensure_positive("volume", volume.si())?;
+        ensure_positive("length", length.si())?;
+        ensure_positive("width", width.si())?;
+        Cuboid::new(length, width, volume / (length * width))
+    }
+}
+
+impl Cylinder {
+    /// Creates a new cylinder with the given radius and height.
+    pub fn new(radius: Length, height: Length) -> Result<Self, CalculationError> {
+        let (radius, height) = (radius.si(), height.si());
+        ensure_positive("radius", radius)?;
+        ensure_positive("height", height)?;
+        Ok(Cylinder { radius, height })
+    }
+
+    /// Creates a cylinder from its diameter and height.
+    pub fn from_diameter(diameter: Length, height: Length) -> Result<Self, CalculationError> {
+        ensure_positive("diameter", diameter.si())?;
+        Cylinder::new(diameter / 2.0, height)
+    }
+
+    /// Creates a cylinder from its volume and diameter.
+    pub fn from_volume_and_diameter(
+        volume: Volume,
+        diameter: Length,
+    ) -> Result<Self, CalculationError> {
+        ensure_positive("volume", volume.si())?;
+        ensure_positive("diameter", diameter.si())?;
+        Cylinder::new(diameter / 2.0, volume * 4.0 / (diameter * diameter * PI))
+    }
+
+    /// Creates a cylinder from its volume and height.
+    pub fn from_volume_and_height(
+        volume: Volume,
+        height: Length,
+    ) -> Result<Self, CalculationError> {
+        ensure_positive("volume", volume.si())?;
+        ensure_positive("height", height.si())?;
+        let base = volume / (height * PI);
+        Cylinder::new(Length::from_si(base.si().sqrt()), height)
+    }
+}
+
+impl Sphere {
+    /// Creates a new sphere with the given radius.
+    pub fn new(radius: Length) -> Result<Self, CalculationError> {
+        let radius = radius.si();
+        ensure_positive("radius", radius)?;
+        Ok(Sphere { radius })
+    }
+
+    /// Creates a sphere from its diameter.
+    pub fn from_diameter(diameter: Length) -> Result<Self, CalculationError> {
+        ensure_positive("diameter", diameter.si())?;
+        Sphere::new(diameter / 2.0)
+    }
+
+    /// Creates a sphere from its volume.
+    pub fn from_volume(volume: Volume) -> Result<Self, CalculationError> {
+        ensure_positive("volume", volume.si())?;
+        Sphere::new(Length::from_si((3.0 * volume.si() / (4.0 * PI)).cbrt()))
+    }
+
+    /// Creates a sphere from its surface area.
+    pub fn from_surface_area(surface_area: Area) -> Result<Self, CalculationError> {
+        ensure_positive("surface_area", surface_area.si())?;
+        Sphere::new(Length::from_si((surface_area.si() / (4.0 * PI)).sqrt()))
+    }
+}
+
+impl Cone {
+    /// Creates a new right circular cone with the given base radius and height.
+    pub fn new(radius: Length, height: Length) -> Result<Self, CalculationError> {
+        let (radius, height) = (radius.si(), height.si());
+        ensure_positive("radius", radius)?;
+        ensure_positive("height", height)?;
+        Ok(Cone { radius, height })
+    }
+
+    /// Creates a cone from its base diameter and height.
+    pub fn from_diameter(diameter: Length, height: Length) -> Result<Self, CalculationError> {
+        ensure_positive("diameter", diameter.si())?;
+        Cone::new(diameter / 2.0, height)
+    }
+
+    /// Creates a cone from its volume and base diameter.
+    pub fn from_volume_and_diameter(
+        volume: Volume,
+        diameter: Length,
+    ) -> Result<Self, CalculationError> {
+        ensure_positive("volume", volume.si())?;
+        ensure_positive("diameter", diameter.si())?;
+        Cone::new(diameter / 2.0, volume * 12.0 / (diameter * diameter * PI))
+    }
+
+    /// Returns the slant height from the base edge to the apex.
+    pub fn slant_height(&self) -> f64 {
+        (self.radius.powi(2) + self.height.powi(2)).sqrt()
+    }
+}
+
+impl Frustum {
+    /// Creates a new frustum with the given bottom and top radius and height.
+    pub fn new(
+        bottom_radius: Length,
+        top_radius: Length,
+        height: Length,
+    ) -> Result<Self, CalculationError> {
+        let (bottom_radius, top_radius, height) =
+            (bottom_radius.si(), top_radius.si(), height.si());
+        ensure_positive("bottom_radius", bottom_radius)?;
+        ensure_positive("height", height)?;
+        if !(0.0..f64::INFINITY).contains(&top_radius) {
+            return Err(CalculationError::OutOfDomain {
+                parameter: "top_radius",
+                value: top_radius,
+            });
+        }
+        Ok(Frustum {
+            bottom_radius,
+            top_radius,
+            height,
+        })
+    }
+
+    /// Creates a frustum from its bottom and top diameter and height; a top diameter
+    /// of 0 gives a cone.
+    pub fn from_diameters(
+        bottom_diameter: Length,
+        top_diameter: Length,
+        height: Length,
+    ) -> Result<Self, CalculationError> {
+        ensure_positive("bottom_diameter", bottom_diameter.si())?;
+        if !(0.0..f64::INFINITY).contains(&top_diameter.si()) {
+            return Err(CalculationError::OutOfDomain {
+                parameter: "top_diameter",
+                value: top_diameter.si(),
+            });
+        }
+        Frustum::new(bottom_diameter / 2.0, top_diameter / 2.0, height)
+    }
+
+    /// Creates a frustum from its volume and both diameters.
+    pub fn from_volume_and_diameters(
+        volume: Volume,
+        bottom_diameter: Length,
+        top_diameter: Length,
+    ) -> Result<Self, CalculationError> {
+        ensure_positive("volume", volume.si())?;
+        let (d1, d2) = (bottom_diameter, top_diameter);
+        let height = volume * 12.0 / ((d1 * d1 + d1 * d2 + d2 * d2) * PI);
+        Frustum::from_diameters(bottom_diameter, top_diameter, height)
+    }
+
+    /// Returns the slant height of the side surface.
+    pub fn slant_height(&self) -> f64 {
+        ((self.bottom_radius - self.top_radius).powi(2) + self.height.powi(2)).sqrt()
+    }
+}
+
+impl<S: Geometric2D> Prism<S> {
+    /// Creates a prism by extruding `base` over `height`.
+    pub fn new(base: S, height: Length) -> Result<Self, CalculationError> {
+        let height = height.si();
+        ensure_positive("height", height)?;
+        Ok(Prism { base, height })
+    }
+
+    /// Creates a prism from its base shape and volume.
+    pub fn from_volume(base: S, volume: Volume) -> Result<Self, CalculationError> {
+        ensure_positive("volume", volume.si())?;
+        let height = volume / base.area_quantity();
+        Prism::new(base, height)
+    }
+}
+
+impl HollowTube {
+    /// Creates a tube from its outer diameter, wall thickness and length.
+    pub fn new(
+        outer_diameter: Length,
+        wall_thickness: Length,
+        length: Length,
+    ) -> Result<Self, CalculationError> {
+        let (outer_diameter, wall_thickness, length) =
+            (outer_diameter.si(), wall_thickness.si(), length.si());
+        ensure_positive("outer_diameter", outer_diameter)?;
+        ensure_positive("wall_thickness", wall_thickness)?;
+        ensure_positive("length", length)?;
+        if 2.0 * wall_thickness >= outer_diameter {
+            return Err(CalculationError::Inconsistent {
+                parameter: "wall_thickness",
+                value: wall_thickness,
+            });
+        }
+        Ok(HollowTube {
+            outer_diameter,
+            wall_thickness,
+            length,
+        })
+    }
+
+    /// Creates a tube from its volume, outer diameter and wall thickness.
+    pub fn from_volume(
+        volume: Volume,
+        outer_diameter: Length,
+        wall_thickness: Length,
+    ) -> Result<Self, CalculationError> {
+        ensure_positive("volume", volume.si())?;
+        let inner_diameter = outer_diameter - wall_thickness * 2.0;
+        let ring = (outer_diameter * outer_diameter - inner_diameter * inner_diameter) * (PI / 4.0);
+        HollowTube::new(outer_diameter, wall_thickness, volume / ring)
+    }
+
+    /// Returns the inner diameter.
+    pub fn inner_diameter(&self) -> f64 {
+        self.outer_diameter - 2.0 * self.wall_thickness
+    }
+}
+
+impl Geometric3D for Cuboid {
+    fn volume(&self) -> f64 {
+        self.length * self.width * self.height
+    }
+
+    fn surface_area(&self) -> f64 {
+        2.0 * (self.length * self.width + self.length * self.height + self.width * self.height)
+    }
+}
+
+impl Geometric3D for Cylinder {
+    fn volume(&self) -> f64 {
+        PI * self.radius.powi(2) * self.height
+    }
+
+    fn surface_area(&self) -> f64 {
+        2.0 * PI * self.radius * (self.radius + self.height)
+    }
+}
+
+impl Geometric3D for Sphere {
+    fn volume(&self) -> f64 {
+        4.0 / 3.0 * PI * self.radius.powi(3)
+    }
+
+    fn surface_area(&self) -> f64 {
+        4.0 * PI * self.radius.powi(2)
+    }
+}
+
+impl Geometric3D for Cone {
+    fn volume(&self) -> f64 {
+        PI * self.radius.powi(2) * self.height / 3.0
+    }
+
+    fn surface_area(&self) -> f64 {
+        PI * self.radius * (self.radius + self.slant_height())
+    }
+}
+
+impl Geometric3D for Frustum {
+    fn volume(&self) -> f64 {
+        let (r1, r2) = (self.bottom_radius, self.top_radius);
+        PI * self.height / 3.0 * (r1.powi(2) + r1 * r2 + r2.powi(2))
+    }
+
+    fn surface_area(&self) -> f64 {
+        let (r1, r2) = (self.bottom_radius, self.top_radius);
+        PI * (r1.powi(2) + r2.powi(2)) + PI * (r1 + r2) * self.slant_height()
+    }
+}
+
+impl<S: Geometric2D> Geometric3D for Prism<S> {
+    fn volume(&self) -> f64 {
+        self.base.area() * self.height
+    }
+
+    fn surface_area(&self) -> f64 {
+        2.0 * self.base.area() + self.base.perimeter() * self.height
+    }
+}
+
+impl Geometric3D for HollowTube {
+    fn volume(&self) -> f64 {
+        PI / 4.0 * (self.outer_diameter.powi(2) - self.inner_diameter().powi(2)) * self.length
+    }
+
+    /// Outer and inner mantle plus both ring-shaped ends.
+    fn surface_area(&self) -> f64 {
+        let (d_o, d_i) = (self.outer_diameter, self.inner_diameter());
+        PI * (d_o + d_i) * self.length + 2.0 * PI / 4.0 * (d_o.powi(2) - d_i.powi(2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPS: f64 = 1e-9;
+
+    fn m(value: f64) -> Length {
+        Length::from_si(value)
+    }
+
+    fn m3(value: f64) -> Volume {
+        Volume::from_si(value)
+    }
+
+    #[test]
+    fn test_cuboid_and_mass() {
+        let c = Cuboid::new(m(2.0), m(3.0), m(4.0)).unwrap();
+        assert!((c.volume() - 24.0).abs() < EPS);
+        assert!((c.surface_area() - 52.0).abs() < EPS);
+        // 1 m x 1 m x 10 mm steel plate
+        let plate = Cuboid::new(m(1.0), m(1.0), m(0.01)).unwrap();
+        assert!((plate.mass(7850.0) - 78.5).abs() < EPS);
+        assert!(
+            (Cuboid::from_volume(m3(24.0), m(2.0), m(3.0))
+                .unwrap()
+                .height
+                - 4.0)
+                .abs()
+                < EPS
+        );
+        // The derived height overflows
+        assert!(Cuboid::from_volume(m3(1e300), m(1e-300), m(1e-300)).is_err());
+    }
+
+    #[test]
+    fn test_cylinder_sphere_cone() {
+        let cyl = Cylinder::from_volume_and_diameter(m3(PI * 8.0), m(4.0)).unwrap();
+        assert!((cyl.height - 2.0).abs() < EPS);
+        assert!(
+            (Cylinder::from_volume_and_height(m3(PI * 8.0), m(2.0))
+                .unwrap()
+                .radius
+                - 2.0)
+                .abs()
+                < EPS
+        );
+        assert!(Cylinder::from_volume_and_height(m3(1e300), m(1e-300)).is_err());
+
+        let s = Sphere::from_volume(m3(4.0 / 3.0 * PI * 27.0)).unwrap();
+        assert!((s.radius - 3.0).abs() < EPS);
+        assert!(
+            (Sphere::from_surface_area(Area::from_si(s.surface_area()))
+                .unwrap()
+                .radius
+                - 3.0)
+                .abs()
+                < EPS
+        );
+
+        let cone = Cone::new(m(3.0), m(4.0)).unwrap();
+        assert!((cone.surface_area() - PI * 3.0 * 8.0).abs() < EPS);
+        assert!(
+            (Cone::from_volume_and_diameter(m3(cone.volume()), m(6.0))
+                .unwrap()
+                .height
+                - 4.0)
+                .abs()
+                < EPS
+        );
+    }
+
+    #[test]
+    fn test_frustum_reduces_to_cone_and_cylinder() {
+        let f = Frustum::new(m(3.0), m(0.0), m(4.0)).unwrap();
+        let cone = Cone::new(m(3.0), m(4.0)).unwrap();
+        assert!((f.volume() - cone.volume()).abs() < EPS);
+        let f = Frustum::new(m(2.0), m(2.0), m(5.0)).unwrap();
+        let cyl = Cylinder::new(m(2.0), m(5.0)).unwrap();
+        assert!((f.volume() - cyl.volume()).abs() < EPS);
+        assert!((f.surface_area() - cyl.surface_area()).abs() < EPS);
+
+        let f = Frustum::from_diameters(m(6.0), m(0.0), m(4.0)).unwrap();
+        assert!((f.volume() - cone.volume()).abs() < EPS);
+        assert!(matches!(
+            Frustum::from_diameters(m(6.0), m(-1.0), m(4.0)),
+            Err(CalculationError::OutOfDomain {
+                parameter: "top_diameter",
+                ..
+            })
+        ));
+        let f = Frustum::from_volume_and_diameters(m3(cone.volume()), m(6.0), m(0.0)).unwrap();
+        assert!((f.height - 4.0).abs() < EPS);
+        assert!((Cone::from_diameter(m(6.0), m(4.0)).unwrap().radius - 3.0).abs() < EPS);
+    }
+
+    #[test]
+    fn test_prism_and_tube() {
+        let prism = Prism::new(Rectangle::new(m(2.0), m(3.0)).unwrap(), m(4.0)).unwrap();
+        let cuboid = Cuboid::new(m(2.0), m(3.0), m(4.0)).unwrap();
+        assert!((prism.volume() - cuboid.volume()).abs() < EPS);
+        assert!((prism.surface_area() - cuboid.surface_area()).abs() < EPS);
+        let prism = Prism::from_volume(Circle::new(m(1.0)).unwrap(), m3(PI * 3.0)).unwrap();
+        assert!((prism.height - 3.0).abs() < EPS);
+
+        let tube = HollowTube::new(m(0.1), m(0.01), m(2.0)).unwrap();
+        assert!((tube.volume() - PI / 4.0 * (0.01 - 0.0064) * 2.0).abs() < EPS);
+        assert!(
+            (HollowTube::from_volume(m3(tube.volume()), m(0.1), m(0.01))
+                .unwrap()
+                .length
+                - 2.0)
+                .abs()
+                < EPS
+        );
+        assert!(HollowTube::new(m(0.1), m(0.05), m(1.0)).is_err());
+    }
+}
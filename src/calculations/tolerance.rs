@@ -0,0 +1,189 @@
+//! Propagation of input tolerances through a calculation.
+//!
+//! Inputs such as `100 ± 0.2 mm` are [`Toleranced`] values. [`propagate`] finds for
+//! every result the worst-case range, by calculating with each combination of the
+//! input bounds, and the first-order uncertainty, the root sum of squares of the
+//! change each tolerance causes on its own. The worst case is exact for results
+//! that grow or shrink steadily with each input, as the shapes' results do within
+//! a tolerance.
+
+use crate::calculations::errors::CalculationError;
+
+/// Inputs with a tolerance beyond which every combination of bounds is no longer
+/// calculated; the others count as exact for the worst case.
+const MAX_CORNER_INPUTS: usize = 10;
+
+/// A nominal value with a symmetric tolerance, both in the same unit.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Toleranced {
+    pub nominal: f64,
+    pub tolerance: f64,
+}
+
+impl Toleranced {
+    pub fn new(nominal: f64, tolerance: f64) -> Self {
+        Toleranced {
+            nominal,
+            tolerance: tolerance.abs(),
+        }
+    }
+
+    /// A value without tolerance.
+    pub fn exact(nominal: f64) -> Self {
+        Toleranced::new(nominal, 0.0)
+    }
+
+    pub fn is_exact(&self) -> bool {
+        self.tolerance == 0.0
+    }
+
+    /// The value and tolerance converted by a linear function, such as to SI units.
+    pub fn map(self, convert: impl Fn(f64) -> f64) -> Self {
+        let nominal = convert(self.nominal);
+        Toleranced::new(nominal, convert(self.nominal + self.tolerance) - nominal)
+    }
+}
+
+/// A result with the range its inputs' tolerances allow.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Propagated {
+    pub nominal: f64,
+    /// Smallest value at any combination of the input bounds.
+    pub min: f64,
+    /// Largest value at any combination of the input bounds.
+    pub max: f64,
+    /// First-order (root-sum-square) uncertainty.
+    pub uncertainty: f64,
+}
+
+impl Propagated {
+    /// The range of an input itself.
+    pub fn of(value: Toleranced) -> Self {
+        Propagated {
+            nominal: value.nominal,
+            min: value.nominal - value.tolerance,
+            max: value.nominal + value.tolerance,
+            uncertainty: value.tolerance,
+        }
+    }
+
+    pub fn is_exact(&self) -> bool {
+        self.min == self.max
+    }
+
+    /// The range converted by an increasing linear function, such as to a unit.
+    pub fn map(self, convert: impl Fn(f64) -> f64) -> Self {
+        let nominal = convert(self.nominal);
+        Propagated {
+            nominal,
+            min: convert(self.min),
+            max: convert(self.max),
+            uncertainty: convert(self.nominal + self.uncertainty) - nominal,
+        }
+    }
+}
+
+/// Calculates `compute` at the nominal inputs and at their bounds, giving the range
+/// of every result. Fails if any combination of bounds can't be calculated, such as
+/// a length whose tolerance reaches zero.
+pub fn propagate(
+    inputs: &[Toleranced],
+    compute: impl Fn(&[f64]) -> Result<Vec<f64>, CalculationError>,
+) -> Result<Vec<Propagated>, CalculationError> {
+    let nominal: Vec<f64> = inputs.iter().map(|input| input.nominal).collect();
+    let mut results: Vec<Propagated> = compute(&nominal)?
+        .into_iter()
+        .map(|value| Propagated::of(Toleranced::exact(value)))
+        .collect();
+
+    let toleranced: Vec<usize> = (0..inputs.len())
+        .filter(|index| !inputs[*index].is_exact())
+        .collect();
+    // Every combination of lower and upper bounds, one bit per input
+    let corner_inputs = &toleranced[..toleranced.len().min(MAX_CORNER_INPUTS)];
+    let corners = if corner_inputs.is_empty() {
+        0
+    } else {
+        1usize << corner_inputs.len()
+    };
+    for corner in 0..corners {
+        let mut values = nominal.clone();
+        for (bit, index) in corner_inputs.iter().enumerate() {
+            let sign = if corner & (1 << bit) == 0 { -1.0 } else { 1.0 };
+            values[*index] += sign * inputs[*index].tolerance;
+        }
+        include(&mut results, &compute(&values)?);
+    }
+
+    // Each tolerance on its own, for the first-order change it causes
+    let mut squares = vec![0.0; results.len()];
+    for index in toleranced {
+        let at = |sign: f64| {
+            let mut values = nominal.clone();
+            values[index] += sign * inputs[index].tolerance;
+            compute(&values)
+        };
+        let (low, high) = (at(-1.0)?, at(1.0)?);
+        include(&mut results, &low);
+        include(&mut results, &high);
+        for (square, (low, high)) in squares.iter_mut().zip(low.iter().zip(&high)) {
+            *square += ((high - low) / 2.0).powi(2);
+        }
+    }
+    for (result, square) in results.iter_mut().zip(squares) {
+        result.uncertainty = square.sqrt();
+    }
+    Ok(results)
+}
+
+/// Widens the ranges of the results to hold the values of one calculation.
+fn include(results: &mut [Propagated], values: &[f64]) {
+    for (result, value) in results.iter_mut().zip(values) {
+        result.min = result.min.min(*value);
+        result.max = result.max.max(*value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculations::shapes_2d::{Geometric2D, Rectangle};
+    use crate::calculations::units::Length;
+
+    #[test]
+    fn test_propagate() {
+        let area = |v: &[f64]| {
+            Ok(vec![Rectangle::new(
+                Length::from_si(v[0]),
+                Length::from_si(v[1]),
+            )?
+            .area()])
+        };
+        let inputs = [Toleranced::new(100.0, 0.2), Toleranced::new(50.0, 0.1)];
+        let [result] = propagate(&inputs, area).unwrap()[..] else {
+            panic!("one result");
+        };
+        assert_eq!(result.nominal, 5000.0);
+        assert!((result.min - 99.8 * 49.9).abs() < 1e-9);
+        assert!((result.max - 100.2 * 50.1).abs() < 1e-9);
+        // √((50 · 0.2)² + (100 · 0.1)²)
+        assert!((result.uncertainty - 200.0_f64.sqrt()).abs() < 1e-9);
+
+        let exact = propagate(&[Toleranced::exact(2.0), Toleranced::exact(3.0)], area).unwrap();
+        assert!(exact[0].is_exact());
+        assert_eq!(exact[0].uncertainty, 0.0);
+
+        // A tolerance reaching past zero can't be calculated
+        assert!(propagate(&[Toleranced::new(0.1, 0.2), Toleranced::exact(1.0)], area).is_err());
+    }
+
+    #[test]
+    fn test_map() {
+        let millimetres = Toleranced::new(100.0, 0.2).map(|value| value / 1000.0);
+        assert!((millimetres.nominal - 0.1).abs() < 1e-15);
+        assert!((millimetres.tolerance - 0.0002).abs() < 1e-15);
+        let range = Propagated::of(millimetres).map(|value| value * 1000.0);
+        assert!((range.max - 100.2).abs() < 1e-9);
+        assert!((range.uncertainty - 0.2).abs() < 1e-9);
+    }
+}
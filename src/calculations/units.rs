@@ -0,0 +1,589 @@
+//! Physical units and dimension-safe quantities.
+//!
+//! A [`Quantity`] stores its value in the SI unit of its dimension, so values
+//! entered in different units can be combined safely. Multiplying or dividing
+//! quantities yields the quantity of the resulting dimension, and mixing
+//! incompatible dimensions does not compile.
+//!
+//! The shapes in `shapes_2d`, `shapes_3d`, `sections` and `composite` are built
+//! from quantities and keep their dimensions as plain `f64`s in SI units; their
+//! results come back as quantities through [`Measured2D`] and its siblings.
+
+use crate::calculations::errors::CalculationError;
+use crate::calculations::shapes_2d::*;
+use crate::calculations::shapes_3d::*;
+use std::marker::PhantomData;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A unit of measurement for one physical dimension.
+pub trait Unit: Copy + PartialEq + Send + Sync + 'static {
+    /// Every unit of this dimension, in the order they are offered to the user.
+    const ALL: &'static [Self];
+
+    fn symbol(&self) -> &'static str;
+
+    /// Factor to convert a value in this unit to the SI unit.
+    fn factor(&self) -> f64;
+
+    /// Offset added after scaling, only non-zero for affine units such as °C.
+    fn offset(&self) -> f64 {
+        0.0
+    }
+
+    fn to_si(&self, value: f64) -> f64 {
+        value * self.factor() + self.offset()
+    }
+
+    fn to_unit(&self, value: f64) -> f64 {
+        (value - self.offset()) / self.factor()
+    }
+
+    /// Looks up a unit by its symbol.
+    fn from_symbol(symbol: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|unit| unit.symbol() == symbol)
+    }
+
+    /// Looks up the unit of `parameter` by its symbol, failing for units of another dimension.
+    fn parse(parameter: &'static str, symbol: &str) -> Result<Self, CalculationError> {
+        Self::from_symbol(symbol).ok_or_else(|| CalculationError::UnitMismatch {
+            parameter,
+            unit: symbol.to_string(),
+        })
+    }
+}
+
+/// A physical dimension, such as length or pressure.
+pub trait Dimension: Send + Sync + 'static {
+    type Unit: Unit;
+}
+
+/// A value of dimension `D`, stored in SI units.
+pub struct Quantity<D: Dimension> {
+    si: f64,
+    dimension: PhantomData<D>,
+}
+
+impl<D: Dimension> Quantity<D> {
+    /// Creates a quantity from a value in the given unit.
+    pub fn new(value: f64, unit: D::Unit) -> Self {
+        Self::from_si(unit.to_si(value))
+    }
+
+    /// Creates a quantity from a value in the SI unit.
+    pub fn from_si(si: f64) -> Self {
+        Quantity {
+            si,
+            dimension: PhantomData,
+        }
+    }
+
+    /// Returns the value in the SI unit.
+    pub fn si(&self) -> f64 {
+        self.si
+    }
+
+    /// Returns the value in the given unit.
+    pub fn value_in(&self, unit: D::Unit) -> f64 {
+        unit.to_unit(self.si)
+    }
+}
+
+// Manual impls, so `D` itself does not need to implement these traits
+impl<D: Dimension> Clone for Quantity<D> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<D: Dimension> Copy for Quantity<D> {}
+
+impl<D: Dimension> Default for Quantity<D> {
+    fn default() -> Self {
+        Self::from_si(0.0)
+    }
+}
+
+impl<D: Dimension> PartialEq for Quantity<D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.si == other.si
+    }
+}
+
+impl<D: Dimension> PartialOrd for Quantity<D> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.si.partial_cmp(&other.si)
+    }
+}
+
+impl<D: Dimension> std::fmt::Debug for Quantity<D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.si, D::Unit::ALL[0].symbol())
+    }
+}
+
+impl<D: Dimension> Add for Quantity<D> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::from_si(self.si + rhs.si)
+    }
+}
+
+impl<D: Dimension> Sub for Quantity<D> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_si(self.si - rhs.si)
+    }
+}
+
+impl<D: Dimension> Neg for Quantity<D> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::from_si(-self.si)
+    }
+}
+
+impl<D: Dimension> Mul<f64> for Quantity<D> {
+    type Output = Self;
+    fn mul(self, rhs: f64) -> Self {
+        Self::from_si(self.si * rhs)
+    }
+}
+
+impl<D: Dimension> Div<f64> for Quantity<D> {
+    type Output = Self;
+    fn div(self, rhs: f64) -> Self {
+        Self::from_si(self.si / rhs)
+    }
+}
+
+/// The ratio of two quantities of the same dimension is a plain number.
+impl<D: Dimension> Div for Quantity<D> {
+    type Output = f64;
+    fn div(self, rhs: Self) -> f64 {
+        self.si / rhs.si
+    }
+}
+
+/// Declares `lhs op rhs = output` for quantities of different dimensions.
+macro_rules! dimension_product {
+    ($lhs:ident * $rhs:ident = $out:ident) => {
+        impl Mul<Quantity<dimension::$rhs>> for Quantity<dimension::$lhs> {
+            type Output = Quantity<dimension::$out>;
+            fn mul(self, rhs: Quantity<dimension::$rhs>) -> Self::Output {
+                Quantity::from_si(self.si * rhs.si)
+            }
+        }
+    };
+    ($lhs:ident / $rhs:ident = $out:ident) => {
+        impl Div<Quantity<dimension::$rhs>> for Quantity<dimension::$lhs> {
+            type Output = Quantity<dimension::$out>;
+            fn div(self, rhs: Quantity<dimension::$rhs>) -> Self::Output {
+                Quantity::from_si(self.si / rhs.si)
+            }
+        }
+    };
+}
+
+dimension_product!(Length * Length = Area);
+dimension_product!(Area * Length = Volume);
+dimension_product!(Length * Area = Volume);
+dimension_product!(Area * Area = SecondMomentOfArea);
+dimension_product!(Area / Length = Length);
+dimension_product!(Volume / Length = Area);
+dimension_product!(Volume / Area = Length);
+dimension_product!(SecondMomentOfArea / Area = Area);
+dimension_product!(Volume * Density = Mass);
+dimension_product!(Density * Volume = Mass);
+dimension_product!(Mass / Volume = Density);
+dimension_product!(Force / Area = Pressure);
+dimension_product!(Pressure * Area = Force);
+dimension_product!(Area * Pressure = Force);
+dimension_product!(Force * Length = Torque);
+dimension_product!(Torque / Length = Force);
+dimension_product!(Pressure * FlowRate = Power);
+dimension_product!(FlowRate * Pressure = Power);
+
+/// Declares a unit enum for a dimension. Each unit is listed with its symbol
+/// and the factor that converts it to the SI unit, which must come first.
+macro_rules! unit_enum {
+    ($dimension:ident, $name:ident { $($variant:ident => ($symbol:expr, $factor:expr)),+ $(,)? }) => {
+        #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+        #[allow(clippy::enum_variant_names)]
+        pub enum $name {
+            $($variant),+
+        }
+
+        impl Unit for $name {
+            const ALL: &'static [Self] = &[$($name::$variant),+];
+
+            fn symbol(&self) -> &'static str {
+                match self {
+                    $($name::$variant => $symbol),+
+                }
+            }
+
+            fn factor(&self) -> f64 {
+                match self {
+                    $($name::$variant => $factor),+
+                }
+            }
+        }
+
+        impl Dimension for dimension::$dimension {
+            type Unit = $name;
+        }
+
+        pub type $dimension = Quantity<dimension::$dimension>;
+    };
+}
+
+/// Marker types for the physical dimensions.
+pub mod dimension {
+    pub struct Length;
+    pub struct Area;
+    pub struct Volume;
+    pub struct SecondMomentOfArea;
+    pub struct Mass;
+    pub struct Density;
+    pub struct Force;
+    pub struct Pressure;
+    pub struct Energy;
+    pub struct Power;
+    pub struct Torque;
+    pub struct FlowRate;
+    pub struct Temperature;
+    pub struct Angle;
+}
+
+const INCH: f64 = 0.0254;
+const FOOT: f64 = 0.3048;
+const YARD: f64 = 0.9144;
+const MILE: f64 = 1_609.344;
+const US_GALLON: f64 = 3.785_411_784e-3;
+const IMPERIAL_GALLON: f64 = 4.546_09e-3;
+const POUND: f64 = 0.453_592_37;
+const STANDARD_GRAVITY: f64 = 9.806_65;
+const POUND_FORCE: f64 = 4.448_221_615_260_5;
+const CALORIE: f64 = 4.186_8;
+const BTU: f64 = 1_055.055_852_62;
+const HORSEPOWER: f64 = 745.699_871_582_27;
+
+unit_enum!(Length, LengthUnit {
+    Metre => ("m", 1.0),
+    Millimetre => ("mm", 1e-3),
+    Centimetre => ("cm", 1e-2),
+    Kilometre => ("km", 1e3),
+    Micrometre => ("µm", 1e-6),
+    Inch => ("in", INCH),
+    Foot => ("ft", FOOT),
+    Yard => ("yd", YARD),
+    Mile => ("mi", MILE),
+});
+
+unit_enum!(Area, AreaUnit {
+    SquareMetre => ("m²", 1.0),
+    SquareMillimetre => ("mm²", 1e-6),
+    SquareCentimetre => ("cm²", 1e-4),
+    SquareKilometre => ("km²", 1e6),
+    Hectare => ("ha", 1e4),
+    SquareInch => ("in²", INCH * INCH),
+    SquareFoot => ("ft²", FOOT * FOOT),
+    SquareYard => ("yd²", YARD * YARD),
+    Acre => ("ac", 4_840.0 * YARD * YARD),
+});
+
+unit_enum!(Volume, VolumeUnit {
+    CubicMetre => ("m³", 1.0),
+    CubicMillimetre => ("mm³", 1e-9),
+    CubicCentimetre => ("cm³", 1e-6),
+    Litre => ("L", 1e-3),
+    Millilitre => ("mL", 1e-6),
+    CubicInch => ("in³", INCH * INCH * INCH),
+    CubicFoot => ("ft³", FOOT * FOOT * FOOT),
+    CubicYard => ("yd³", YARD * YARD * YARD),
+    UsGallon => ("gal (US)", US_GALLON),
+    ImperialGallon => ("gal (imp)", IMPERIAL_GALLON),
+    UsFluidOunce => ("fl oz (US)", US_GALLON / 128.0),
+});
+
+unit_enum!(SecondMomentOfArea, SecondMomentOfAreaUnit {
+    MetreToTheFourth => ("m⁴", 1.0),
+    MillimetreToTheFourth => ("mm⁴", 1e-12),
+    CentimetreToTheFourth => ("cm⁴", 1e-8),
+    InchToTheFourth => ("in⁴", INCH * INCH * INCH * INCH),
+});
+
+unit_enum!(Mass, MassUnit {
+    Kilogram => ("kg", 1.0),
+    Gram => ("g", 1e-3),
+    Tonne => ("t", 1e3),
+    Milligram => ("mg", 1e-6),
+    Pound => ("lb", POUND),
+    Ounce => ("oz", POUND / 16.0),
+    ShortTon => ("ton (US)", 2_000.0 * POUND),
+    LongTon => ("ton (UK)", 2_240.0 * POUND),
+});
+
+unit_enum!(Density, DensityUnit {
+    KilogramPerCubicMetre => ("kg/m³", 1.0),
+    GramPerCubicCentimetre => ("g/cm³", 1e3),
+    PoundPerCubicFoot => ("lb/ft³", POUND / (FOOT * FOOT * FOOT)),
+});
+
+unit_enum!(Force, ForceUnit {
+    Newton => ("N", 1.0),
+    Kilonewton => ("kN", 1e3),
+    Meganewton => ("MN", 1e6),
+    KilogramForce => ("kgf", STANDARD_GRAVITY),
+    PoundForce => ("lbf", POUND_FORCE),
+    Kip => ("kip", 1e3 * POUND_FORCE),
+});
+
+unit_enum!(Pressure, PressureUnit {
+    Pascal => ("Pa", 1.0),
+    Kilopascal => ("kPa", 1e3),
+    Megapascal => ("MPa", 1e6),
+    Gigapascal => ("GPa", 1e9),
+    NewtonPerSquareMillimetre => ("N/mm²", 1e6),
+    Bar => ("bar", 1e5),
+    Millibar => ("mbar", 1e2),
+    Atmosphere => ("atm", 101_325.0),
+    MillimetreOfMercury => ("mmHg", 133.322_387_415),
+    Psi => ("psi", POUND_FORCE / (INCH * INCH)),
+    Ksi => ("ksi", 1e3 * POUND_FORCE / (INCH * INCH)),
+    Psf => ("psf", POUND_FORCE / (FOOT * FOOT)),
+});
+
+unit_enum!(Energy, EnergyUnit {
+    Joule => ("J", 1.0),
+    Kilojoule => ("kJ", 1e3),
+    Megajoule => ("MJ", 1e6),
+    WattHour => ("Wh", 3.6e3),
+    KilowattHour => ("kWh", 3.6e6),
+    Calorie => ("cal", CALORIE),
+    Kilocalorie => ("kcal", 1e3 * CALORIE),
+    BritishThermalUnit => ("BTU", BTU),
+    FootPoundForce => ("ft·lbf", FOOT * POUND_FORCE),
+});
+
+unit_enum!(Power, PowerUnit {
+    Watt => ("W", 1.0),
+    Kilowatt => ("kW", 1e3),
+    Megawatt => ("MW", 1e6),
+    MetricHorsepower => ("PS", 75.0 * STANDARD_GRAVITY),
+    Horsepower => ("hp", HORSEPOWER),
+    BritishThermalUnitPerHour => ("BTU/h", BTU / 3_600.0),
+    FootPoundForcePerSecond => ("ft·lbf/s", FOOT * POUND_FORCE),
+});
+
+unit_enum!(Torque, TorqueUnit {
+    NewtonMetre => ("N·m", 1.0),
+    KilonewtonMetre => ("kN·m", 1e3),
+    NewtonMillimetre => ("N·mm", 1e-3),
+    KilogramForceMetre => ("kgf·m", STANDARD_GRAVITY),
+    PoundForceFoot => ("lbf·ft", POUND_FORCE * FOOT),
+    PoundForceInch => ("lbf·in", POUND_FORCE * INCH),
+});
+
+unit_enum!(FlowRate, FlowRateUnit {
+    CubicMetrePerSecond => ("m³/s", 1.0),
+    CubicMetrePerHour => ("m³/h", 1.0 / 3_600.0),
+    LitrePerSecond => ("L/s", 1e-3),
+    LitrePerMinute => ("L/min", 1e-3 / 60.0),
+    UsGallonPerMinute => ("gal/min (US)", US_GALLON / 60.0),
+    ImperialGallonPerMinute => ("gal/min (imp)", IMPERIAL_GALLON / 60.0),
+    CubicFootPerMinute => ("ft³/min", FOOT * FOOT * FOOT / 60.0),
+});
+
+unit_enum!(Angle, AngleUnit {
+    Radian => ("rad", 1.0),
+    Degree => ("°", std::f64::consts::PI / 180.0),
+    Arcminute => ("′", std::f64::consts::PI / 10_800.0),
+    Arcsecond => ("″", std::f64::consts::PI / 648_000.0),
+    Gradian => ("gon", std::f64::consts::PI / 200.0),
+    Turn => ("rev", std::f64::consts::TAU),
+});
+
+/// Temperature units are affine, so they are not declared with `unit_enum!`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TemperatureUnit {
+    Kelvin,
+    Celsius,
+    Fahrenheit,
+    Rankine,
+}
+
+impl Unit for TemperatureUnit {
+    const ALL: &'static [Self] = &[
+        TemperatureUnit::Kelvin,
+        TemperatureUnit::Celsius,
+        TemperatureUnit::Fahrenheit,
+        TemperatureUnit::Rankine,
+    ];
+
+    fn symbol(&self) -> &'static str {
+        match self {
+            TemperatureUnit::Kelvin => "K",
+            TemperatureUnit::Celsius => "°C",
+            TemperatureUnit::Fahrenheit => "°F",
+            TemperatureUnit::Rankine => "°R",
+        }
+    }
+
+    fn factor(&self) -> f64 {
+        match self {
+            TemperatureUnit::Kelvin | TemperatureUnit::Celsius => 1.0,
+            TemperatureUnit::Fahrenheit | TemperatureUnit::Rankine => 5.0 / 9.0,
+        }
+    }
+
+    fn offset(&self) -> f64 {
+        match self {
+            TemperatureUnit::Kelvin | TemperatureUnit::Rankine => 0.0,
+            TemperatureUnit::Celsius => 273.15,
+            TemperatureUnit::Fahrenheit => 273.15 - 32.0 * 5.0 / 9.0,
+        }
+    }
+}
+
+impl Dimension for dimension::Temperature {
+    type Unit = TemperatureUnit;
+}
+
+pub type Temperature = Quantity<dimension::Temperature>;
+
+/// Typed results of a 2D shape whose dimensions are stored in metres.
+pub trait Measured2D: Geometric2D {
+    fn area_quantity(&self) -> Area {
+        Area::from_si(self.area())
+    }
+
+    fn perimeter_quantity(&self) -> Length {
+        Length::from_si(self.perimeter())
+    }
+}
+
+impl<T: Geometric2D + ?Sized> Measured2D for T {}
+
+/// Typed section properties of a section whose dimensions are stored in metres.
+pub trait MeasuredSection: SectionProperties {
+    fn ix_quantity(&self) -> SecondMomentOfArea {
+        SecondMomentOfArea::from_si(self.ix())
+    }
+
+    fn iy_quantity(&self) -> SecondMomentOfArea {
+        SecondMomentOfArea::from_si(self.iy())
+    }
+
+    fn section_modulus_x_quantity(&self) -> Volume {
+        Volume::from_si(self.section_modulus_x())
+    }
+
+    fn section_modulus_y_quantity(&self) -> Volume {
+        Volume::from_si(self.section_modulus_y())
+    }
+}
+
+impl<T: SectionProperties + ?Sized> MeasuredSection for T {}
+
+/// Typed results of a 3D solid whose dimensions are stored in metres.
+pub trait Measured3D: Geometric3D {
+    fn volume_quantity(&self) -> Volume {
+        Volume::from_si(self.volume())
+    }
+
+    fn surface_area_quantity(&self) -> Area {
+        Area::from_si(self.surface_area())
+    }
+
+    fn mass_quantity(&self, density: Density) -> Mass {
+        Mass::from_si(self.mass(density.si()))
+    }
+}
+
+impl<T: Geometric3D + ?Sized> Measured3D for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPS: f64 = 1e-9;
+
+    #[test]
+    fn test_mixed_units_rectangle() {
+        let width = Length::new(500.0, LengthUnit::Millimetre);
+        let height = Length::new(2.0, LengthUnit::Metre);
+        let area: Area = width * height;
+        assert!((area.value_in(AreaUnit::SquareMetre) - 1.0).abs() < EPS);
+        assert!((area.value_in(AreaUnit::SquareMillimetre) - 1e6).abs() < 1e-3);
+
+        let rect = Rectangle::new(width, height).unwrap();
+        assert_eq!(rect.area_quantity(), area);
+        assert!((area.value_in(AreaUnit::SquareInch) - 1550.0031).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_derived_dimensions() {
+        let volume = Volume::new(1.0, VolumeUnit::Litre);
+        let mass = volume * Density::new(1000.0, DensityUnit::KilogramPerCubicMetre);
+        assert!((mass.value_in(MassUnit::Kilogram) - 1.0).abs() < EPS);
+
+        let stress =
+            Force::new(10.0, ForceUnit::Kilonewton) / Area::new(100.0, AreaUnit::SquareMillimetre);
+        assert!((stress.value_in(PressureUnit::Megapascal) - 100.0).abs() < EPS);
+        assert!((stress.value_in(PressureUnit::Psi) - 14503.77).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_affine_temperature() {
+        let t = Temperature::new(100.0, TemperatureUnit::Celsius);
+        assert!((t.si() - 373.15).abs() < EPS);
+        assert!((t.value_in(TemperatureUnit::Fahrenheit) - 212.0).abs() < EPS);
+        let t = Temperature::new(-40.0, TemperatureUnit::Fahrenheit);
+        assert!((t.value_in(TemperatureUnit::Celsius) + 40.0).abs() < EPS);
+    }
+
+    #[test]
+    fn test_imperial_conversions() {
+        let length = Length::new(1.0, LengthUnit::Mile);
+        assert!((length.value_in(LengthUnit::Foot) - 5280.0).abs() < 1e-6);
+        let area = Area::new(1.0, AreaUnit::Acre);
+        assert!((area.value_in(AreaUnit::SquareFoot) - 43_560.0).abs() < 1e-6);
+        let volume = Volume::new(1.0, VolumeUnit::CubicFoot);
+        assert!((volume.value_in(VolumeUnit::UsGallon) - 7.480_519).abs() < 1e-6);
+        let energy = Energy::new(1.0, EnergyUnit::KilowattHour);
+        assert!((energy.value_in(EnergyUnit::BritishThermalUnit) - 3_412.141_6).abs() < 1e-3);
+        let torque = Force::new(1.0, ForceUnit::PoundForce) * Length::new(1.0, LengthUnit::Foot);
+        assert!((torque.value_in(TorqueUnit::NewtonMetre) - 1.355_818).abs() < 1e-6);
+        let t = Temperature::new(491.67, TemperatureUnit::Rankine);
+        assert!((t.value_in(TemperatureUnit::Celsius)).abs() < EPS);
+    }
+
+    #[test]
+    fn test_hydraulic_power() {
+        let power = Pressure::new(100.0, PressureUnit::Bar)
+            * FlowRate::new(60.0, FlowRateUnit::LitrePerMinute);
+        assert!((power.value_in(PowerUnit::Kilowatt) - 10.0).abs() < EPS);
+        assert!((power.value_in(PowerUnit::Horsepower) - 13.410_22).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_from_symbol() {
+        assert_eq!(LengthUnit::from_symbol("mm"), Some(LengthUnit::Millimetre));
+        assert_eq!(
+            PressureUnit::from_symbol("N/mm²"),
+            Some(PressureUnit::NewtonPerSquareMillimetre)
+        );
+        assert_eq!(LengthUnit::from_symbol("furlong"), None);
+        assert_eq!(
+            AreaUnit::parse("area", "mm"),
+            Err(CalculationError::UnitMismatch {
+                parameter: "area",
+                unit: "mm".to_string()
+            })
+        );
+    }
+}
@@ -1,185 +1,800 @@
-use crate::calculations::errors::*;
+use crate::calculations::formula::Step;
 use crate::calculations::shapes_2d::*;
-use crate::dom::clipboard::float_to_clipboard;
-use crate::locales::i18n::I18n;
+use crate::calculations::units::*;
+use crate::cards::calculator::{
+    metres, AnyQuantity, Calculator, CalculatorCard, CalculatorInput, CalculatorOutput, Measure,
+    Scale, SolveMode,
+};
+use crate::cards::drawing::{Dimension, Drawing, Labels, Shape, EMPTY_SVG, RADIUS_ANGLE};
+use crate::cards::validation::Rule;
 use leptos::prelude::*;
-use leptos::wasm_bindgen::JsCast;
-
-pub fn create_rectangle_svg(rectangle: Rectangle) -> String {
-    let x_position = rectangle.width / 2.0; // Center horizontally
-    let y_position = rectangle.height / 2.0; // Center vertically
-
-    format!(
-        r#"<svg xmlns="http://www.w3.org/2000/svg">
-            <rect id="rectangle" x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" />
-        </svg>"#,
-        // Rectangle position
-        x_position,
-        y_position,
-        // Rectangle dimensions
-        rectangle.width,
-        rectangle.height
-    )
-}
-
-// fn create svg of circle
-pub fn create_circle_svg(circle: Circle) -> String {
-    let cx = circle.radius + 10.0; // Centering the circle in the SVG
-    let cy = circle.radius + 10.0; // Centering the circle in the SVG
-    let dimension_end_x = cx + circle.radius; // End of the dimension line
-
-    format!(
-        r#"<svg xmlns="http://www.w3.org/2000/svg">
-            <circle id="circle" cx="{:.2}" cy="{:.2}" r="{:.2}" />
-            <g id="dimension">
-                <circle cx="{:.2}" cy="{:.2}" r="3" />
-                <text x="{:.2}" y="{:.2}" font-size="12" text-anchor="middle" dominant-baseline="middle">R</text>
-                <line x1="{:.2}" y1="{:.2}" x2="{:.2}" y2="{:.2}" />
-                <circle cx="{:.2}" cy="{:.2}" r="3" />
-            </g>
-        </svg>"#,
-        cx,
-        cy,            // Circle center
-        circle.radius, // Circle radius
-        cx,
-        cy, // Start of the dimension line
-        dimension_end_x + 5.0,
-        cy, // Position of the "R" text
-        cx,
-        cy,
-        dimension_end_x,
-        cy, // Dimension line
-        dimension_end_x,
-        cy // End of the dimension line
-    )
+use std::f64::consts::{FRAC_PI_2, PI};
+
+pub fn create_rectangle_svg(rectangle: Rectangle, labels: &Labels) -> String {
+    let Rectangle { width, height } = rectangle;
+    Drawing::new()
+        .with_shape("shape", Shape::rectangle(0.0, 0.0, width, height))
+        .with_dimension(Dimension::Aligned {
+            from: (0.0, 0.0),
+            to: (width, 0.0),
+            label: labels.length(width),
+        })
+        .with_dimension(Dimension::Aligned {
+            from: (width, 0.0),
+            to: (width, height),
+            label: labels.length(height),
+        })
+        .to_svg()
 }
 
-#[allow(non_snake_case)]
-#[component]
-pub fn RectangleCard() -> impl IntoView {
-    let i18n = use_context::<Memo<I18n>>().expect("I18n context not found");
+pub fn create_circle_svg(circle: Circle, labels: &Labels) -> String {
+    let center = (0.0, 0.0);
+    Drawing::new()
+        .with_shape(
+            "shape",
+            Shape::Circle {
+                center,
+                radius: circle.radius,
+            },
+        )
+        .with_dimension(Dimension::Radius {
+            center,
+            radius: circle.radius,
+            angle: RADIUS_ANGLE,
+            label: labels.radius(circle.radius),
+        })
+        .to_svg()
+}
 
-    let sum_result = RwSignal::new(0.0);
-    let width = RwSignal::new("w".to_string());
-    let height = RwSignal::new("h".to_string());
+/// Draws the triangle with side `c` at the bottom and every side dimensioned.
+pub fn create_triangle_svg(triangle: Triangle, labels: &Labels) -> String {
+    let [a, b, c] = triangle.vertices();
+    Drawing::new()
+        .with_shape("shape", Shape::Polygon(vec![a, b, c]))
+        .with_dimension(Dimension::Aligned {
+            from: a,
+            to: b,
+            label: labels.length(triangle.c),
+        })
+        .with_dimension(Dimension::Aligned {
+            from: b,
+            to: c,
+            label: labels.length(triangle.a),
+        })
+        .with_dimension(Dimension::Aligned {
+            from: c,
+            to: a,
+            label: labels.length(triangle.b),
+        })
+        .to_svg()
+}
 
-    let calculate_area = move |_| {
-        let rect = Rectangle::new(
-            width.get().parse::<f64>().unwrap_or(0.0),
-            height.get().parse::<f64>().unwrap_or(0.0),
+pub fn create_trapezoid_svg(trapezoid: Trapezoid, labels: &Labels) -> String {
+    let Trapezoid {
+        bottom,
+        top,
+        height,
+    } = trapezoid;
+    let inset = (bottom - top) / 2.0;
+    let top_left = (inset, height);
+    let top_right = (inset + top, height);
+    Drawing::new()
+        .with_shape(
+            "shape",
+            Shape::Polygon(vec![(0.0, 0.0), (bottom, 0.0), top_right, top_left]),
         )
-        .unwrap_or_default();
-        let area = Geometric2D::area(&rect);
-        sum_result.set(area);
-
-        // Render the latex formula dynamically
-        let formula = format!(
-            r"A = w \times h \\ A = {} \times {} \\ A = {}",
-            rect.width,
-            rect.height,
-            sum_result.get()
-        );
-        if let Some(element) = web_sys::window()
-            .and_then(|win| win.document())
-            .and_then(|doc| doc.get_element_by_id("rectangle-formula"))
-        {
-            let katex = js_sys::Reflect::get(&js_sys::global(), &"katex".into()).unwrap();
-            let render = js_sys::Reflect::get(&katex, &"render".into())
-                .unwrap()
-                .dyn_into::<js_sys::Function>() // Convert JsValue to Function
-                .expect("Expected katex.render to be a function");
-
-            let _ = render.call2(&katex, &formula.into(), &element.into());
-        }
-    };
-
-    let result_to_clipboard = float_to_clipboard(sum_result.get());
-
-    view! {
-      <div class="card">
-        <a class="card__title">{move || i18n.get().t("rec_area_calculator").to_string()}</a>
-        <div class="card__variables">
-            <p>"A = "{move || i18n.get().t("rec_area_area").to_string()}</p>
-            <p>"w = "{move || i18n.get().t("rec_area_breedte").to_string()}</p>
-            <p>"h = "{move || i18n.get().t("rec_area_hoogte").to_string()}</p>
-        </div>
-        <div class="card__inputs">
-          <input type="text" pattern="[0-9]*" bind:value=width />
-          <input type="text" pattern="[0-9]*" bind:value=height />
-          <button class="card__inputs__button" on:click=calculate_area>{move || i18n.get().t("rec_area_calculate").to_string()}</button>
-        </div>
-        <div class="card__result">
-            <div class="card__result__svg">
-                <div inner_html=move || create_rectangle_svg(Rectangle {
-                    width: width.get().parse::<f64>().unwrap_or(0.0),
-                    height: height.get().parse::<f64>().unwrap_or(0.0),
-                }) />
-            </div>
-            <div id="rectangle-formula" class="card__result__formula"></div>
-          <p>{move || i18n.get().t("rec_area_result").to_string()}{sum_result}</p>
-          <a href="/cards/area/rectangle">{move || i18n.get().t("card_direct_link").to_string()}</a>
-          <button on:click=result_to_clipboard>{move || i18n.get().t("copy_to_clipboard").to_string()}</button>
-        </div>
-      </div>
+        .with_dimension(Dimension::Aligned {
+            from: (0.0, 0.0),
+            to: (bottom, 0.0),
+            label: labels.length(bottom),
+        })
+        .with_dimension(Dimension::Aligned {
+            from: top_right,
+            to: top_left,
+            label: labels.length(top),
+        })
+        .with_dimension(Dimension::Vertical {
+            from: top_left,
+            to: (0.0, 0.0),
+            label: labels.length(height),
+        })
+        .to_svg()
+}
+
+pub fn create_ellipse_svg(ellipse: Ellipse, labels: &Labels) -> String {
+    let center = (0.0, 0.0);
+    Drawing::new()
+        .with_shape(
+            "shape",
+            Shape::Ellipse {
+                center,
+                rx: ellipse.semi_major,
+                ry: ellipse.semi_minor,
+            },
+        )
+        .with_dimension(Dimension::Radius {
+            center,
+            radius: ellipse.semi_major,
+            angle: 0.0,
+            label: labels.length(ellipse.semi_major),
+        })
+        .with_dimension(Dimension::Radius {
+            center,
+            radius: ellipse.semi_minor,
+            angle: FRAC_PI_2,
+            label: labels.length(ellipse.semi_minor),
+        })
+        .to_svg()
+}
+
+/// Most sides a regular polygon is drawn with; with more it is drawn as its
+/// circumscribed circle, from which it can't be told apart.
+pub const MAX_DRAWN_SIDES: u32 = 360;
+
+/// Corners of a regular polygon around the origin, counterclockwise from the bottom
+/// left, so it rests on a flat side.
+pub fn regular_polygon_points(polygon: &RegularPolygon) -> Vec<(f64, f64)> {
+    let radius = polygon.circumradius();
+    let n = polygon.sides.max(1) as f64;
+    let start = -FRAC_PI_2 - PI / n;
+    (0..polygon.sides)
+        .map(|i| {
+            let angle = start + 2.0 * PI * i as f64 / n;
+            (radius * angle.cos(), radius * angle.sin())
+        })
+        .collect()
+}
+
+/// Draws the polygon resting on a flat side, which is dimensioned.
+pub fn create_regular_polygon_svg(polygon: RegularPolygon, labels: &Labels) -> String {
+    if polygon.sides > MAX_DRAWN_SIDES {
+        return Drawing::new()
+            .with_shape(
+                "shape",
+                Shape::Circle {
+                    center: (0.0, 0.0),
+                    radius: polygon.circumradius(),
+                },
+            )
+            .to_svg();
+    }
+    let points = regular_polygon_points(&polygon);
+    let mut drawing = Drawing::new();
+    if let [first, second, ..] = points[..] {
+        drawing = drawing.with_dimension(Dimension::Aligned {
+            from: first,
+            to: second,
+            label: labels.length(polygon.side_length),
+        });
+    }
+    drawing.with_shape("shape", Shape::Polygon(points)).to_svg()
+}
+
+pub fn create_parallelogram_svg(parallelogram: Parallelogram, labels: &Labels) -> String {
+    let Parallelogram { base, side, angle } = parallelogram;
+    let height = parallelogram.height();
+    let shift = side * angle.cos();
+    let top_left = (shift, height);
+    Drawing::new()
+        .with_shape(
+            "shape",
+            Shape::Polygon(vec![
+                (0.0, 0.0),
+                (base, 0.0),
+                (base + shift, height),
+                top_left,
+            ]),
+        )
+        .with_dimension(Dimension::Aligned {
+            from: (0.0, 0.0),
+            to: (base, 0.0),
+            label: labels.length(base),
+        })
+        .with_dimension(Dimension::Aligned {
+            from: top_left,
+            to: (0.0, 0.0),
+            label: labels.length(side),
+        })
+        .to_svg()
+}
+
+/// A positive length input.
+const fn length(parameter: &'static str, symbol: &'static str) -> CalculatorInput {
+    CalculatorInput {
+        parameter,
+        symbol,
+        measure: Measure::Length,
+        rule: Rule::Positive,
+    }
+}
+
+/// An angle input between 0 and π, exclusive.
+const fn angle(parameter: &'static str, symbol: &'static str) -> CalculatorInput {
+    CalculatorInput {
+        parameter,
+        symbol,
+        measure: Measure::Angle,
+        rule: Rule::Between(0.0, PI),
     }
 }
 
+/// The area as input of a solve mode.
+const AREA: CalculatorInput = CalculatorInput {
+    parameter: "area",
+    symbol: "A",
+    measure: Measure::Area,
+    rule: Rule::Positive,
+};
+
+/// The number of sides of a regular polygon.
+const SIDES: CalculatorInput = CalculatorInput {
+    parameter: "sides",
+    symbol: "n",
+    measure: Measure::Count,
+    rule: Rule::Count {
+        min: 3,
+        max: MAX_SIDES,
+    },
+};
+
+/// The area output, shown after the given result text.
+const fn area(label_key: &'static str) -> CalculatorOutput {
+    CalculatorOutput {
+        symbol: "A",
+        measure: Measure::Area,
+        label_key: Some(label_key),
+    }
+}
+
+/// Heron's formula, shared by the triangle and its solve modes.
+const TRIANGLE_AREA: Step = Step::new(
+    "A",
+    r"\sqrt{s(s-a)(s-b)(s-c)}",
+    r"\sqrt{#s(#s - #a)(#s - #b)(#s - #c)}",
+);
+
+const CIRCLE_AREA: Step = Step::new("A", r"\pi r^2", r"\pi \cdot (#r)^2");
+
+/// Ramanujan's approximation, where `h` is `((a - b) / (a + b))^2`.
+const ELLIPSE_PERIMETER: Step = Step::approximate(
+    "P",
+    r"\pi (a + b) \left(1 + \frac{3h}{10 + \sqrt{4 - 3h}}\right)",
+    "",
+);
+
+const POLYGON_AREA: Step = Step::new(
+    "A",
+    r"\frac{n s^2}{4 \tan(\pi / n)}",
+    r"\frac{#n \cdot (#s)^2}{4 \tan(\pi / #n)}",
+);
+
+pub static RECTANGLE: Calculator = Calculator {
+    id: "rectangle",
+    path: "/cards/area/rectangle",
+    title_key: "rec_area_calculator",
+    calculate_key: "rec_area_calculate",
+    variables: &[
+        ("A", "rec_area_area"),
+        ("w", "rec_area_breedte"),
+        ("h", "rec_area_hoogte"),
+    ],
+    inputs: &[length("width", "w"), length("height", "h")],
+    constraints: &[],
+    outputs: &[area("rec_area_result")],
+    formula: &[Step::new("A", r"w \times h", r"#w \times #h")],
+    compute: |v| {
+        Ok(vec![Rectangle::new(v[0].length(), v[1].length())?
+            .area_quantity()
+            .into()])
+    },
+    drawing: Some(|v, labels| {
+        let unit = labels.unit();
+        create_rectangle_svg(
+            Rectangle {
+                width: v[0].length().value_in(unit),
+                height: v[1].length().value_in(unit),
+            },
+            labels,
+        )
+    }),
+    solve_modes: &[
+        SolveMode {
+            id: "width",
+            label_key: "solve_rectangle_width",
+            variables: &[],
+            inputs: &[AREA, length("height", "h")],
+            unknowns: &["w"],
+            solve: |v| {
+                let rectangle = Rectangle::from_area_and_height(v[0].area(), v[1].length())?;
+                Ok(metres(&[rectangle.width, rectangle.height]))
+            },
+            formula: &[Step::new("w", r"\frac{A}{h}", r"\frac{#A}{#h}")],
+        },
+        SolveMode {
+            id: "height",
+            label_key: "solve_rectangle_height",
+            variables: &[],
+            inputs: &[AREA, length("width", "w")],
+            unknowns: &["h"],
+            solve: |v| {
+                let rectangle = Rectangle::from_area_and_width(v[0].area(), v[1].length())?;
+                Ok(metres(&[rectangle.width, rectangle.height]))
+            },
+            formula: &[Step::new("h", r"\frac{A}{w}", r"\frac{#A}{#w}")],
+        },
+    ],
+    scale: Scale::Metre,
+    parts: None,
+};
+
+pub static CIRCLE: Calculator = Calculator {
+    id: "circle",
+    path: "/cards/area/circle",
+    title_key: "circle_area_calculator",
+    calculate_key: "circle_area_calculate",
+    variables: &[("A", "rec_area_area"), ("r", "circle_area_radius")],
+    inputs: &[length("radius", "r")],
+    constraints: &[],
+    outputs: &[area("circle_area_result")],
+    formula: &[CIRCLE_AREA],
+    compute: |v| Ok(vec![Circle::new(v[0].length())?.area_quantity().into()]),
+    drawing: Some(|v, labels| {
+        let radius = v[0].length().value_in(labels.unit());
+        create_circle_svg(Circle { radius }, labels)
+    }),
+    solve_modes: &[
+        SolveMode {
+            id: "area",
+            label_key: "solve_circle_from_area",
+            variables: &[],
+            inputs: &[AREA],
+            unknowns: &["r"],
+            solve: |v| Ok(metres(&[Circle::from_area(v[0].area())?.radius])),
+            formula: &[Step::new(
+                "r",
+                r"\sqrt{\frac{A}{\pi}}",
+                r"\sqrt{\frac{#A}{\pi}}",
+            )],
+        },
+        SolveMode {
+            id: "circumference",
+            label_key: "solve_circle_from_circumference",
+            variables: &[("C", "circle_circumference")],
+            inputs: &[length("circumference", "C")],
+            unknowns: &["r"],
+            solve: |v| Ok(metres(&[Circle::from_circumference(v[0].length())?.radius])),
+            formula: &[
+                Step::new("r", r"\frac{C}{2 \pi}", r"\frac{#C}{2 \pi}"),
+                CIRCLE_AREA,
+            ],
+        },
+    ],
+    scale: Scale::Metre,
+    parts: None,
+};
+
+pub static TRIANGLE: Calculator = Calculator {
+    id: "triangle",
+    path: "/cards/area/triangle",
+    title_key: "triangle_area_calculator",
+    calculate_key: "triangle_area_calculate",
+    variables: &[("A", "rec_area_area"), ("a, b, c", "triangle_area_sides")],
+    inputs: &[length("a", "a"), length("b", "b"), length("c", "c")],
+    constraints: &["a < b + c", "b < a + c", "c < a + b"],
+    outputs: &[
+        area("triangle_area_result"),
+        CalculatorOutput {
+            symbol: "s",
+            measure: Measure::Length,
+            label_key: None,
+        },
+    ],
+    formula: &[
+        Step::new("s", r"\frac{a + b + c}{2}", r"\frac{#a + #b + #c}{2}"),
+        TRIANGLE_AREA,
+    ],
+    compute: |v| {
+        let triangle = Triangle::new(v[0].length(), v[1].length(), v[2].length())?;
+        Ok(vec![
+            triangle.area_quantity().into(),
+            (triangle.perimeter_quantity() / 2.0).into(),
+        ])
+    },
+    drawing: Some(|v, labels| {
+        let [a, b, c] = [0, 1, 2].map(|index| v[index].length());
+        if Triangle::new(a, b, c).is_err() {
+            return EMPTY_SVG.to_string();
+        }
+        let [a, b, c] = [a, b, c].map(|side| side.value_in(labels.unit()));
+        create_triangle_svg(Triangle { a, b, c }, labels)
+    }),
+    solve_modes: &[
+        SolveMode {
+            id: "sas",
+            label_key: "solve_triangle_sas",
+            variables: &[("γ", "triangle_angle_gamma")],
+            inputs: &[length("a", "a"), angle("gamma", "γ"), length("b", "b")],
+            unknowns: &["c"],
+            solve: |v| {
+                let triangle = Triangle::from_sas(v[0].length(), v[1].angle(), v[2].length())?;
+                Ok(metres(&[triangle.a, triangle.b, triangle.c]))
+            },
+            formula: &[
+                Step::new(
+                    "c",
+                    r"\sqrt{a^2 + b^2 - 2ab\cos\gamma}",
+                    r"\sqrt{(#a)^2 + (#b)^2 - 2 \cdot #a \cdot #b \cos(#γ)}",
+                ),
+                TRIANGLE_AREA,
+            ],
+        },
+        SolveMode {
+            id: "asa",
+            label_key: "solve_triangle_asa",
+            variables: &[("α, β", "triangle_angles_alpha_beta")],
+            inputs: &[angle("alpha", "α"), length("c", "c"), angle("beta", "β")],
+            unknowns: &["a", "b"],
+            solve: |v| {
+                let triangle = Triangle::from_asa(v[0].angle(), v[1].length(), v[2].angle())?;
+                Ok(metres(&[triangle.a, triangle.b, triangle.c]))
+            },
+            formula: &[
+                Step::new(
+                    "a",
+                    r"\frac{c \sin\alpha}{\sin(\alpha + \beta)}",
+                    r"\frac{#c \sin(#α)}{\sin(#α + #β)}",
+                ),
+                Step::new(
+                    "b",
+                    r"\frac{c \sin\beta}{\sin(\alpha + \beta)}",
+                    r"\frac{#c \sin(#β)}{\sin(#α + #β)}",
+                ),
+                TRIANGLE_AREA,
+            ],
+        },
+        SolveMode {
+            id: "base-height",
+            label_key: "solve_triangle_base_height",
+            variables: &[("h", "triangle_height")],
+            inputs: &[length("base", "c"), length("height", "h")],
+            unknowns: &["a", "b"],
+            solve: |v| {
+                let triangle = Triangle::from_base_and_height(v[0].length(), v[1].length())?;
+                Ok(metres(&[triangle.a, triangle.b, triangle.c]))
+            },
+            formula: &[
+                Step::new(
+                    "a",
+                    r"\sqrt{h^2 + \left(\frac{c}{2}\right)^2}",
+                    r"\sqrt{(#h)^2 + \left(\frac{#c}{2}\right)^2}",
+                ),
+                Step::new("b", "a", ""),
+                Step::new("A", r"\frac{c h}{2}", r"\frac{#c \cdot #h}{2}"),
+            ],
+        },
+    ],
+    scale: Scale::Metre,
+    parts: None,
+};
+
+pub static TRAPEZOID: Calculator = Calculator {
+    id: "trapezoid",
+    path: "/cards/area/trapezoid",
+    title_key: "trapezoid_area_calculator",
+    calculate_key: "trapezoid_area_calculate",
+    variables: &[
+        ("A", "rec_area_area"),
+        ("a", "trapezoid_area_bottom"),
+        ("b", "trapezoid_area_top"),
+        ("h", "rec_area_hoogte"),
+    ],
+    inputs: &[
+        length("bottom", "a"),
+        length("top", "b"),
+        length("height", "h"),
+    ],
+    constraints: &[],
+    outputs: &[area("trapezoid_area_result")],
+    formula: &[Step::new(
+        "A",
+        r"\frac{a + b}{2} h",
+        r"\frac{#a + #b}{2} \times #h",
+    )],
+    compute: |v| {
+        let trapezoid = Trapezoid::new(v[0].length(), v[1].length(), v[2].length())?;
+        Ok(vec![trapezoid.area_quantity().into()])
+    },
+    drawing: Some(|v, labels| {
+        let unit = labels.unit();
+        create_trapezoid_svg(
+            Trapezoid {
+                bottom: v[0].length().value_in(unit),
+                top: v[1].length().value_in(unit),
+                height: v[2].length().value_in(unit),
+            },
+            labels,
+        )
+    }),
+    solve_modes: &[SolveMode {
+        id: "height",
+        label_key: "solve_trapezoid_height",
+        variables: &[],
+        inputs: &[AREA, length("bottom", "a"), length("top", "b")],
+        unknowns: &["h"],
+        solve: |v| {
+            let trapezoid =
+                Trapezoid::from_area_and_bases(v[0].area(), v[1].length(), v[2].length())?;
+            Ok(metres(&[trapezoid.bottom, trapezoid.top, trapezoid.height]))
+        },
+        formula: &[Step::new(
+            "h",
+            r"\frac{2A}{a + b}",
+            r"\frac{2 \cdot #A}{#a + #b}",
+        )],
+    }],
+    scale: Scale::Metre,
+    parts: None,
+};
+
+pub static ELLIPSE: Calculator = Calculator {
+    id: "ellipse",
+    path: "/cards/area/ellipse",
+    title_key: "ellipse_area_calculator",
+    calculate_key: "ellipse_area_calculate",
+    variables: &[
+        ("A", "rec_area_area"),
+        ("P", "ellipse_area_perimeter"),
+        ("a", "ellipse_area_semi_major"),
+        ("b", "ellipse_area_semi_minor"),
+        ("h", "ellipse_area_h"),
+    ],
+    inputs: &[length("semi_major", "a"), length("semi_minor", "b")],
+    constraints: &["b ≤ a"],
+    outputs: &[
+        area("ellipse_area_result"),
+        CalculatorOutput {
+            symbol: "P",
+            measure: Measure::Length,
+            label_key: Some("ellipse_perimeter_result"),
+        },
+    ],
+    formula: &[
+        Step::new("A", r"\pi a b", r"\pi \cdot #a \cdot #b"),
+        ELLIPSE_PERIMETER,
+    ],
+    compute: |v| {
+        let ellipse = Ellipse::new(v[0].length(), v[1].length())?;
+        Ok(vec![
+            ellipse.area_quantity().into(),
+            ellipse.perimeter_quantity().into(),
+        ])
+    },
+    drawing: Some(|v, labels| {
+        let unit = labels.unit();
+        create_ellipse_svg(
+            Ellipse {
+                semi_major: v[0].length().value_in(unit),
+                semi_minor: v[1].length().value_in(unit),
+            },
+            labels,
+        )
+    }),
+    solve_modes: &[SolveMode {
+        id: "semi-minor",
+        label_key: "solve_ellipse_semi_minor",
+        variables: &[],
+        inputs: &[AREA, length("semi_major", "a")],
+        unknowns: &["b"],
+        solve: |v| {
+            let ellipse = Ellipse::from_area_and_semi_axis(v[0].area(), v[1].length())?;
+            Ok(metres(&[ellipse.semi_major, ellipse.semi_minor]))
+        },
+        formula: &[
+            Step::new("b", r"\frac{A}{\pi a}", r"\frac{#A}{\pi \cdot #a}"),
+            ELLIPSE_PERIMETER,
+        ],
+    }],
+    scale: Scale::Metre,
+    parts: None,
+};
+
+pub static REGULAR_POLYGON: Calculator = Calculator {
+    id: "regular-polygon",
+    path: "/cards/area/regular-polygon",
+    title_key: "polygon_area_calculator",
+    calculate_key: "polygon_area_calculate",
+    variables: &[
+        ("A", "rec_area_area"),
+        ("n", "polygon_area_sides"),
+        ("s", "polygon_area_side_length"),
+    ],
+    inputs: &[SIDES, length("side_length", "s")],
+    constraints: &[],
+    outputs: &[area("polygon_area_result")],
+    formula: &[POLYGON_AREA],
+    compute: |v| {
+        let polygon = RegularPolygon::new(RegularPolygon::sides(v[0].count())?, v[1].length())?;
+        Ok(vec![polygon.area_quantity().into()])
+    },
+    drawing: Some(|v, labels| {
+        let side_length = v[1].length();
+        let polygon = RegularPolygon::sides(v[0].count())
+            .and_then(|sides| RegularPolygon::new(sides, side_length));
+        match polygon {
+            Ok(polygon) => create_regular_polygon_svg(
+                RegularPolygon {
+                    side_length: side_length.value_in(labels.unit()),
+                    ..polygon
+                },
+                labels,
+            ),
+            Err(_) => EMPTY_SVG.to_string(),
+        }
+    }),
+    solve_modes: &[
+        SolveMode {
+            id: "circumradius",
+            label_key: "solve_polygon_circumradius",
+            variables: &[("R", "polygon_circumradius")],
+            inputs: &[SIDES, length("circumradius", "R")],
+            unknowns: &["s"],
+            solve: |v| {
+                let polygon = RegularPolygon::from_circumradius(
+                    RegularPolygon::sides(v[0].count())?,
+                    v[1].length(),
+                )?;
+                Ok(vec![
+                    AnyQuantity::Count(polygon.sides as f64),
+                    Length::from_si(polygon.side_length).into(),
+                ])
+            },
+            formula: &[
+                Step::new("s", r"2 R \sin(\pi / n)", r"2 \cdot #R \sin(\pi / #n)"),
+                POLYGON_AREA,
+            ],
+        },
+        SolveMode {
+            id: "apothem",
+            label_key: "solve_polygon_apothem",
+            variables: &[("a", "polygon_apothem")],
+            inputs: &[SIDES, length("apothem", "a")],
+            unknowns: &["s"],
+            solve: |v| {
+                let polygon = RegularPolygon::from_apothem(
+                    RegularPolygon::sides(v[0].count())?,
+                    v[1].length(),
+                )?;
+                Ok(vec![
+                    AnyQuantity::Count(polygon.sides as f64),
+                    Length::from_si(polygon.side_length).into(),
+                ])
+            },
+            formula: &[
+                Step::new("s", r"2 a \tan(\pi / n)", r"2 \cdot #a \tan(\pi / #n)"),
+                POLYGON_AREA,
+            ],
+        },
+    ],
+    scale: Scale::Metre,
+    parts: None,
+};
+
+pub static PARALLELOGRAM: Calculator = Calculator {
+    id: "parallelogram",
+    path: "/cards/area/parallelogram",
+    title_key: "parallelogram_area_calculator",
+    calculate_key: "parallelogram_area_calculate",
+    variables: &[
+        ("A", "rec_area_area"),
+        ("b", "parallelogram_area_base"),
+        ("s", "parallelogram_area_side"),
+        ("θ", "parallelogram_area_angle"),
+    ],
+    inputs: &[
+        length("base", "b"),
+        length("side", "s"),
+        angle("angle", "θ"),
+    ],
+    constraints: &[],
+    outputs: &[area("parallelogram_area_result")],
+    formula: &[Step::new(
+        "A",
+        r"b \cdot s \cdot \sin\theta",
+        r"#b \cdot #s \cdot \sin(#θ)",
+    )],
+    compute: |v| {
+        let parallelogram = Parallelogram::new(v[0].length(), v[1].length(), v[2].angle())?;
+        Ok(vec![parallelogram.area_quantity().into()])
+    },
+    drawing: Some(|v, labels| {
+        let angle = v[2].angle().si();
+        create_parallelogram_svg(
+            Parallelogram {
+                base: v[0].length().value_in(labels.unit()),
+                side: v[1].length().value_in(labels.unit()),
+                angle: if angle > 0.0 { angle } else { FRAC_PI_2 },
+            },
+            labels,
+        )
+    }),
+    solve_modes: &[SolveMode {
+        id: "height",
+        label_key: "solve_parallelogram_side",
+        variables: &[("h", "rec_area_hoogte")],
+        inputs: &[
+            length("base", "b"),
+            length("height", "h"),
+            angle("angle", "θ"),
+        ],
+        unknowns: &["s"],
+        solve: |v| {
+            let parallelogram =
+                Parallelogram::from_base_and_height(v[0].length(), v[1].length(), v[2].angle())?;
+            Ok(vec![
+                Length::from_si(parallelogram.base).into(),
+                Length::from_si(parallelogram.side).into(),
+                Angle::from_si(parallelogram.angle).into(),
+            ])
+        },
+        formula: &[
+            Step::new("s", r"\frac{h}{\sin\theta}", r"\frac{#h}{\sin(#θ)}"),
+            Step::new("A", r"b \cdot h", r"#b \cdot #h"),
+        ],
+    }],
+    scale: Scale::Metre,
+    parts: None,
+};
+
+/// All area calculators, as the API serves them.
+#[cfg(feature = "ssr")]
+pub static CALCULATORS: [&Calculator; 7] = [
+    &RECTANGLE,
+    &CIRCLE,
+    &TRIANGLE,
+    &TRAPEZOID,
+    &ELLIPSE,
+    &REGULAR_POLYGON,
+    &PARALLELOGRAM,
+];
+
+#[allow(non_snake_case)]
+#[component]
+pub fn RectangleCard() -> impl IntoView {
+    view! { <CalculatorCard calculator=&RECTANGLE /> }
+}
+
 #[allow(non_snake_case)]
 #[component]
 pub fn CircleCard() -> impl IntoView {
-    let i18n = use_context::<Memo<I18n>>().expect("I18n context not found");
-
-    let sum_result = RwSignal::new(0.0);
-    let radius = RwSignal::new("r".to_string());
-
-    let calculate_area = move |_| {
-        let circ = Circle::new(radius.get().parse::<f64>().unwrap_or(0.0)).unwrap_or_default();
-        let area = Geometric2D::area(&circ);
-        sum_result.set(area);
-        // Render the latex formula dynamically
-        let formula = format!(
-            r"A = \pi r^2 \\ A = \pi \cdot {}^2 \\ A = {:.2}",
-            circ.radius,
-            sum_result.get()
-        );
-        if let Some(element) = web_sys::window()
-            .and_then(|win| win.document())
-            .and_then(|doc| doc.get_element_by_id("circle-formula"))
-        {
-            let katex = js_sys::Reflect::get(&js_sys::global(), &"katex".into()).unwrap();
-            let render = js_sys::Reflect::get(&katex, &"render".into())
-                .unwrap()
-                .dyn_into::<js_sys::Function>() // Convert JsValue to Function
-                .expect("Expected katex.render to be a function");
-
-            let _ = render.call2(&katex, &formula.into(), &element.into());
-        }
-    };
-
-    let result_to_clipboard = float_to_clipboard(sum_result.get());
-
-    view! {
-      <div class="card">
-        <a class="card__title">{move || i18n.get().t("circle_area_calculator").to_string()}</a>
-        <div class="card__variables">
-            <p>"A = "{move || i18n.get().t("rec_area_area").to_string()}</p>
-            <p>"r = "{move || i18n.get().t("circle_area_radius").to_string()}</p>
-        </div>
-        <div class="card__inputs">
-          <input type="text" pattern="[0-9]*" bind:value=radius />
-          <button class="card__inputs__button" on:click=calculate_area>{move || i18n.get().t("circle_area_calculate").to_string()}</button>
-        </div>
-        <div class="card__result">
-            <div class="card__result__svg">
-                <div inner_html=move || create_circle_svg(Circle {
-                    radius: radius.get().parse::<f64>().unwrap_or(0.0),
-                }) />
-            </div>
-            <div id="circle-formula" class="card__result__formula"></div>
-          <p>{move || i18n.get().t("circle_area_result").to_string()}{sum_result}</p>
-          <a href="/cards/area/circle">{move || i18n.get().t("card_direct_link").to_string()}</a>
-          <button on:click=result_to_clipboard>{move || i18n.get().t("copy_to_clipboard").to_string()}</button>
-        </div>
-      </div>
-    }
+    view! { <CalculatorCard calculator=&CIRCLE /> }
+}
+
+#[allow(non_snake_case)]
+#[component]
+pub fn TriangleCard() -> impl IntoView {
+    view! { <CalculatorCard calculator=&TRIANGLE /> }
+}
+
+#[allow(non_snake_case)]
+#[component]
+pub fn TrapezoidCard() -> impl IntoView {
+    view! { <CalculatorCard calculator=&TRAPEZOID /> }
+}
+
+#[allow(non_snake_case)]
+#[component]
+pub fn EllipseCard() -> impl IntoView {
+    view! { <CalculatorCard calculator=&ELLIPSE /> }
+}
+
+#[allow(non_snake_case)]
+#[component]
+pub fn RegularPolygonCard() -> impl IntoView {
+    view! { <CalculatorCard calculator=&REGULAR_POLYGON /> }
+}
+
+#[allow(non_snake_case)]
+#[component]
+pub fn ParallelogramCard() -> impl IntoView {
+    view! { <CalculatorCard calculator=&PARALLELOGRAM /> }
 }
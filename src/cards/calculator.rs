@@ -0,0 +1,2182 @@
+use crate::calculations::errors::{ensure_finite, CalculationError};
+use crate::calculations::formula::{Derivation, Step};
+use crate::calculations::tolerance::{propagate, Propagated, Toleranced};
+use crate::calculations::units::*;
+use crate::cards::area::{
+    CIRCLE, ELLIPSE, PARALLELOGRAM, RECTANGLE, REGULAR_POLYGON, TRAPEZOID, TRIANGLE,
+};
+use crate::cards::composite::COMPOSITE;
+use crate::cards::drawing::{DrawingDownload, Labels};
+use crate::cards::errors::FieldError;
+use crate::cards::export::{CopyResult, CopyToast, ResultExport};
+use crate::cards::history::{record_history, HistoryEntry, HistoryValue};
+use crate::cards::live::{LiveCalculation, LiveControls, Outcome};
+use crate::cards::mathml::latex_to_mathml;
+use crate::cards::section::SECTIONS;
+use crate::cards::share::{
+    card_link, link_number, link_tolerance, restore_number, restore_shared, restore_unit, CardInfo,
+    CardLink,
+};
+use crate::cards::units::{latex_unit, QuantityInput, UnitSelect};
+use crate::cards::validation::{
+    all_valid, check_number, check_quantity, check_toleranced_quantity, InputMessage, NumberInput,
+    Rule,
+};
+use crate::cards::volume::SOLIDS;
+use crate::locales::i18n::I18n;
+use crate::locales::numbers::NumberFormat;
+use leptos::prelude::*;
+use leptos_router::params::ParamsMap;
+
+/// Kind of quantity an input or output holds, which decides the units it can be given in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Measure {
+    Length,
+    Area,
+    Volume,
+    SecondMomentOfArea,
+    Angle,
+    Mass,
+    Density,
+    /// A whole number without unit, such as the number of sides.
+    Count,
+}
+
+/// An input of a calculator.
+pub struct CalculatorInput {
+    /// Name of the constructor parameter, which errors of the calculation refer to.
+    pub parameter: &'static str,
+    /// Symbol of the input in the formula, also used as placeholder.
+    pub symbol: &'static str,
+    pub measure: Measure,
+    pub rule: Rule,
+}
+
+/// Scale of the units a calculator starts in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Scale {
+    /// SI units, for shapes measured in metres.
+    Metre,
+    /// Millimetres and their powers, for cross-sections.
+    Millimetre,
+}
+
+/// An output of a calculator.
+pub struct CalculatorOutput {
+    pub symbol: &'static str,
+    pub measure: Measure,
+    /// Key of the text in front of the result, or `None` for an intermediate value
+    /// that only appears in the formula.
+    pub label_key: Option<&'static str>,
+}
+
+/// Declarative description of a calculator, rendered by [`CalculatorCard`].
+///
+/// Values passed to `compute` and returned from it are quantities of the measure of
+/// their input or output, in the order of `inputs` and `outputs`. A calculator with
+/// [`Parts`] receives the values of its parts instead.
+pub struct Calculator {
+    pub id: &'static str,
+    /// Path of the page holding only this card.
+    pub path: &'static str,
+    pub title_key: &'static str,
+    pub calculate_key: &'static str,
+    /// Explanation of the symbols: the symbols and the key of their description.
+    pub variables: &'static [(&'static str, &'static str)],
+    pub inputs: &'static [CalculatorInput],
+    /// Conditions between the inputs that the constructor checks, such as the triangle
+    /// inequality, shown with the variables and described by the API.
+    pub constraints: &'static [&'static str],
+    pub outputs: &'static [CalculatorOutput],
+    /// Steps deriving the outputs from the inputs, whose placeholders are the symbols
+    /// of the inputs and outputs.
+    pub formula: &'static [Step],
+    pub compute: fn(&[AnyQuantity]) -> Result<Vec<AnyQuantity>, CalculationError>,
+    /// Draws the shape from the inputs with its lengths in the unit of the labels, that
+    /// of the first length input, so mixed units keep their proportions.
+    pub drawing: Option<fn(&[AnyQuantity], &Labels) -> String>,
+    /// Other sets of known values the inputs can be solved from.
+    pub solve_modes: &'static [SolveMode],
+    /// Scale of the units the inputs and outputs start in.
+    pub scale: Scale,
+    /// Parts the calculator combines, entered in rows instead of `inputs`.
+    pub parts: Option<&'static Parts>,
+}
+
+/// Alternative set of known values from which a calculator finds its inputs, such as
+/// the radius of a circle from its area.
+pub struct SolveMode {
+    pub id: &'static str,
+    pub label_key: &'static str,
+    /// Explanation of the symbols entered in this mode only.
+    pub variables: &'static [(&'static str, &'static str)],
+    pub inputs: &'static [CalculatorInput],
+    /// Symbols of the calculator inputs found by this mode, shown with the results.
+    pub unknowns: &'static [&'static str],
+    /// Finds all calculator inputs from the values entered in the mode, usually with
+    /// one of the inverse constructors of the shape.
+    pub solve: fn(&[AnyQuantity]) -> Result<Vec<AnyQuantity>, CalculationError>,
+    /// Steps like [`Calculator::formula`], which can also refer to the inputs of the
+    /// mode.
+    pub formula: &'static [Step],
+}
+
+/// Outcome of a calculator.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solution {
+    /// The solve mode the values were entered in.
+    pub mode: Option<usize>,
+    /// The entered values, in the order of the inputs of the mode.
+    pub values: Vec<AnyQuantity>,
+    /// The calculator inputs, entered or solved.
+    pub dimensions: Vec<AnyQuantity>,
+    pub outputs: Vec<AnyQuantity>,
+    /// How the outputs were found, with every value by its symbol.
+    pub derivation: Derivation<AnyQuantity>,
+    /// Ranges in SI units the tolerances of the entered values give every value, by
+    /// symbol; empty when no value has a tolerance.
+    pub ranges: Vec<(&'static str, Propagated)>,
+}
+
+impl Solution {
+    /// The range of the value with the given symbol, if any value has a tolerance.
+    pub fn range(&self, symbol: &str) -> Option<Propagated> {
+        self.ranges
+            .iter()
+            .find(|(known, _)| *known == symbol)
+            .map(|(_, range)| *range)
+    }
+}
+
+impl Calculator {
+    pub const fn card(&self) -> CardInfo {
+        CardInfo {
+            id: self.id,
+            title_key: self.title_key,
+            path: self.path,
+        }
+    }
+
+    /// Key of the explanation of an input among the variables of the solve mode or of
+    /// the calculator; one explanation may cover several symbols.
+    pub fn label_key(&self, mode: Option<usize>, symbol: &str) -> Option<&'static str> {
+        let mode_variables = mode.map_or(&[][..], |index| self.solve_modes[index].variables);
+        mode_variables
+            .iter()
+            .chain(self.variables)
+            .find(|(symbols, _)| symbols.split(", ").any(|known| known == symbol))
+            .map(|(_, key)| *key)
+    }
+
+    /// Inputs whose values are entered in a solve mode, or the calculator inputs
+    /// without one.
+    pub fn entered_inputs(&self, mode: Option<usize>) -> &'static [CalculatorInput] {
+        mode.map_or(self.inputs, |index| self.solve_modes[index].inputs)
+    }
+
+    /// Inputs of the calculator and of all its solve modes.
+    fn all_inputs(&'static self) -> impl Iterator<Item = &'static CalculatorInput> {
+        self.inputs
+            .iter()
+            .chain(self.solve_modes.iter().flat_map(|mode| mode.inputs))
+    }
+
+    /// Calculates the outputs from the values entered in a solve mode.
+    pub fn solve(
+        &self,
+        mode: Option<usize>,
+        values: Vec<AnyQuantity>,
+    ) -> Result<Solution, CalculationError> {
+        let dimensions = match mode {
+            Some(index) => (self.solve_modes[index].solve)(&values)?,
+            None => values.clone(),
+        };
+        let outputs = (self.compute)(&dimensions)?;
+        // Results overflow for huge inputs, and so do the inputs a solve mode finds
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|input| input.symbol)
+            .zip(&dimensions);
+        let outputs_by_symbol = self
+            .outputs
+            .iter()
+            .map(|output| output.symbol)
+            .zip(&outputs);
+        for (symbol, value) in inputs.clone().chain(outputs_by_symbol.clone()) {
+            ensure_finite(symbol, value.si())?;
+        }
+        let steps = mode.map_or(self.formula, |index| self.solve_modes[index].formula);
+        let entered = self
+            .entered_inputs(mode)
+            .iter()
+            .map(|input| input.symbol)
+            .zip(&values);
+        let derivation = inputs
+            .chain(entered)
+            .chain(outputs_by_symbol)
+            .fold(Derivation::new(steps), |derivation, (symbol, value)| {
+                derivation.with_value(symbol, *value)
+            });
+        Ok(Solution {
+            mode,
+            values,
+            dimensions,
+            outputs,
+            derivation,
+            ranges: Vec::new(),
+        })
+    }
+
+    /// Calculates like [`Calculator::solve`] from values with a tolerance in SI units,
+    /// with the range the tolerances give every input and output.
+    pub fn solve_toleranced(
+        &self,
+        mode: Option<usize>,
+        values: Vec<AnyQuantity>,
+        tolerances: &[f64],
+    ) -> Result<Solution, CalculationError> {
+        let toleranced: Vec<Toleranced> = values
+            .iter()
+            .zip(tolerances)
+            .map(|(value, tolerance)| Toleranced::new(value.si(), *tolerance))
+            .collect();
+        let mut solution = self.solve(mode, values.clone())?;
+        if toleranced.iter().all(Toleranced::is_exact) {
+            return Ok(solution);
+        }
+        let ranges = propagate(&toleranced, |si| {
+            let bounds = values
+                .iter()
+                .zip(si)
+                .map(|(value, si)| value.with_si(*si))
+                .collect();
+            let solution = self.solve(mode, bounds)?;
+            Ok(solution
+                .dimensions
+                .iter()
+                .chain(&solution.outputs)
+                .map(|value| value.si())
+                .collect())
+        })?;
+        let entered = self.entered_inputs(mode);
+        let is_entered = |symbol: &str| entered.iter().any(|input| input.symbol == symbol);
+        let symbols = self
+            .inputs
+            .iter()
+            .map(|input| input.symbol)
+            .chain(self.outputs.iter().map(|output| output.symbol));
+        solution.ranges = entered
+            .iter()
+            .zip(toleranced)
+            .map(|(input, value)| (input.symbol, Propagated::of(value)))
+            .chain(
+                symbols
+                    .zip(ranges)
+                    .filter(|(symbol, _)| !is_entered(symbol)),
+            )
+            .collect();
+        Ok(solution)
+    }
+
+    /// Position of the input with the given symbol.
+    pub fn input_index(&self, symbol: &str) -> Option<usize> {
+        self.inputs.iter().position(|input| input.symbol == symbol)
+    }
+
+    /// Position of the output with the given symbol.
+    pub fn output_index(&self, symbol: &str) -> Option<usize> {
+        self.outputs
+            .iter()
+            .position(|output| output.symbol == symbol)
+    }
+
+    /// The entered values of a solution in the unit `input_unit` gives for their
+    /// symbol; with parts only their number, as the parts are too many to list.
+    pub fn entered_values(
+        &self,
+        solution: &Solution,
+        input_unit: impl Fn(&str) -> AnyUnit,
+    ) -> Vec<HistoryValue> {
+        if let Some(parts) = self.parts {
+            let count = parts.split(&solution.values).len();
+            return vec![HistoryValue::new("n", count as f64, "")];
+        }
+        self.entered_inputs(solution.mode)
+            .iter()
+            .zip(&solution.values)
+            .map(|(input, value)| shown_value(input.symbol, *value, input_unit(input.symbol)))
+            .collect()
+    }
+
+    /// The inputs a solve mode found followed by the outputs with a result text, in
+    /// the units `input_unit` gives and `output_units`.
+    pub fn result_values(
+        &self,
+        solution: &Solution,
+        input_unit: impl Fn(&str) -> AnyUnit,
+        output_units: &[AnyUnit],
+    ) -> Vec<HistoryValue> {
+        let unknowns = solution
+            .mode
+            .map_or(&[][..], |index| self.solve_modes[index].unknowns)
+            .iter()
+            .filter_map(|symbol| {
+                let index = self.input_index(symbol)?;
+                Some(shown_value(
+                    symbol,
+                    solution.dimensions[index],
+                    input_unit(symbol),
+                ))
+            });
+        let outputs = self
+            .outputs
+            .iter()
+            .zip(output_units)
+            .zip(&solution.outputs)
+            .filter(|((output, _), _)| output.label_key.is_some())
+            .map(|((output, unit), value)| shown_value(output.symbol, *value, *unit));
+        unknowns.chain(outputs).collect()
+    }
+
+    /// The derivation of a solution as LaTeX, with the inputs in the unit `input_unit`
+    /// gives for their symbol and the outputs in `output_units`. Entered values are
+    /// written with their tolerance, followed by the range of every result.
+    pub fn formula_latex(
+        &self,
+        solution: &Solution,
+        input_unit: impl Fn(&str) -> AnyUnit,
+        output_units: &[AnyUnit],
+        numbers: NumberFormat,
+    ) -> String {
+        let entered = self.entered_inputs(solution.mode);
+        let is_entered = |symbol: &str| entered.iter().any(|input| input.symbol == symbol);
+        let unit = |symbol: &str| match self.output_index(symbol) {
+            Some(index) if !is_entered(symbol) && self.input_index(symbol).is_none() => {
+                let output = &self.outputs[index];
+                // Intermediate values follow the first input they share a measure with
+                match output.label_key {
+                    Some(_) => output_units[index],
+                    None => self
+                        .inputs
+                        .iter()
+                        .find(|input| input.measure == output.measure)
+                        .map_or(output_units[index], |input| input_unit(input.symbol)),
+                }
+            }
+            _ => input_unit(symbol),
+        };
+        let mut latex = solution.derivation.latex(|symbol, value| {
+            match solution.range(symbol).filter(|_| is_entered(symbol)) {
+                Some(range) if !range.is_exact() => {
+                    unit(symbol).latex_toleranced(value, range.uncertainty, numbers)
+                }
+                _ => unit(symbol).latex(value, numbers),
+            }
+        });
+        for (symbol, range) in &solution.ranges {
+            let intermediate = self
+                .output_index(symbol)
+                .is_some_and(|index| self.outputs[index].label_key.is_none());
+            if is_entered(symbol) || intermediate || range.is_exact() {
+                continue;
+            }
+            latex.push_str(r" \\ ");
+            latex.push_str(&unit(symbol).latex_range(symbol, *range, numbers));
+        }
+        latex
+    }
+
+    /// The unit a drawing gives its lengths in: that of the first length input, so
+    /// mixed units keep their proportions.
+    pub fn drawing_unit(&self, input_unit: impl Fn(&str) -> AnyUnit) -> Option<LengthUnit> {
+        self.inputs
+            .iter()
+            .find_map(|input| match input_unit(input.symbol) {
+                AnyUnit::Length(unit) => Some(unit),
+                _ => None,
+            })
+    }
+
+    /// Draws a solution, with the lengths in `drawing_unit`, or metres without one.
+    pub fn drawing_svg(
+        &self,
+        solution: &Solution,
+        drawing_unit: Option<LengthUnit>,
+        numbers: NumberFormat,
+    ) -> Option<String> {
+        let draw = self.drawing?;
+        let labels = Labels::new(numbers, drawing_unit.unwrap_or(LengthUnit::Metre));
+        Some(draw(&solution.dimensions, &labels))
+    }
+}
+
+/// Parts a calculator combines, such as the sections of a composite section: each is
+/// one of `kinds` placed by the `placement` inputs, with every input a length entered
+/// in the unit of its row.
+///
+/// `compute` receives the parts one after another, each as the index of its kind, 1
+/// when it is subtracted or 0 when it is added, both as counts, followed by the inputs
+/// of its kind and the placement.
+pub struct Parts {
+    pub kinds: &'static [Calculator],
+    pub placement: &'static [CalculatorInput],
+    /// Key of the label of the box that subtracts a part.
+    pub subtract_key: &'static str,
+    /// Key of the button that adds a part.
+    pub add_key: &'static str,
+}
+
+/// One part in the values of a calculator with [`Parts`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Part<'a> {
+    /// Index of the kind of the part.
+    pub kind: usize,
+    pub subtract: bool,
+    /// Values of the inputs of the kind.
+    pub values: &'a [AnyQuantity],
+    /// Values of the placement inputs.
+    pub placement: &'a [AnyQuantity],
+}
+
+impl Parts {
+    /// Index of the kind with the given id, or the first kind.
+    pub fn kind(&self, id: &str) -> usize {
+        self.kinds
+            .iter()
+            .position(|kind| kind.id == id)
+            .unwrap_or_default()
+    }
+
+    /// Inputs of a part of the given kind, in the order of its values.
+    pub fn inputs(&self, kind: usize) -> impl Iterator<Item = &'static CalculatorInput> {
+        self.kinds[kind].inputs.iter().chain(self.placement)
+    }
+
+    /// Splits the values of a calculator into its parts; values that do not make up
+    /// a whole part are left out.
+    pub fn split<'a>(&self, mut values: &'a [AnyQuantity]) -> Vec<Part<'a>> {
+        let mut parts = Vec::new();
+        while let [kind, subtract, rest @ ..] = values {
+            let kind = kind.count() as usize;
+            let Some(calculator) = self.kinds.get(kind) else {
+                break;
+            };
+            let count = calculator.inputs.len();
+            if rest.len() < count + self.placement.len() {
+                break;
+            }
+            let (part, rest) = rest.split_at(count + self.placement.len());
+            parts.push(Part {
+                kind,
+                subtract: subtract.count() != 0.0,
+                values: &part[..count],
+                placement: &part[count..],
+            });
+            values = rest;
+        }
+        parts
+    }
+
+    /// Reads a part written by [`Parts::write_link`].
+    fn read_link(&self, text: &str) -> LinkPart {
+        let mut fields = text.split(';');
+        let kind = self.kind(fields.next().unwrap_or_default());
+        let unit = fields.next().map(str::to_string);
+        let number = |field: Option<&str>| field?.parse().ok();
+        let placement: Vec<_> = self
+            .placement
+            .iter()
+            .map(|_| number(fields.next()))
+            .collect();
+        let subtract = fields.next() == Some("hole");
+        let mut values: Vec<_> = self.kinds[kind]
+            .inputs
+            .iter()
+            .map(|_| number(fields.next()))
+            .collect();
+        values.extend(placement);
+        LinkPart {
+            kind,
+            unit,
+            subtract,
+            values,
+        }
+    }
+
+    /// A part for a link, as `kind;unit;placement…;add|hole;inputs…` with the numbers
+    /// in Rust notation.
+    fn write_link(&self, part: &LinkPart) -> String {
+        let count = self.kinds[part.kind].inputs.len();
+        let number =
+            |value: &Option<f64>| value.map_or_else(String::new, |value| value.to_string());
+        let mut fields = vec![
+            self.kinds[part.kind].id.to_string(),
+            part.unit.clone().unwrap_or_default(),
+        ];
+        fields.extend(part.values[count..].iter().map(number));
+        fields.push(if part.subtract { "hole" } else { "add" }.to_string());
+        fields.extend(part.values[..count].iter().map(number));
+        fields.join(";")
+    }
+}
+
+/// A part as a link holds it, with its values in the unit of the part.
+#[derive(Debug, Clone, PartialEq)]
+struct LinkPart {
+    kind: usize,
+    unit: Option<String>,
+    subtract: bool,
+    /// Inputs of the kind followed by the placement, `None` where a number is missing.
+    values: Vec<Option<f64>>,
+}
+
+/// The calculators a card offers: one, or several kinds to pick from, such as the
+/// kinds of section. Kinds start in the same scale and share the inputs and output
+/// units they have in common by symbol, so values stay when another kind is picked.
+#[derive(Clone, Copy)]
+pub struct CalculatorGroup {
+    pub card: CardInfo,
+    pub kinds: &'static [Calculator],
+    /// Index of the kind the card starts with.
+    pub initial: usize,
+}
+
+impl CalculatorGroup {
+    /// Index of the kind with the given id, or the kind the card starts with.
+    pub fn kind(&self, id: &str) -> usize {
+        self.kinds
+            .iter()
+            .position(|kind| kind.id == id)
+            .unwrap_or(self.initial)
+    }
+}
+
+impl CalculatorGroup {
+    /// The card of a single calculator.
+    pub const fn single(calculator: &'static Calculator) -> Self {
+        CalculatorGroup {
+            card: calculator.card(),
+            kinds: std::slice::from_ref(calculator),
+            initial: 0,
+        }
+    }
+}
+
+impl From<&'static Calculator> for CalculatorGroup {
+    fn from(calculator: &'static Calculator) -> Self {
+        CalculatorGroup::single(calculator)
+    }
+}
+
+/// Every card built from calculators, as reports and other pages without cards look
+/// them up.
+pub static CARDS: [CalculatorGroup; 10] = [
+    CalculatorGroup::single(&RECTANGLE),
+    CalculatorGroup::single(&CIRCLE),
+    CalculatorGroup::single(&TRIANGLE),
+    CalculatorGroup::single(&TRAPEZOID),
+    CalculatorGroup::single(&ELLIPSE),
+    CalculatorGroup::single(&REGULAR_POLYGON),
+    CalculatorGroup::single(&PARALLELOGRAM),
+    SECTIONS,
+    SOLIDS,
+    CalculatorGroup::single(&COMPOSITE),
+];
+
+/// The card with the given id.
+pub fn find_card(id: &str) -> Option<CalculatorGroup> {
+    CARDS.iter().copied().find(|group| group.card.id == id)
+}
+
+/// A value of an input or output, typed by its measure.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnyQuantity {
+    Length(Length),
+    Area(Area),
+    Volume(Volume),
+    SecondMomentOfArea(SecondMomentOfArea),
+    Angle(Angle),
+    Mass(Mass),
+    Density(Density),
+    Count(f64),
+}
+
+/// Converts a typed quantity into the variant of its measure.
+macro_rules! any_quantity_from {
+    ($($dimension:ident),+) => {
+        $(
+            impl From<$dimension> for AnyQuantity {
+                fn from(quantity: $dimension) -> Self {
+                    AnyQuantity::$dimension(quantity)
+                }
+            }
+        )+
+    };
+}
+
+any_quantity_from!(
+    Length,
+    Area,
+    Volume,
+    SecondMomentOfArea,
+    Angle,
+    Mass,
+    Density
+);
+
+/// Reads a quantity of one measure, for values whose measure the calculator declares.
+macro_rules! any_quantity_as {
+    ($($method:ident => $dimension:ident),+) => {
+        $(
+            pub fn $method(self) -> $dimension {
+                match self {
+                    AnyQuantity::$dimension(quantity) => quantity,
+                    other => panic!("expected {}, got {other:?}", stringify!($method)),
+                }
+            }
+        )+
+    };
+}
+
+impl AnyQuantity {
+    any_quantity_as!(
+        length => Length,
+        area => Area,
+        volume => Volume,
+        angle => Angle,
+        density => Density
+    );
+
+    /// The number of a count, such as the number of sides.
+    pub fn count(self) -> f64 {
+        match self {
+            AnyQuantity::Count(count) => count,
+            other => panic!("expected count, got {other:?}"),
+        }
+    }
+
+    /// The value in SI units, or the number of a count.
+    pub fn si(self) -> f64 {
+        match self {
+            AnyQuantity::Length(quantity) => quantity.si(),
+            AnyQuantity::Area(quantity) => quantity.si(),
+            AnyQuantity::Volume(quantity) => quantity.si(),
+            AnyQuantity::SecondMomentOfArea(quantity) => quantity.si(),
+            AnyQuantity::Angle(quantity) => quantity.si(),
+            AnyQuantity::Mass(quantity) => quantity.si(),
+            AnyQuantity::Density(quantity) => quantity.si(),
+            AnyQuantity::Count(count) => count,
+        }
+    }
+
+    /// A quantity of the same measure with the given value in SI units.
+    pub fn with_si(self, si: f64) -> AnyQuantity {
+        match self {
+            AnyQuantity::Length(_) => Length::from_si(si).into(),
+            AnyQuantity::Area(_) => Area::from_si(si).into(),
+            AnyQuantity::Volume(_) => Volume::from_si(si).into(),
+            AnyQuantity::SecondMomentOfArea(_) => SecondMomentOfArea::from_si(si).into(),
+            AnyQuantity::Angle(_) => Angle::from_si(si).into(),
+            AnyQuantity::Mass(_) => Mass::from_si(si).into(),
+            AnyQuantity::Density(_) => Density::from_si(si).into(),
+            AnyQuantity::Count(_) => AnyQuantity::Count(si),
+        }
+    }
+
+    /// The value in the given unit, which must be of the same measure.
+    pub fn value_in(self, unit: AnyUnit) -> f64 {
+        match (self, unit) {
+            (AnyQuantity::Length(quantity), AnyUnit::Length(unit)) => quantity.value_in(unit),
+            (AnyQuantity::Area(quantity), AnyUnit::Area(unit)) => quantity.value_in(unit),
+            (AnyQuantity::Volume(quantity), AnyUnit::Volume(unit)) => quantity.value_in(unit),
+            (AnyQuantity::SecondMomentOfArea(quantity), AnyUnit::SecondMomentOfArea(unit)) => {
+                quantity.value_in(unit)
+            }
+            (AnyQuantity::Angle(quantity), AnyUnit::Angle(unit)) => quantity.value_in(unit),
+            (AnyQuantity::Mass(quantity), AnyUnit::Mass(unit)) => quantity.value_in(unit),
+            (AnyQuantity::Density(quantity), AnyUnit::Density(unit)) => quantity.value_in(unit),
+            (AnyQuantity::Count(count), AnyUnit::Count) => count,
+            (quantity, unit) => panic!("{quantity:?} cannot be given in {unit:?}"),
+        }
+    }
+}
+
+/// Lengths in metres as values, such as the dimensions a solve mode finds.
+pub fn metres(lengths: &[f64]) -> Vec<AnyQuantity> {
+    lengths
+        .iter()
+        .map(|&length| Length::from_si(length).into())
+        .collect()
+}
+
+/// Unit of an input or output, whatever its measure.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnyUnit {
+    Length(LengthUnit),
+    Area(AreaUnit),
+    Volume(VolumeUnit),
+    SecondMomentOfArea(SecondMomentOfAreaUnit),
+    Angle(AngleUnit),
+    Mass(MassUnit),
+    Density(DensityUnit),
+    Count,
+}
+
+/// Evaluates an expression with the typed unit, or the alternative for counts.
+macro_rules! with_any_unit {
+    ($any:expr, $unit:ident => $body:expr, Count => $count:expr) => {
+        match $any {
+            AnyUnit::Length($unit) => $body,
+            AnyUnit::Area($unit) => $body,
+            AnyUnit::Volume($unit) => $body,
+            AnyUnit::SecondMomentOfArea($unit) => $body,
+            AnyUnit::Angle($unit) => $body,
+            AnyUnit::Mass($unit) => $body,
+            AnyUnit::Density($unit) => $body,
+            AnyUnit::Count => $count,
+        }
+    };
+}
+
+impl AnyUnit {
+    /// The unit a card starts with for a measure: the SI unit, or its millimetre
+    /// counterpart, except degrees for angles.
+    pub fn default_for(measure: Measure, scale: Scale) -> Self {
+        match (measure, scale) {
+            (Measure::Angle, _) => AnyUnit::Angle(AngleUnit::Degree),
+            (Measure::Length, Scale::Millimetre) => AnyUnit::Length(LengthUnit::Millimetre),
+            (Measure::Area, Scale::Millimetre) => AnyUnit::Area(AreaUnit::SquareMillimetre),
+            (Measure::Volume, Scale::Millimetre) => AnyUnit::Volume(VolumeUnit::CubicMillimetre),
+            (Measure::SecondMomentOfArea, Scale::Millimetre) => {
+                AnyUnit::SecondMomentOfArea(SecondMomentOfAreaUnit::MillimetreToTheFourth)
+            }
+            (Measure::Length, Scale::Metre) => AnyUnit::Length(LengthUnit::Metre),
+            (Measure::Area, Scale::Metre) => AnyUnit::Area(AreaUnit::SquareMetre),
+            (Measure::Volume, Scale::Metre) => AnyUnit::Volume(VolumeUnit::CubicMetre),
+            (Measure::SecondMomentOfArea, Scale::Metre) => {
+                AnyUnit::SecondMomentOfArea(SecondMomentOfAreaUnit::MetreToTheFourth)
+            }
+            (Measure::Mass, _) => AnyUnit::Mass(MassUnit::Kilogram),
+            (Measure::Density, _) => AnyUnit::Density(DensityUnit::KilogramPerCubicMetre),
+            (Measure::Count, _) => AnyUnit::Count,
+        }
+    }
+
+    /// The quantity of a value given in this unit.
+    pub fn quantity(self, value: f64) -> AnyQuantity {
+        match self {
+            AnyUnit::Length(unit) => Length::new(value, unit).into(),
+            AnyUnit::Area(unit) => Area::new(value, unit).into(),
+            AnyUnit::Volume(unit) => Volume::new(value, unit).into(),
+            AnyUnit::SecondMomentOfArea(unit) => SecondMomentOfArea::new(value, unit).into(),
+            AnyUnit::Angle(unit) => Angle::new(value, unit).into(),
+            AnyUnit::Mass(unit) => Mass::new(value, unit).into(),
+            AnyUnit::Density(unit) => Density::new(value, unit).into(),
+            AnyUnit::Count => AnyQuantity::Count(value),
+        }
+    }
+
+    /// The SI unit of a measure.
+    #[cfg(feature = "ssr")]
+    pub fn si(measure: Measure) -> Self {
+        match measure {
+            Measure::Length => AnyUnit::Length(LengthUnit::Metre),
+            Measure::Area => AnyUnit::Area(AreaUnit::SquareMetre),
+            Measure::Volume => AnyUnit::Volume(VolumeUnit::CubicMetre),
+            Measure::SecondMomentOfArea => {
+                AnyUnit::SecondMomentOfArea(SecondMomentOfAreaUnit::MetreToTheFourth)
+            }
+            Measure::Angle => AnyUnit::Angle(AngleUnit::Radian),
+            Measure::Mass => AnyUnit::Mass(MassUnit::Kilogram),
+            Measure::Density => AnyUnit::Density(DensityUnit::KilogramPerCubicMetre),
+            Measure::Count => AnyUnit::Count,
+        }
+    }
+
+    /// Every unit of a measure, in the order they are offered; none for counts.
+    #[cfg(feature = "ssr")]
+    pub fn all(measure: Measure) -> Vec<Self> {
+        fn all<U: Unit>(any: fn(U) -> AnyUnit) -> Vec<AnyUnit> {
+            U::ALL.iter().copied().map(any).collect()
+        }
+        match measure {
+            Measure::Length => all(AnyUnit::Length),
+            Measure::Area => all(AnyUnit::Area),
+            Measure::Volume => all(AnyUnit::Volume),
+            Measure::SecondMomentOfArea => all(AnyUnit::SecondMomentOfArea),
+            Measure::Angle => all(AnyUnit::Angle),
+            Measure::Mass => all(AnyUnit::Mass),
+            Measure::Density => all(AnyUnit::Density),
+            Measure::Count => Vec::new(),
+        }
+    }
+
+    /// The unit of a measure with the given symbol.
+    pub fn from_symbol(measure: Measure, symbol: &str) -> Option<Self> {
+        Self::parse(measure, "unit", symbol).ok()
+    }
+
+    /// The unit of `parameter` with the given symbol, failing for units of another measure.
+    pub fn parse(
+        measure: Measure,
+        parameter: &'static str,
+        symbol: &str,
+    ) -> Result<Self, CalculationError> {
+        match measure {
+            Measure::Length => LengthUnit::parse(parameter, symbol).map(AnyUnit::Length),
+            Measure::Area => AreaUnit::parse(parameter, symbol).map(AnyUnit::Area),
+            Measure::Volume => VolumeUnit::parse(parameter, symbol).map(AnyUnit::Volume),
+            Measure::SecondMomentOfArea => {
+                SecondMomentOfAreaUnit::parse(parameter, symbol).map(AnyUnit::SecondMomentOfArea)
+            }
+            Measure::Angle => AngleUnit::parse(parameter, symbol).map(AnyUnit::Angle),
+            Measure::Mass => MassUnit::parse(parameter, symbol).map(AnyUnit::Mass),
+            Measure::Density => DensityUnit::parse(parameter, symbol).map(AnyUnit::Density),
+            Measure::Count => Err(CalculationError::UnitMismatch {
+                parameter,
+                unit: symbol.to_string(),
+            }),
+        }
+    }
+
+    /// Symbol of the unit, `None` for counts.
+    pub fn symbol(self) -> Option<&'static str> {
+        with_any_unit!(self, unit => Some(unit.symbol()), Count => None)
+    }
+
+    /// A value in SI units in this unit.
+    pub fn to_unit(self, si: f64) -> f64 {
+        with_any_unit!(self, unit => unit.to_unit(si), Count => si)
+    }
+
+    /// A tolerance in this unit in SI units, which unlike a value has no offset.
+    pub fn tolerance_si(self, tolerance: f64) -> f64 {
+        with_any_unit!(self, unit => tolerance * unit.factor(), Count => tolerance)
+    }
+
+    /// A quantity as LaTeX in this unit, followed by the unit.
+    pub fn latex(self, quantity: AnyQuantity, numbers: NumberFormat) -> String {
+        format!(
+            "{}{}",
+            numbers.format_latex(quantity.value_in(self)),
+            self.latex_unit()
+        )
+    }
+
+    /// A quantity with a tolerance in SI units as LaTeX, like `(100 \pm 0.2)\,\text{mm}`.
+    pub fn latex_toleranced(
+        self,
+        quantity: AnyQuantity,
+        tolerance: f64,
+        numbers: NumberFormat,
+    ) -> String {
+        let value = Toleranced::new(quantity.si(), tolerance).map(|si| self.to_unit(si));
+        format!(
+            r"\left({} \pm {}\right){}",
+            numbers.format_latex(value.nominal),
+            numbers.format_latex(value.tolerance),
+            self.latex_unit()
+        )
+    }
+
+    /// The range of a value in SI units as LaTeX, like
+    /// `A_{min} = 4\,\text{m²},\; A_{max} = 6\,\text{m²},\; u_{A} = 1\,\text{m²}`.
+    pub fn latex_range(self, symbol: &str, si: Propagated, numbers: NumberFormat) -> String {
+        let range = si.map(|si| self.to_unit(si));
+        let write = |value: f64| format!("{}{}", numbers.format_latex(value), self.latex_unit());
+        format!(
+            r"{{{symbol}}}_{{min}} = {},\; {{{symbol}}}_{{max}} = {},\; u_{{{symbol}}} = {}",
+            write(range.min),
+            write(range.max),
+            write(range.uncertainty),
+        )
+    }
+
+    fn latex_unit(self) -> String {
+        with_any_unit!(self, unit => latex_unit(unit), Count => String::new())
+    }
+}
+
+/// A quantity as it is shown in a unit.
+fn shown_value(symbol: &str, quantity: AnyQuantity, unit: AnyUnit) -> HistoryValue {
+    HistoryValue::new(symbol, quantity.value_in(unit), unit.symbol().unwrap_or(""))
+}
+
+/// A calculation read from the saved state of a calculator card, the query of its
+/// link, for showing it outside the card.
+pub struct SavedCalculation {
+    /// The kind of the card the state was saved with.
+    pub calculator: &'static Calculator,
+    /// Units of the inputs of all solve modes, by symbol.
+    input_units: Vec<(&'static str, AnyUnit)>,
+    /// Unit of the parts, that of the first one.
+    part_unit: Option<LengthUnit>,
+    pub output_units: Vec<AnyUnit>,
+    /// `None` while an input of the solve mode has no value.
+    pub outcome: Outcome<Solution>,
+}
+
+impl SavedCalculation {
+    /// Reads a state as the card restores it; missing units are the ones the card
+    /// starts with.
+    pub fn read(group: CalculatorGroup, state: &[(String, String)]) -> Self {
+        let get = |key: &str| {
+            state
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, value)| value.as_str())
+        };
+        let calculator = &group.kinds[group.kind(get("kind").unwrap_or_default())];
+        let scale = calculator.scale;
+        let unit = |measure: Measure, symbol: Option<&str>| {
+            symbol
+                .and_then(|symbol| AnyUnit::from_symbol(measure, symbol))
+                .unwrap_or(AnyUnit::default_for(measure, scale))
+        };
+        let mode = calculator
+            .solve_modes
+            .iter()
+            .position(|mode| Some(mode.id) == get("mode"));
+        let mut input_units: Vec<(&'static str, AnyUnit)> = Vec::new();
+        for input in calculator.all_inputs() {
+            if !input_units
+                .iter()
+                .any(|(symbol, _)| *symbol == input.symbol)
+            {
+                let key = format!("{}_unit", input.symbol);
+                input_units.push((input.symbol, unit(input.measure, get(&key))));
+            }
+        }
+        let output_units = calculator
+            .outputs
+            .iter()
+            .map(|output| {
+                let key = format!("{}_result_unit", output.symbol);
+                unit(output.measure, get(&key))
+            })
+            .collect();
+
+        let mut calculation = SavedCalculation {
+            calculator,
+            input_units,
+            part_unit: None,
+            output_units,
+            outcome: None,
+        };
+        let values: Option<Vec<AnyQuantity>> = match calculator.parts {
+            Some(parts) => {
+                let mut values = Some(Vec::new());
+                for (_, text) in state.iter().filter(|(key, _)| key == "part") {
+                    let part = parts.read_link(text);
+                    let AnyUnit::Length(part_unit) = unit(Measure::Length, part.unit.as_deref())
+                    else {
+                        unreachable!("the unit of a part is a length unit");
+                    };
+                    calculation.part_unit.get_or_insert(part_unit);
+                    let kind = [part.kind as f64, if part.subtract { 1.0 } else { 0.0 }];
+                    let part_values: Option<Vec<AnyQuantity>> =
+                        kind.into_iter()
+                            .map(|count| Some(AnyQuantity::Count(count)))
+                            .chain(part.values.iter().map(|value| {
+                                value.map(|value| Length::new(value, part_unit).into())
+                            }))
+                            .collect();
+                    values = values.zip(part_values).map(|(mut values, part)| {
+                        values.extend(part);
+                        values
+                    });
+                }
+                values
+            }
+            None => calculator
+                .entered_inputs(mode)
+                .iter()
+                .map(|input| {
+                    let value: f64 = get(input.symbol)?.parse().ok()?;
+                    Some(calculation.input_unit(input.symbol).quantity(value))
+                })
+                .collect(),
+        };
+        // Tolerances are in the unit of their value, and zero without one
+        let tolerances: Vec<f64> = calculator
+            .entered_inputs(mode)
+            .iter()
+            .map(|input| {
+                let tolerance: f64 = get(&format!("{}_tol", input.symbol))
+                    .and_then(|tolerance| tolerance.parse().ok())
+                    .unwrap_or(0.0);
+                calculation.input_unit(input.symbol).tolerance_si(tolerance)
+            })
+            .collect();
+        calculation.outcome =
+            values.map(|values| calculator.solve_toleranced(mode, values, &tolerances));
+        calculation
+    }
+
+    pub fn input_unit(&self, symbol: &str) -> AnyUnit {
+        self.input_units
+            .iter()
+            .find(|(s, _)| *s == symbol)
+            .map_or(AnyUnit::Count, |(_, unit)| *unit)
+    }
+
+    pub fn solution(&self) -> Option<&Solution> {
+        self.outcome.as_ref()?.as_ref().ok()
+    }
+
+    /// The entered values in the unit they were entered in.
+    pub fn inputs(&self) -> Vec<HistoryValue> {
+        self.solution().map_or_else(Vec::new, |solution| {
+            self.calculator
+                .entered_values(solution, |symbol| self.input_unit(symbol))
+        })
+    }
+
+    /// The inputs found by the solve mode and the outputs with a result text.
+    pub fn results(&self) -> Vec<HistoryValue> {
+        self.solution().map_or_else(Vec::new, |solution| {
+            self.calculator.result_values(
+                solution,
+                |symbol| self.input_unit(symbol),
+                &self.output_units,
+            )
+        })
+    }
+
+    pub fn formula(&self, numbers: NumberFormat) -> Option<String> {
+        let formula = self.calculator.formula_latex(
+            self.solution()?,
+            |symbol| self.input_unit(symbol),
+            &self.output_units,
+            numbers,
+        );
+        Some(formula)
+    }
+
+    /// The drawing, in the unit of the first part or else of the first length input.
+    pub fn drawing(&self, numbers: NumberFormat) -> Option<String> {
+        let drawing_unit = match self.calculator.parts {
+            Some(_) => self.part_unit,
+            None => self
+                .calculator
+                .drawing_unit(|symbol| self.input_unit(symbol)),
+        };
+        self.calculator
+            .drawing_svg(self.solution()?, drawing_unit, numbers)
+    }
+}
+
+/// Unit signal of an input or output, whatever its measure.
+#[derive(Clone, Copy)]
+enum UnitSignal {
+    Length(RwSignal<LengthUnit>),
+    Area(RwSignal<AreaUnit>),
+    Volume(RwSignal<VolumeUnit>),
+    SecondMomentOfArea(RwSignal<SecondMomentOfAreaUnit>),
+    Angle(RwSignal<AngleUnit>),
+    Mass(RwSignal<MassUnit>),
+    Density(RwSignal<DensityUnit>),
+    Count,
+}
+
+/// Evaluates an expression with the typed unit signal, or the alternative for counts.
+macro_rules! with_unit {
+    ($signal:expr, $unit:ident => $body:expr, Count => $count:expr) => {
+        match $signal {
+            UnitSignal::Length($unit) => $body,
+            UnitSignal::Area($unit) => $body,
+            UnitSignal::Volume($unit) => $body,
+            UnitSignal::SecondMomentOfArea($unit) => $body,
+            UnitSignal::Angle($unit) => $body,
+            UnitSignal::Mass($unit) => $body,
+            UnitSignal::Density($unit) => $body,
+            UnitSignal::Count => $count,
+        }
+    };
+}
+
+impl UnitSignal {
+    fn new(measure: Measure, scale: Scale) -> Self {
+        match AnyUnit::default_for(measure, scale) {
+            AnyUnit::Length(unit) => UnitSignal::Length(RwSignal::new(unit)),
+            AnyUnit::Area(unit) => UnitSignal::Area(RwSignal::new(unit)),
+            AnyUnit::Volume(unit) => UnitSignal::Volume(RwSignal::new(unit)),
+            AnyUnit::SecondMomentOfArea(unit) => {
+                UnitSignal::SecondMomentOfArea(RwSignal::new(unit))
+            }
+            AnyUnit::Angle(unit) => UnitSignal::Angle(RwSignal::new(unit)),
+            AnyUnit::Mass(unit) => UnitSignal::Mass(RwSignal::new(unit)),
+            AnyUnit::Density(unit) => UnitSignal::Density(RwSignal::new(unit)),
+            AnyUnit::Count => UnitSignal::Count,
+        }
+    }
+
+    /// The selected unit.
+    fn get(self) -> AnyUnit {
+        match self {
+            UnitSignal::Length(unit) => AnyUnit::Length(unit.get()),
+            UnitSignal::Area(unit) => AnyUnit::Area(unit.get()),
+            UnitSignal::Volume(unit) => AnyUnit::Volume(unit.get()),
+            UnitSignal::SecondMomentOfArea(unit) => AnyUnit::SecondMomentOfArea(unit.get()),
+            UnitSignal::Angle(unit) => AnyUnit::Angle(unit.get()),
+            UnitSignal::Mass(unit) => AnyUnit::Mass(unit.get()),
+            UnitSignal::Density(unit) => AnyUnit::Density(unit.get()),
+            UnitSignal::Count => AnyUnit::Count,
+        }
+    }
+
+    /// Symbol of the selected unit, `None` for counts.
+    fn symbol(self) -> Option<&'static str> {
+        with_unit!(self, unit => Some(unit.get().symbol()), Count => None)
+    }
+
+    fn restore(self, query: &ParamsMap, key: &str) {
+        with_unit!(self, unit => restore_unit(query, key, unit), Count => ())
+    }
+
+    /// Checks an input, which may hold a tolerance after its value when `toleranced`.
+    fn check(
+        self,
+        parameter: &'static str,
+        value: RwSignal<String>,
+        rule: Rule,
+        toleranced: bool,
+    ) -> Signal<Option<CalculationError>> {
+        with_unit!(
+            self,
+            unit => if toleranced {
+                check_toleranced_quantity(parameter, value, unit, rule)
+            } else {
+                check_quantity(parameter, value, unit, rule)
+            },
+            Count => check_number(parameter, value, rule)
+        )
+    }
+
+    fn input_view(
+        self,
+        value: RwSignal<String>,
+        id: String,
+        placeholder: &'static str,
+        label_key: Option<&'static str>,
+        check: Signal<Option<CalculationError>>,
+    ) -> AnyView {
+        with_unit!(
+            self,
+            unit => view! {
+                <QuantityInput
+                    value=value
+                    unit=unit
+                    id=id
+                    placeholder=placeholder
+                    label_key=label_key
+                    check=check
+                />
+            }
+            .into_any(),
+            Count => view! {
+                <NumberInput
+                    value=value
+                    id=id.clone()
+                    placeholder=placeholder
+                    label_key=label_key
+                    check=check
+                />
+                <InputMessage value=value input_id=id check=check />
+            }
+            .into_any()
+        )
+    }
+
+    fn select_view(self) -> AnyView {
+        with_unit!(self, unit => view! { <UnitSelect unit=unit /> }.into_any(), Count => ().into_any())
+    }
+}
+
+/// State of one input of a calculator card.
+#[derive(Clone, Copy)]
+struct InputState {
+    value: RwSignal<String>,
+    unit: UnitSignal,
+}
+
+impl InputState {
+    /// The entered value in its unit, without its tolerance; unparsable input counts
+    /// as zero.
+    fn quantity(&self, numbers: NumberFormat) -> AnyQuantity {
+        self.unit.get().quantity(self.toleranced(numbers).nominal)
+    }
+
+    /// The tolerance of the entered value in SI units, zero without one.
+    fn tolerance(&self, numbers: NumberFormat) -> f64 {
+        self.unit
+            .get()
+            .tolerance_si(self.toleranced(numbers).tolerance)
+    }
+
+    fn toleranced(&self, numbers: NumberFormat) -> Toleranced {
+        let (nominal, tolerance) = numbers
+            .parse_toleranced(&self.value.get())
+            .unwrap_or((0.0, 0.0));
+        Toleranced::new(nominal, tolerance)
+    }
+}
+
+/// Inputs of all kinds and solve modes of a card; those entering the same symbol
+/// share its input.
+#[derive(Clone)]
+struct Fields(Vec<(&'static str, InputState)>);
+
+impl Fields {
+    fn new(
+        inputs: impl IntoIterator<Item = &'static CalculatorInput>,
+        unit: impl Fn(Measure) -> UnitSignal,
+    ) -> Self {
+        let mut fields: Vec<(&'static str, InputState)> = Vec::new();
+        for input in inputs {
+            if fields.iter().any(|(symbol, _)| *symbol == input.symbol) {
+                continue;
+            }
+            let value = RwSignal::new(String::new());
+            let unit = unit(input.measure);
+            fields.push((input.symbol, InputState { value, unit }));
+        }
+        Fields(fields)
+    }
+
+    fn get(&self, symbol: &str) -> InputState {
+        self.0
+            .iter()
+            .find(|(s, _)| *s == symbol)
+            .map(|(_, state)| *state)
+            .expect("every input of a calculator has a field")
+    }
+
+    /// Checks of the given inputs against their own rule, as kinds sharing a field
+    /// may constrain it differently; `toleranced` inputs may hold a tolerance.
+    fn checks<'a>(
+        &self,
+        inputs: impl IntoIterator<Item = &'a CalculatorInput>,
+        toleranced: bool,
+    ) -> Vec<Signal<Option<CalculationError>>> {
+        inputs
+            .into_iter()
+            .map(|input| {
+                let state = self.get(input.symbol);
+                state
+                    .unit
+                    .check(input.parameter, state.value, input.rule, toleranced)
+            })
+            .collect()
+    }
+}
+
+/// Units of the outputs of all kinds of a card; kinds with the same output share its unit.
+#[derive(Clone)]
+struct OutputUnits(Vec<(&'static str, UnitSignal)>);
+
+impl OutputUnits {
+    fn new(outputs: impl IntoIterator<Item = &'static CalculatorOutput>, scale: Scale) -> Self {
+        let mut units: Vec<(&'static str, UnitSignal)> = Vec::new();
+        for output in outputs {
+            if !units.iter().any(|(symbol, _)| *symbol == output.symbol) {
+                units.push((output.symbol, UnitSignal::new(output.measure, scale)));
+            }
+        }
+        OutputUnits(units)
+    }
+
+    fn get(&self, symbol: &str) -> UnitSignal {
+        self.0
+            .iter()
+            .find(|(s, _)| *s == symbol)
+            .map(|(_, unit)| *unit)
+            .expect("every output of a calculator has a unit")
+    }
+
+    /// The selected units of the outputs of a calculator, in their order.
+    fn of(&self, calculator: &Calculator) -> Vec<AnyUnit> {
+        calculator
+            .outputs
+            .iter()
+            .map(|output| self.get(output.symbol).get())
+            .collect()
+    }
+}
+
+/// One row of a card with parts: the kind of the part, its inputs and placement, all
+/// entered in the unit of the row.
+#[derive(Clone, Copy)]
+struct PartRow {
+    id: usize,
+    kind: RwSignal<usize>,
+    subtract: RwSignal<bool>,
+    unit: RwSignal<LengthUnit>,
+    fields: StoredValue<Fields>,
+    /// Checks of the inputs and placement of every kind.
+    checks: StoredValue<Vec<Vec<Signal<Option<CalculationError>>>>>,
+}
+
+impl PartRow {
+    /// A row of the first kind, placed at the origin.
+    fn new(id: usize, parts: &'static Parts, scale: Scale) -> Self {
+        let unit = RwSignal::new(match scale {
+            Scale::Metre => LengthUnit::Metre,
+            Scale::Millimetre => LengthUnit::Millimetre,
+        });
+        let inputs = parts
+            .kinds
+            .iter()
+            .flat_map(|kind| kind.inputs)
+            .chain(parts.placement);
+        let fields = Fields::new(inputs, |_| UnitSignal::Length(unit));
+        for input in parts.placement {
+            fields.get(input.symbol).value.set("0".to_string());
+        }
+        let checks = (0..parts.kinds.len())
+            .map(|kind| fields.checks(parts.inputs(kind), false))
+            .collect();
+        PartRow {
+            id,
+            kind: RwSignal::new(0),
+            subtract: RwSignal::new(false),
+            unit,
+            fields: StoredValue::new(fields),
+            checks: StoredValue::new(checks),
+        }
+    }
+
+    /// A row holding a part read from a link.
+    fn restore(
+        id: usize,
+        parts: &'static Parts,
+        scale: Scale,
+        part: &LinkPart,
+        numbers: NumberFormat,
+    ) -> Self {
+        let row = PartRow::new(id, parts, scale);
+        row.kind.set(part.kind);
+        row.subtract.set(part.subtract);
+        if let Some(unit) = part.unit.as_deref().and_then(LengthUnit::from_symbol) {
+            row.unit.set(unit);
+        }
+        let fields = row.fields.get_value();
+        for (input, value) in parts.inputs(part.kind).zip(&part.values) {
+            let text = value.map_or_else(String::new, |value| numbers.format_exact(value));
+            fields.get(input.symbol).value.set(text);
+        }
+        row
+    }
+
+    /// Checks of the inputs of the selected kind and of the placement.
+    fn checks(&self) -> Vec<Signal<Option<CalculationError>>> {
+        self.checks.get_value()[self.kind.get()].clone()
+    }
+
+    /// The values of the part as the calculator receives them.
+    fn values(&self, parts: &'static Parts, numbers: NumberFormat) -> Vec<AnyQuantity> {
+        let fields = self.fields.get_value();
+        let kind = self.kind.get();
+        let subtract = if self.subtract.get() { 1.0 } else { 0.0 };
+        [
+            AnyQuantity::Count(kind as f64),
+            AnyQuantity::Count(subtract),
+        ]
+        .into_iter()
+        .chain(
+            parts
+                .inputs(kind)
+                .map(|input| fields.get(input.symbol).quantity(numbers)),
+        )
+        .collect()
+    }
+
+    /// The part as a link holds it.
+    fn link(&self, parts: &'static Parts, numbers: NumberFormat) -> LinkPart {
+        let fields = self.fields.get_value();
+        let kind = self.kind.get();
+        LinkPart {
+            kind,
+            unit: Some(self.unit.get().symbol().to_string()),
+            subtract: self.subtract.get(),
+            values: parts
+                .inputs(kind)
+                .map(|input| numbers.parse(&fields.get(input.symbol).value.get()))
+                .collect(),
+        }
+    }
+}
+
+/// Describes the range of a result in the unit it is shown in, like
+/// `Range 4 … 6, uncertainty (RSS) ± 1`.
+pub fn range_text(i18n: &I18n, numbers: NumberFormat, range: Propagated) -> String {
+    format!(
+        "{} {} … {}, {} ± {}",
+        i18n.t("tolerance_range"),
+        numbers.format(range.min),
+        numbers.format(range.max),
+        i18n.t("tolerance_uncertainty"),
+        numbers.format(range.uncertainty)
+    )
+}
+
+/// Card that takes the inputs of a calculator, computes its outputs and shows the
+/// formula, the drawing and the results. With several kinds, the user picks one.
+#[allow(non_snake_case)]
+#[component]
+pub fn CalculatorCard(#[prop(into)] calculator: CalculatorGroup) -> impl IntoView {
+    let i18n = use_context::<Memo<I18n>>().expect("I18n context not found");
+    let numbers = Memo::new(move |_| i18n.get().number_format());
+
+    let group = calculator;
+    let scale = group.kinds[0].scale;
+    let all_inputs = group.kinds.iter().flat_map(Calculator::all_inputs);
+    let fields = Fields::new(all_inputs, |measure| UnitSignal::new(measure, scale));
+    // Checks of every kind, first of its inputs, then of the inputs of each solve mode
+    let checks: Vec<Vec<_>> = group
+        .kinds
+        .iter()
+        .map(|kind| {
+            std::iter::once(kind.inputs)
+                .chain(kind.solve_modes.iter().map(|mode| mode.inputs))
+                .map(|inputs| fields.checks(inputs, true))
+                .collect()
+        })
+        .collect();
+    let fields = StoredValue::new(fields);
+    let checks = StoredValue::new(checks);
+    let all_outputs = group.kinds.iter().flat_map(|kind| kind.outputs);
+    let outputs = StoredValue::new(OutputUnits::new(all_outputs, scale));
+    let kind = RwSignal::new(group.initial);
+    // `None` calculates from the dimensions, otherwise the index of the solve mode
+    let mode = RwSignal::new(None::<usize>);
+    let parts = group.kinds.iter().find_map(|kind| kind.parts);
+    let next_id = StoredValue::new(1);
+    let rows =
+        RwSignal::new(parts.map_or_else(Vec::new, |parts| vec![PartRow::new(0, parts, scale)]));
+
+    restore_shared(group.card.path, move |query| {
+        let numbers = numbers.get_untracked();
+        let restored = group.kind(query.get_str("kind").unwrap_or_default());
+        let id = query.get_str("mode");
+        mode.set(None);
+        kind.set(restored);
+        mode.set(
+            group.kinds[restored]
+                .solve_modes
+                .iter()
+                .position(|mode| Some(mode.id) == id),
+        );
+        for (symbol, state) in &fields.get_value().0 {
+            restore_number(query, symbol, state.value, numbers);
+            state.unit.restore(query, &format!("{symbol}_unit"));
+        }
+        for (symbol, unit) in outputs.get_value().0 {
+            unit.restore(query, &format!("{symbol}_result_unit"));
+        }
+        if let (Some(parts), Some(links)) = (parts, query.get_all("part")) {
+            // Fresh ids, so no view of a replaced row is reused
+            let first_id = next_id.get_value();
+            next_id.set_value(first_id + links.len());
+            rows.set(
+                links
+                    .iter()
+                    .enumerate()
+                    .map(|(index, link)| {
+                        let part = parts.read_link(link);
+                        PartRow::restore(first_id + index, parts, scale, &part, numbers)
+                    })
+                    .collect(),
+            );
+        }
+    });
+
+    let calculator = move || &group.kinds[kind.get()];
+    let solve_mode = move || mode.get().map(|index| &calculator().solve_modes[index]);
+    let active_inputs = move || calculator().entered_inputs(mode.get());
+    let inputs_valid = move || {
+        let mut checks =
+            checks.get_value()[kind.get()][mode.get().map_or(0, |index| index + 1)].clone();
+        if calculator().parts.is_some() {
+            checks.extend(rows.get().iter().flat_map(PartRow::checks));
+        }
+        all_valid(&checks)
+    };
+    let input_unit = move |symbol: &str| fields.get_value().get(symbol).unit;
+
+    // The kind is kept with the solution, so the results show the outputs it has
+    let calculation = LiveCalculation::new(move || {
+        if !inputs_valid() {
+            return None;
+        }
+        let numbers = numbers.get();
+        let fields = fields.get_value();
+        let kind = kind.get();
+        let calculator = &group.kinds[kind];
+        // Parts are entered without tolerances
+        let (values, tolerances): (Vec<AnyQuantity>, Vec<f64>) = match calculator.parts {
+            Some(parts) => rows
+                .get()
+                .iter()
+                .flat_map(|row| row.values(parts, numbers))
+                .map(|value| (value, 0.0))
+                .unzip(),
+            None => active_inputs()
+                .iter()
+                .map(|input| {
+                    let state = fields.get(input.symbol);
+                    (state.quantity(numbers), state.tolerance(numbers))
+                })
+                .unzip(),
+        };
+        Some(
+            calculator
+                .solve_toleranced(mode.get(), values, &tolerances)
+                .map(|solution| (kind, solution)),
+        )
+    });
+    // The solution of the selected kind, which has its outputs
+    let solution = move || {
+        calculation
+            .value()
+            .filter(|(solved_kind, _)| *solved_kind == kind.get())
+            .map(|(_, solution)| solution)
+    };
+    let error = calculation.error();
+    // Errors of values without an input of their own, such as parts, solved
+    // dimensions or results that overflowed
+    let unplaced_error = Signal::derive(move || {
+        error.get().filter(|err: &CalculationError| {
+            !active_inputs()
+                .iter()
+                .any(|input| input.parameter == err.parameter())
+        })
+    });
+
+    // Range of a result in its unit, when an entered value has a tolerance
+    let result_range = move |symbol: &'static str, unit: UnitSignal| {
+        let range = solution()?.range(symbol)?;
+        Some(range_text(
+            &i18n.get(),
+            numbers.get(),
+            range.map(|si| unit.get().to_unit(si)),
+        ))
+    };
+
+    let latex = move || {
+        let (kind, solution) = calculation.value()?;
+        let calculator = &group.kinds[kind];
+        Some(calculator.formula_latex(
+            &solution,
+            |symbol| input_unit(symbol).get(),
+            &outputs.get_value().of(calculator),
+            numbers.get(),
+        ))
+    };
+    let formula = move || latex().map(|latex| latex_to_mathml(&latex));
+
+    // Parts are drawn in the unit of the first row
+    let drawing = move || {
+        let calculator = calculator();
+        let drawing_unit = match calculator.parts {
+            Some(_) => rows.get().first().map(|row| row.unit.get()),
+            None => calculator.drawing_unit(|symbol| input_unit(symbol).get()),
+        };
+        calculator.drawing_svg(&solution()?, drawing_unit, numbers.get())
+    };
+
+    let state = move || {
+        let numbers = numbers.get();
+        let fields = fields.get_value();
+        let calculator = calculator();
+        let mut query = ParamsMap::new();
+        if group.kinds.len() > 1 {
+            query.insert("kind", calculator.id.to_string());
+        }
+        if let Some(solve_mode) = solve_mode() {
+            query.insert("mode", solve_mode.id.to_string());
+        }
+        for input in active_inputs() {
+            let state = fields.get(input.symbol);
+            if let Some(number) = link_number(&state.value.get(), numbers) {
+                query.insert(input.symbol, number);
+            }
+            if let Some(tolerance) = link_tolerance(&state.value.get(), numbers) {
+                query.insert(format!("{}_tol", input.symbol), tolerance);
+            }
+            if let Some(unit) = state.unit.symbol() {
+                query.insert(format!("{}_unit", input.symbol), unit.to_string());
+            }
+        }
+        if let Some(parts) = calculator.parts {
+            for row in rows.get() {
+                query.insert("part", parts.write_link(&row.link(parts, numbers)));
+            }
+        }
+        let units = outputs.get_value();
+        for output in calculator.outputs {
+            let unit = units.get(output.symbol).symbol();
+            if let (Some(_), Some(unit)) = (output.label_key, unit) {
+                query.insert(format!("{}_result_unit", output.symbol), unit.to_string());
+            }
+        }
+        query
+    };
+
+    // Entered values and results in the units they are shown in
+    let shown_values = move || {
+        let (kind, solution) = calculation.value()?;
+        let calculator = &group.kinds[kind];
+        let input_unit = |symbol: &str| input_unit(symbol).get();
+        let output_units = outputs.get_value().of(calculator);
+        Some((
+            calculator.entered_values(&solution, input_unit),
+            calculator.result_values(&solution, input_unit, &output_units),
+        ))
+    };
+
+    record_history(move || {
+        let (inputs, results) = shown_values()?;
+        Some(HistoryEntry::new(
+            group.card,
+            card_link(group.card.path, &state()),
+            inputs,
+            results,
+        ))
+    });
+
+    let export = Signal::derive(move || {
+        let (inputs, results) = shown_values()?;
+        Some(ResultExport {
+            inputs,
+            results,
+            latex: latex(),
+        })
+    });
+    let toast = CopyToast::default();
+
+    let add_row = move |_| {
+        let Some(parts) = parts else {
+            return;
+        };
+        let id = next_id.get_value();
+        next_id.set_value(id + 1);
+        rows.update(|rows| rows.push(PartRow::new(id, parts, scale)));
+    };
+
+    let variable_view = move |(symbols, key): &'static (&'static str, &'static str)| {
+        view! { <p>{*symbols}" = "{move || i18n.get().t(key).to_string()}</p> }
+    };
+
+    view! {
+      <div class="card">
+        <a class="card__title">{move || i18n.get().t(group.card.title_key).to_string()}</a>
+        <div class="card__variables">
+            {move || calculator().variables.iter().map(variable_view).collect_view()}
+            {move || {
+                solve_mode()
+                    .map(|mode| mode.variables.iter().map(variable_view).collect_view())
+            }}
+            {move || {
+                let constraints = match solve_mode() {
+                    Some(_) => &[][..],
+                    None => calculator().constraints,
+                };
+                constraints
+                    .iter()
+                    .map(|constraint| view! { <p>{*constraint}</p> })
+                    .collect_view()
+            }}
+        </div>
+        <div class="card__inputs">
+          <Show when=move || { group.kinds.len() > 1 }>
+            <select
+              prop:value=move || calculator().id
+              on:change=move |ev| {
+                  kind.set(group.kind(&event_target_value(&ev)));
+                  mode.set(None);
+              }
+            >
+              {group
+                  .kinds
+                  .iter()
+                  .map(|option| {
+                      view! {
+                          <option value=option.id>
+                              {move || i18n.get().t(option.title_key).to_string()}
+                          </option>
+                      }
+                  })
+                  .collect_view()}
+            </select>
+          </Show>
+          <Show when=move || !calculator().solve_modes.is_empty()>
+            <select
+              aria-label=move || i18n.get().t("solve_mode").to_string()
+              prop:value=move || solve_mode().map_or("", |mode| mode.id)
+              on:change=move |ev| {
+                  let id = event_target_value(&ev);
+                  mode.set(calculator().solve_modes.iter().position(|mode| mode.id == id));
+              }
+            >
+              <option value="">{move || i18n.get().t("solve_from_dimensions").to_string()}</option>
+              {move || {
+                  calculator()
+                      .solve_modes
+                      .iter()
+                      .map(|solve_mode| {
+                          view! {
+                              <option value=solve_mode.id>
+                                  {move || i18n.get().t(solve_mode.label_key).to_string()}
+                              </option>
+                          }
+                      })
+                      .collect_view()
+              }}
+            </select>
+          </Show>
+          {move || {
+              let fields = fields.get_value();
+              let checks = checks.get_value();
+              active_inputs()
+                  .iter()
+                  .zip(&checks[kind.get()][mode.get().map_or(0, |index| index + 1)])
+                  .map(|(input, check)| {
+                      let state = fields.get(input.symbol);
+                      let id = format!("{}-{}", group.card.id, input.symbol);
+                      let label_key = calculator().label_key(mode.get(), input.symbol);
+                      view! {
+                          {state
+                              .unit
+                              .input_view(state.value, id.clone(), input.symbol, label_key, *check)}
+                          <FieldError
+                            error=error
+                            parameters=std::slice::from_ref(&input.parameter)
+                            input_id=id
+                          />
+                      }
+                  })
+                  .collect_view()
+          }}
+          {move || {
+              calculator()
+                  .parts
+                  .map(|parts| {
+                      view! {
+                          <For
+                            each=move || rows.get()
+                            key=|row| row.id
+                            children=move |row| {
+                                view! {
+                                    <PartRowInputs calculator=calculator() row=row parts=parts rows=rows />
+                                }
+                            }
+                          />
+                          <button class="card__inputs__button" on:click=add_row>
+                              {move || i18n.get().t(parts.add_key).to_string()}
+                          </button>
+                      }
+                  })
+          }}
+          <FieldError error=unplaced_error parameters=&[] />
+          {move || {
+              calculator().parts.is_none().then(|| view! {
+                  <p class="card__inputs__hint">{move || i18n.get().t("tolerance_hint").to_string()}</p>
+              })
+          }}
+          <LiveControls
+            calculation=calculation
+            calculate_key=Signal::derive(move || calculator().calculate_key)
+            valid=Signal::derive(inputs_valid)
+          />
+        </div>
+        <div class="card__result">
+            <div class="card__result__svg">
+                <div inner_html=drawing />
+                <DrawingDownload
+                    file_name=Signal::derive(move || calculator().id)
+                    svg=Signal::derive(drawing)
+                />
+            </div>
+            <div class="card__result__formula" inner_html=formula></div>
+          {move || {
+              let fields = fields.get_value();
+              let solution = solution()?;
+              let calculator = calculator();
+              let mode = &calculator.solve_modes[solution.mode?];
+              Some(
+                  mode.unknowns
+                      .iter()
+                      .map(|symbol| {
+                          let unit = fields.get(symbol).unit;
+                          let index = calculator.input_index(symbol).unwrap_or_default();
+                          let value = solution.dimensions[index];
+                          view! {
+                              <p>
+                                {*symbol}" = "
+                                {move || numbers.get().format(value.value_in(unit.get()))}
+                                {unit.select_view()}
+                              </p>
+                              <p class="card__result__range">{move || result_range(symbol, unit)}</p>
+                          }
+                      })
+                      .collect_view(),
+              )
+          }}
+          {move || {
+              let units = outputs.get_value();
+              calculator()
+                  .outputs
+                  .iter()
+                  .enumerate()
+                  .filter_map(|(index, output)| {
+                      let key = output.label_key?;
+                      let unit = units.get(output.symbol);
+                      // Only results of the selected kind have this output
+                      let value = move || {
+                          let output = solution()?.outputs[index];
+                          Some(numbers.get().format(output.value_in(unit.get())))
+                      };
+                      Some(view! {
+                          <p>
+                            {move || i18n.get().t(key).to_string()}
+                            {value}
+                            {unit.select_view()}
+                          </p>
+                          <p class="card__result__range">
+                            {move || result_range(output.symbol, unit)}
+                          </p>
+                      })
+                  })
+                  .collect_view()
+          }}
+          <CardLink card=group.card state=Signal::derive(state) toast=toast />
+          <CopyResult export=export toast=toast />
+        </div>
+        {toast.view()}
+      </div>
+    }
+}
+
+/// Inputs of one part of a card with parts: its kind, dimensions, placement and unit,
+/// and whether it is subtracted.
+#[allow(non_snake_case)]
+#[component]
+fn PartRowInputs(
+    /// The calculator of the card, whose variables explain the inputs of every part.
+    calculator: &'static Calculator,
+    row: PartRow,
+    parts: &'static Parts,
+    rows: RwSignal<Vec<PartRow>>,
+) -> impl IntoView {
+    let i18n = use_context::<Memo<I18n>>().expect("I18n context not found");
+
+    view! {
+        <div class="card__inputs__row">
+            <select
+                prop:value=move || parts.kinds[row.kind.get()].id
+                on:change=move |ev| row.kind.set(parts.kind(&event_target_value(&ev)))
+            >
+                {parts
+                    .kinds
+                    .iter()
+                    .map(|option| {
+                        view! {
+                            <option value=option.id>
+                                {move || i18n.get().t(option.title_key).to_string()}
+                            </option>
+                        }
+                    })
+                    .collect_view()}
+            </select>
+            {move || {
+                let fields = row.fields.get_value();
+                parts
+                    .inputs(row.kind.get())
+                    .zip(row.checks())
+                    .map(|(input, check)| {
+                        let state = fields.get(input.symbol);
+                        let id = format!("{}-part{}-{}", calculator.id, row.id, input.symbol);
+                        view! {
+                            <NumberInput
+                                value=state.value
+                                id=id.clone()
+                                placeholder=input.symbol
+                                label_key=calculator.label_key(None, input.symbol)
+                                check=check
+                            />
+                            <InputMessage value=state.value input_id=id check=check />
+                        }
+                    })
+                    .collect_view()
+            }}
+            <UnitSelect unit=row.unit />
+            <label>
+                <input type="checkbox" bind:checked=row.subtract />
+                {move || i18n.get().t(parts.subtract_key).to_string()}
+            </label>
+            <button
+                class="card__inputs__row__remove"
+                on:click=move |_| rows.update(|rows| rows.retain(|r| r.id != row.id))
+            >
+                "×"
+            </button>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_any_quantity() {
+        let width = AnyUnit::Length(LengthUnit::Millimetre).quantity(250.0);
+        assert_eq!(width, AnyQuantity::Length(Length::from_si(0.25)));
+        assert_eq!(width.value_in(AnyUnit::Length(LengthUnit::Metre)), 0.25);
+        assert_eq!(AnyUnit::Count.quantity(6.0).count(), 6.0);
+        let area = AnyQuantity::from(Area::from_si(6.0));
+        assert_eq!(
+            AnyUnit::Area(AreaUnit::SquareMetre).latex(area, NumberFormat::new("en")),
+            r"6\,\text{m²}"
+        );
+    }
+
+    #[test]
+    fn test_solve_modes() {
+        use crate::cards::area::*;
+
+        let calculators = [
+            &RECTANGLE,
+            &CIRCLE,
+            &TRIANGLE,
+            &TRAPEZOID,
+            &ELLIPSE,
+            &REGULAR_POLYGON,
+            &PARALLELOGRAM,
+        ];
+        for calculator in calculators {
+            for (index, mode) in calculator.solve_modes.iter().enumerate() {
+                let values: Vec<AnyQuantity> = mode
+                    .inputs
+                    .iter()
+                    .map(|input| {
+                        let value = match input.measure {
+                            Measure::Angle => 60.0,
+                            Measure::Count => 6.0,
+                            _ => 2.0,
+                        };
+                        AnyUnit::default_for(input.measure, Scale::Metre).quantity(value)
+                    })
+                    .collect();
+                let solution = calculator.solve(Some(index), values).unwrap();
+                assert_eq!(
+                    solution.dimensions.len(),
+                    calculator.inputs.len(),
+                    "{}",
+                    mode.id
+                );
+                for symbol in mode.unknowns {
+                    assert!(calculator.input_index(symbol).is_some(), "{}", mode.id);
+                }
+            }
+        }
+
+        let area = Area::from_si(std::f64::consts::PI).into();
+        let radius = (CIRCLE.solve_modes[0].solve)(&[area]).unwrap();
+        assert!((radius[0].length().si() - 1.0).abs() < 1e-12);
+        let values = [Area::from_si(6.0).into(), Length::from_si(2.0).into()];
+        let rectangle = (RECTANGLE.solve_modes[0].solve)(&values).unwrap();
+        assert_eq!(rectangle, metres(&[3.0, 2.0]));
+
+        assert!(matches!(
+            RECTANGLE.solve(None, metres(&[1e200, 1e200])),
+            Err(CalculationError::NotFinite { parameter: "A", .. })
+        ));
+    }
+
+    #[test]
+    fn test_label_key() {
+        use crate::cards::area::RECTANGLE;
+
+        assert_eq!(RECTANGLE.label_key(None, "w"), Some("rec_area_breedte"));
+        assert_eq!(RECTANGLE.label_key(Some(0), "A"), Some("rec_area_area"));
+        assert_eq!(COMPOSITE.label_key(None, "y"), Some("composite_position"));
+        assert_eq!(
+            COMPOSITE.label_key(None, "tw"),
+            Some("composite_dimensions")
+        );
+        assert_eq!(COMPOSITE.label_key(None, "z"), None);
+    }
+
+    #[test]
+    fn test_formula_placeholders() {
+        use crate::calculations::formula::fill_formula;
+
+        // Every placeholder of a step is an input or output of its calculator or mode
+        for calculator in CARDS.iter().flat_map(|group| group.kinds) {
+            let modes = std::iter::once((calculator.formula, &[][..])).chain(
+                calculator
+                    .solve_modes
+                    .iter()
+                    .map(|mode| (mode.formula, mode.inputs)),
+            );
+            for (steps, mode_inputs) in modes {
+                let known = |symbol: &str| {
+                    calculator.input_index(symbol).is_some()
+                        || calculator.output_index(symbol).is_some()
+                        || mode_inputs.iter().any(|input| input.symbol == symbol)
+                };
+                for step in steps {
+                    let filled =
+                        fill_formula(step.substitution, |symbol| known(symbol).then(String::new));
+                    assert!(!filled.contains('#'), "{}: {filled}", calculator.id);
+                    assert!(known(step.symbol), "{}: {}", calculator.id, step.symbol);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_shown_values() {
+        use crate::cards::area::RECTANGLE;
+
+        let unit = |symbol: &str| match symbol {
+            "w" => AnyUnit::Length(LengthUnit::Centimetre),
+            _ => AnyUnit::Length(LengthUnit::Metre),
+        };
+        let output_units = [AnyUnit::Area(AreaUnit::SquareMetre)];
+        let solution = RECTANGLE.solve(None, metres(&[2.0, 3.0])).unwrap();
+        assert_eq!(
+            RECTANGLE.entered_values(&solution, unit)[0],
+            HistoryValue::new("w", 200.0, "cm")
+        );
+        assert_eq!(
+            RECTANGLE.result_values(&solution, unit, &output_units),
+            [HistoryValue::new("A", 6.0, "m²")]
+        );
+
+        // Solving the width from the area shows the width with the results
+        let values = vec![Area::from_si(6.0).into(), Length::from_si(3.0).into()];
+        let solution = RECTANGLE.solve(Some(0), values).unwrap();
+        let results = RECTANGLE.result_values(&solution, unit, &output_units);
+        assert_eq!(results[0], HistoryValue::new("w", 200.0, "cm"));
+        assert_eq!(results[1], HistoryValue::new("A", 6.0, "m²"));
+    }
+
+    #[test]
+    fn test_saved_calculation() {
+        let state = |pairs: &[(&str, &str)]| -> Vec<(String, String)> {
+            pairs
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect()
+        };
+        let rectangle = find_card("rectangle").unwrap();
+        let saved = state(&[("w", "200"), ("w_unit", "cm"), ("h", "3")]);
+        let calculation = SavedCalculation::read(rectangle, &saved);
+        assert_eq!(calculation.inputs()[0], HistoryValue::new("w", 200.0, "cm"));
+        assert_eq!(calculation.results(), [HistoryValue::new("A", 6.0, "m²")]);
+        assert_eq!(
+            calculation.formula(NumberFormat::new("en")).unwrap(),
+            r"A = w \times h = 200\,\text{cm} \times 3\,\text{m} = 6\,\text{m²}"
+        );
+        let en = NumberFormat::new("en");
+        assert!(calculation.drawing(en).unwrap().contains(">200 cm</text>"));
+
+        // Solving the width from the area shows the width with the results
+        let saved = state(&[
+            ("mode", "width"),
+            ("A", "6"),
+            ("h", "3"),
+            ("A_result_unit", "cm²"),
+        ]);
+        let results = SavedCalculation::read(rectangle, &saved).results();
+        assert_eq!(results[0], HistoryValue::new("w", 2.0, "m"));
+        assert_eq!(results[1].unit, "cm²");
+
+        // Kinds of a card with several are read from the state
+        let section = find_card("section").unwrap();
+        let saved = state(&[("kind", "circle"), ("d", "100")]);
+        let calculation = SavedCalculation::read(section, &saved);
+        assert_eq!(calculation.calculator.id, "circle");
+        assert_eq!(calculation.inputs(), [HistoryValue::new("d", 100.0, "mm")]);
+
+        let missing = SavedCalculation::read(rectangle, &state(&[("w", "2")]));
+        assert!(missing.outcome.is_none());
+        let invalid = SavedCalculation::read(rectangle, &state(&[("w", "-2"), ("h", "3")]));
+        assert!(matches!(invalid.outcome, Some(Err(_))));
+        assert!(find_card("unknown").is_none());
+    }
+
+    #[test]
+    fn test_solve_toleranced() {
+        use crate::cards::area::RECTANGLE;
+
+        let solution = RECTANGLE
+            .solve_toleranced(None, metres(&[0.1, 0.05]), &[0.0002, 0.0])
+            .unwrap();
+        let width = solution.range("w").unwrap();
+        assert!((width.min - 0.0998).abs() < 1e-12);
+        assert!(solution.range("h").unwrap().is_exact());
+        let area = solution.range("A").unwrap();
+        assert!((area.nominal - 0.005).abs() < 1e-12);
+        assert!((area.min - 0.00499).abs() < 1e-12);
+        assert!((area.max - 0.00501).abs() < 1e-12);
+        assert!((area.uncertainty - 0.00001).abs() < 1e-12);
+
+        let exact = RECTANGLE
+            .solve_toleranced(None, metres(&[0.1, 0.05]), &[0.0, 0.0])
+            .unwrap();
+        assert!(exact.ranges.is_empty());
+
+        let saved: Vec<(String, String)> = [
+            ("w", "100"),
+            ("w_tol", "0.2"),
+            ("w_unit", "mm"),
+            ("h", "50"),
+            ("h_unit", "mm"),
+            ("A_result_unit", "mm²"),
+        ]
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect();
+        let calculation = SavedCalculation::read(find_card("rectangle").unwrap(), &saved);
+        let formula = calculation.formula(NumberFormat::new("en")).unwrap();
+        assert!(formula.contains(r"\left(100 \pm 0.2\right)\,\text{mm} \times 50\,\text{mm}"));
+        assert!(formula.contains(r"{A}_{min} = 4{,}990\,\text{mm²}"));
+    }
+
+    #[test]
+    fn test_part_links() {
+        use crate::cards::section::SECTION_KINDS;
+
+        const X: CalculatorInput = CalculatorInput {
+            parameter: "x",
+            symbol: "x",
+            measure: Measure::Length,
+            rule: Rule::Finite,
+        };
+        let parts = Parts {
+            kinds: &SECTION_KINDS,
+            placement: &[X],
+            subtract_key: "composite_subtract",
+            add_key: "composite_add_part",
+        };
+        let part = LinkPart {
+            kind: 1,
+            unit: Some("mm".to_string()),
+            subtract: true,
+            values: vec![Some(1.5), Some(-20.0)],
+        };
+        let link = parts.write_link(&part);
+        assert_eq!(link, format!("{};mm;-20;hole;1.5", SECTION_KINDS[1].id));
+        assert_eq!(parts.read_link(&link), part);
+
+        let missing = parts.read_link("unknown");
+        assert_eq!(missing.kind, 0);
+        assert!(!missing.subtract);
+        assert!(missing.values.iter().all(Option::is_none));
+    }
+}
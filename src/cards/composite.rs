@@ -0,0 +1,124 @@
+use crate::calculations::composite::*;
+use crate::calculations::errors::*;
+use crate::calculations::shapes_2d::*;
+use crate::calculations::units::*;
+use crate::cards::calculator::{
+    AnyQuantity, Calculator, CalculatorCard, CalculatorInput, Measure, Part, Parts, Scale,
+};
+use crate::cards::drawing::{Dimension, Drawing, Labels, EMPTY_SVG};
+use crate::cards::section::{
+    build_section, neutral_axes, section_shape, section_values, SectionDimensions, SectionKind,
+    SECTION_FORMULA, SECTION_KINDS, SECTION_PROPERTIES,
+};
+use crate::cards::validation::Rule;
+use leptos::prelude::*;
+
+/// A coordinate of the bottom-left corner of a part.
+const fn position(symbol: &'static str) -> CalculatorInput {
+    CalculatorInput {
+        parameter: symbol,
+        symbol,
+        measure: Measure::Length,
+        rule: Rule::Finite,
+    }
+}
+
+/// The primitive sections a composite section is built from, each placed by the
+/// bottom-left corner of its bounding box.
+static PARTS: Parts = Parts {
+    kinds: &SECTION_KINDS,
+    placement: &[position("x"), position("y")],
+    subtract_key: "composite_subtract",
+    add_key: "composite_add_part",
+};
+
+/// The section of a part with its dimensions and placement in `unit`.
+fn section_part(part: &Part, unit: LengthUnit) -> Result<SectionPart, CalculationError> {
+    let kind = SectionKind::ALL[part.kind];
+    let section = build_section(
+        kind,
+        SectionDimensions::from_inputs(kind, part.values, unit),
+    )?;
+    // In `unit` like the dimensions, so the properties come out in that unit
+    let [x, y] = [0, 1].map(|index| part.placement[index].length().value_in(unit));
+    let [x, y] = [x, y].map(Length::from_si);
+    Ok(if part.subtract {
+        SectionPart::subtracted(section, x, y)
+    } else {
+        SectionPart::added(section, x, y)
+    })
+}
+
+/// Builds the composite section with all part dimensions expressed in `unit`, from the
+/// values of its parts as [`Parts`] lays them out.
+pub fn build_composite(
+    values: &[AnyQuantity],
+    unit: LengthUnit,
+) -> Result<CompositeSection, CalculationError> {
+    let parts = PARTS
+        .split(values)
+        .iter()
+        .map(|part| section_part(part, unit))
+        .collect::<Result<Vec<_>, _>>()?;
+    CompositeSection::new(parts)
+}
+
+/// Draws every part of the composite section, holes included, with the neutral axes
+/// and its overall width and height dimensioned.
+pub fn create_composite_svg(values: &[AnyQuantity], labels: &Labels) -> String {
+    let unit = labels.unit();
+    let Ok(composite) = build_composite(values, unit) else {
+        return EMPTY_SVG.to_string();
+    };
+    let (x0, y0) = composite.origin();
+    let (width, height) = composite.bounding_box();
+
+    // Added parts first, so the holes are drawn on top of them
+    let mut ordered = PARTS.split(values);
+    ordered.sort_by_key(|part| part.subtract);
+    let drawing = ordered.into_iter().fold(Drawing::new(), |drawing, part| {
+        let kind = SectionKind::ALL[part.kind];
+        let [x, y] = [0, 1].map(|index| part.placement[index].length().value_in(unit));
+        let dims = SectionDimensions::from_inputs(kind, part.values, unit);
+        let shape = section_shape(kind, dims).translate(x - x0, y - y0);
+        drawing.with_shape(if part.subtract { "hole" } else { "part" }, shape)
+    });
+    neutral_axes(drawing, (width, height), composite.centroid())
+        .with_dimension(Dimension::Horizontal {
+            from: (0.0, 0.0),
+            to: (width, 0.0),
+            label: labels.length(width),
+        })
+        .with_dimension(Dimension::Vertical {
+            from: (0.0, height),
+            to: (0.0, 0.0),
+            label: labels.length(height),
+        })
+        .to_svg()
+}
+
+pub static COMPOSITE: Calculator = Calculator {
+    id: "composite",
+    path: "/cards/section/composite",
+    title_key: "composite_calculator",
+    calculate_key: "section_calculate",
+    variables: &[
+        ("x, y", "composite_position"),
+        ("b, h, d, t, tw, tf", "composite_dimensions"),
+    ],
+    inputs: &[],
+    constraints: &[],
+    outputs: SECTION_PROPERTIES,
+    formula: SECTION_FORMULA,
+    compute: |v| Ok(section_values(&build_composite(v, LengthUnit::Metre)?)),
+    drawing: Some(create_composite_svg),
+    solve_modes: &[],
+    scale: Scale::Millimetre,
+    parts: Some(&PARTS),
+};
+
+#[allow(non_snake_case)]
+#[component]
+pub fn CompositeSectionCard() -> impl IntoView {
+    view! { <CalculatorCard calculator=&COMPOSITE /> }
+}
@@ -0,0 +1,205 @@
+use crate::calculations::units::*;
+use crate::cards::export::CopyToast;
+use crate::cards::share::{
+    link_number, restore_shared, restore_unit, shared_number, CardInfo, CardLink,
+};
+use crate::cards::units::QuantityInput;
+use crate::cards::validation::{check_quantity, Rule};
+use crate::locales::i18n::I18n;
+use leptos::prelude::*;
+use leptos_router::params::ParamsMap;
+
+/// Significant figures converted values keep at least, more than results so
+/// conversions round-trip.
+const CONVERSION_SIGNIFICANT_FIGURES: usize = 7;
+
+/// Converts a quantity to every unit of its dimension, starting from `initial`
+/// entered in `default_unit` unless a link holds another value.
+#[allow(non_snake_case)]
+#[component]
+pub fn ConversionCard<D: Dimension>(
+    card: CardInfo,
+    initial: Quantity<D>,
+    default_unit: D::Unit,
+) -> impl IntoView {
+    let i18n = use_context::<Memo<I18n>>().expect("I18n context not found");
+    let numbers = Memo::new(move |_| {
+        let numbers = i18n.get().number_format();
+        numbers.with_rounding(numbers.rounding.at_least(CONVERSION_SIGNIFICANT_FIGURES))
+    });
+
+    let value = RwSignal::new(
+        numbers
+            .get_untracked()
+            .format_plain(initial.value_in(default_unit)),
+    );
+    let unit = RwSignal::new(default_unit);
+    restore_shared(card.path, move |query| {
+        if let Some(number) = shared_number(query, "value") {
+            value.set(numbers.get_untracked().format_plain(number));
+        }
+        restore_unit(query, "unit", unit);
+    });
+    let check = check_quantity("value", value, unit, Rule::Finite);
+    // No conversions while the input is not a valid value, rather than those of 0.
+    let quantity = move || {
+        if check.get().is_some() {
+            return None;
+        }
+        let value = numbers.get().parse(&value.get())?;
+        Some(Quantity::<D>::new(value, unit.get()))
+    };
+    let state = move || {
+        let mut query = ParamsMap::new();
+        if let Some(number) = link_number(&value.get(), numbers.get()) {
+            query.insert("value", number);
+        }
+        query.insert("unit", unit.get().symbol().to_string());
+        query
+    };
+    let toast = CopyToast::default();
+
+    view! {
+      <div class="card">
+        <a class="card__title">{move || i18n.get().t(card.title_key).to_string()}</a>
+        <div class="card__inputs">
+          <QuantityInput
+            value=value
+            unit=unit
+            id=format!("conversion-{}", card.id)
+            label_key=card.title_key
+            check=check
+          />
+        </div>
+        <div class="card__result">
+            <table class="card__result__table">
+                {D::Unit::ALL
+                    .iter()
+                    .map(|target| {
+                        view! {
+                            <tr>
+                                <td>{target.symbol()}</td>
+                                <td>
+                                    {move || {
+                                        quantity()
+                                            .map(|quantity| numbers.get().format(quantity.value_in(*target)))
+                                            .unwrap_or_else(|| "–".to_string())
+                                    }}
+                                </td>
+                            </tr>
+                        }
+                    })
+                    .collect_view()}
+            </table>
+          <CardLink card=card state=Signal::derive(state) toast=toast />
+        </div>
+        {toast.view()}
+      </div>
+    }
+}
+
+/// Declares a conversion card component for one quantity, starting at 1 in its default unit.
+macro_rules! conversion_card {
+    ($name:ident, $quantity:ident, $id:literal, $title_key:literal, $default_unit:expr) => {
+        #[allow(non_snake_case)]
+        #[component]
+        pub fn $name() -> impl IntoView {
+            view! {
+                <ConversionCard
+                    card=CardInfo {
+                        id: $id,
+                        title_key: $title_key,
+                        path: concat!("/cards/conversion/", $id),
+                    }
+                    initial=$quantity::new(1.0, $default_unit)
+                    default_unit=$default_unit
+                />
+            }
+        }
+    };
+}
+
+conversion_card!(
+    LengthConversionCard,
+    Length,
+    "length",
+    "conversion_length",
+    LengthUnit::Metre
+);
+conversion_card!(
+    AreaConversionCard,
+    Area,
+    "area",
+    "conversion_area",
+    AreaUnit::SquareMetre
+);
+conversion_card!(
+    VolumeConversionCard,
+    Volume,
+    "volume",
+    "conversion_volume",
+    VolumeUnit::CubicMetre
+);
+conversion_card!(
+    MassConversionCard,
+    Mass,
+    "mass",
+    "conversion_mass",
+    MassUnit::Kilogram
+);
+conversion_card!(
+    ForceConversionCard,
+    Force,
+    "force",
+    "conversion_force",
+    ForceUnit::Newton
+);
+conversion_card!(
+    PressureConversionCard,
+    Pressure,
+    "pressure",
+    "conversion_pressure",
+    PressureUnit::Megapascal
+);
+conversion_card!(
+    EnergyConversionCard,
+    Energy,
+    "energy",
+    "conversion_energy",
+    EnergyUnit::Joule
+);
+conversion_card!(
+    PowerConversionCard,
+    Power,
+    "power",
+    "conversion_power",
+    PowerUnit::Kilowatt
+);
+conversion_card!(
+    TorqueConversionCard,
+    Torque,
+    "torque",
+    "conversion_torque",
+    TorqueUnit::NewtonMetre
+);
+conversion_card!(
+    TemperatureConversionCard,
+    Temperature,
+    "temperature",
+    "conversion_temperature",
+    TemperatureUnit::Celsius
+);
+conversion_card!(
+    FlowRateConversionCard,
+    FlowRate,
+    "flow-rate",
+    "conversion_flow_rate",
+    FlowRateUnit::CubicMetrePerHour
+);
+conversion_card!(
+    AngleConversionCard,
+    Angle,
+    "angle",
+    "conversion_angle",
+    AngleUnit::Degree
+);
@@ -0,0 +1,674 @@
+//! Scaled, dimensioned SVG drawings of the shapes of the cards.
+//!
+//! Shapes are given in model coordinates with the y-axis up, in the unit their
+//! dimensions are labelled in. A drawing scales them so their longest side spans
+//! [`CANVAS`] pixels, keeping the aspect ratio, and fits its view box around the
+//! shapes, dimension lines and labels. Strokes, arrowheads and text keep their size
+//! whatever the size of the shape.
+
+use crate::calculations::units::{LengthUnit, Unit};
+use crate::dom::files::{download_svg_as_png, download_text};
+use crate::locales::i18n::I18n;
+use crate::locales::numbers::NumberFormat;
+use leptos::prelude::*;
+use std::f64::consts::FRAC_PI_4;
+
+/// A point in model coordinates, or in pixels once drawn.
+pub type Point = (f64, f64);
+
+/// Drawing without anything in it, for inputs that describe no shape.
+pub const EMPTY_SVG: &str = r#"<svg xmlns="http://www.w3.org/2000/svg"></svg>"#;
+
+/// Styles of the drawings on the cards, embedded in downloaded drawings.
+const DRAWING_STYLE: &str = include_str!("../../style/components/_drawing.scss");
+
+/// Pixels per pixel of the drawing in a downloaded PNG, so it stays sharp in print.
+const PNG_SCALE: f64 = 3.0;
+
+/// Angle at which a radius or diameter is dimensioned, clear of the horizontal and
+/// vertical dimensions around it.
+pub const RADIUS_ANGLE: f64 = FRAC_PI_4;
+
+/// Pixels spanned by the longest side of the shapes.
+const CANVAS: f64 = 240.0;
+/// Pixels around everything drawn.
+const MARGIN: f64 = 6.0;
+const FONT_SIZE: f64 = 12.0;
+/// Approximate width of a character relative to the font size, for fitting labels.
+const CHAR_WIDTH: f64 = 0.6;
+const ARROW_LENGTH: f64 = 8.0;
+const ARROW_HALF_WIDTH: f64 = 2.5;
+/// Distance from the furthest measured point to a dimension line.
+const DIMENSION_OFFSET: f64 = 22.0;
+/// Gap between the shape and an extension line, and its overshoot past the dimension line.
+const EXTENSION_GAP: f64 = 3.0;
+const EXTENSION_OVERSHOOT: f64 = 4.0;
+/// Gap between a dimension line and its label.
+const LABEL_GAP: f64 = 3.0;
+/// Length of the slanted and horizontal parts of the leader of a radius or diameter.
+const LEADER: f64 = 14.0;
+const SHOULDER: f64 = 8.0;
+
+/// Something drawn in the colour of its class.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Shape {
+    Polygon(Vec<Point>),
+    Circle {
+        center: Point,
+        radius: f64,
+    },
+    Ellipse {
+        center: Point,
+        rx: f64,
+        ry: f64,
+    },
+    /// Outlines filled as one even-odd path, so an outline inside another is a hole.
+    Hollow(Vec<Shape>),
+    Line(Point, Point),
+}
+
+impl Shape {
+    /// A rectangle with its bottom-left corner at `(x, y)`, counterclockwise.
+    pub fn rectangle(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Shape::Polygon(vec![
+            (x, y),
+            (x + width, y),
+            (x + width, y + height),
+            (x, y + height),
+        ])
+    }
+
+    pub fn translate(self, dx: f64, dy: f64) -> Self {
+        let moved = |(x, y): Point| (x + dx, y + dy);
+        match self {
+            Shape::Polygon(points) => Shape::Polygon(points.into_iter().map(moved).collect()),
+            Shape::Circle { center, radius } => Shape::Circle {
+                center: moved(center),
+                radius,
+            },
+            Shape::Ellipse { center, rx, ry } => Shape::Ellipse {
+                center: moved(center),
+                rx,
+                ry,
+            },
+            Shape::Hollow(outlines) => Shape::Hollow(
+                outlines
+                    .into_iter()
+                    .map(|outline| outline.translate(dx, dy))
+                    .collect(),
+            ),
+            Shape::Line(from, to) => Shape::Line(moved(from), moved(to)),
+        }
+    }
+
+    /// Corners of the bounding box of the shape.
+    fn extremes(&self) -> Vec<Point> {
+        let corners = |(x, y): Point, rx: f64, ry: f64| vec![(x - rx, y - ry), (x + rx, y + ry)];
+        match self {
+            Shape::Polygon(points) => points.clone(),
+            Shape::Circle { center, radius } => corners(*center, *radius, *radius),
+            Shape::Ellipse { center, rx, ry } => corners(*center, *rx, *ry),
+            Shape::Hollow(outlines) => outlines.iter().flat_map(Shape::extremes).collect(),
+            Shape::Line(from, to) => vec![*from, *to],
+        }
+    }
+}
+
+/// A measurement drawn next to the shapes, with its label.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Dimension {
+    /// Distance between two points along the line through them. The dimension line
+    /// lies to the right of `from` → `to` as drawn, which is outside a shape whose
+    /// points run counterclockwise.
+    Aligned {
+        from: Point,
+        to: Point,
+        label: String,
+    },
+    /// Horizontal distance between two points, placed like [`Dimension::Aligned`].
+    Horizontal {
+        from: Point,
+        to: Point,
+        label: String,
+    },
+    /// Vertical distance between two points, placed like [`Dimension::Aligned`].
+    Vertical {
+        from: Point,
+        to: Point,
+        label: String,
+    },
+    /// A radius from the centre to the point at `angle` radians, counterclockwise from
+    /// the x-axis, with the label on a leader outside.
+    Radius {
+        center: Point,
+        radius: f64,
+        angle: f64,
+        label: String,
+    },
+    /// A diameter through the centre at `angle` radians, with the label on a leader.
+    Diameter {
+        center: Point,
+        radius: f64,
+        angle: f64,
+        label: String,
+    },
+}
+
+/// Writes the labels of dimensions, in the unit of the drawn lengths.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Labels {
+    numbers: NumberFormat,
+    unit: LengthUnit,
+}
+
+impl Labels {
+    pub fn new(numbers: NumberFormat, unit: LengthUnit) -> Self {
+        Labels { numbers, unit }
+    }
+
+    /// The unit the lengths are drawn and labelled in.
+    pub fn unit(&self) -> LengthUnit {
+        self.unit
+    }
+
+    pub fn length(&self, value: f64) -> String {
+        format!("{} {}", self.numbers.format(value), self.unit.symbol())
+    }
+
+    pub fn radius(&self, value: f64) -> String {
+        format!("R {}", self.length(value))
+    }
+
+    pub fn diameter(&self, value: f64) -> String {
+        format!("Ø {}", self.length(value))
+    }
+}
+
+/// Shapes with their dimensions, drawn to fit.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Drawing {
+    shapes: Vec<(&'static str, Shape)>,
+    dimensions: Vec<Dimension>,
+}
+
+impl Drawing {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a shape with a class that styles it, such as `shape`, `hole` or
+    /// `neutral-axis`.
+    pub fn with_shape(mut self, class: &'static str, shape: Shape) -> Self {
+        self.shapes.push((class, shape));
+        self
+    }
+
+    pub fn with_dimension(mut self, dimension: Dimension) -> Self {
+        self.dimensions.push(dimension);
+        self
+    }
+
+    /// The drawing as an SVG element, or [`EMPTY_SVG`] when the shapes have no size.
+    pub fn to_svg(&self) -> String {
+        let mut model = Bounds::default();
+        for (_, shape) in &self.shapes {
+            shape.extremes().into_iter().for_each(|p| model.include(p));
+        }
+        let size = model.width().max(model.height());
+        if !(size.is_finite() && size > 0.0) {
+            return EMPTY_SVG.to_string();
+        }
+        let mut canvas = Canvas {
+            origin: (model.min.0, model.max.1),
+            scale: CANVAS / size,
+            view: Bounds::default(),
+            body: String::new(),
+        };
+        for (class, shape) in &self.shapes {
+            canvas.shape(class, shape);
+        }
+        if !self.dimensions.is_empty() {
+            canvas.body.push_str(
+                r#"<g class="dimension" stroke="black" stroke-width="0.75" fill="black">"#,
+            );
+            for dimension in &self.dimensions {
+                canvas.dimension(dimension);
+            }
+            canvas.body.push_str("</g>");
+        }
+
+        let Bounds { min, max } = canvas.view;
+        let (x, y) = (min.0 - MARGIN, min.1 - MARGIN);
+        let (width, height) = (max.0 - min.0 + 2.0 * MARGIN, max.1 - min.1 + 2.0 * MARGIN);
+        format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{x:.2} {y:.2} {width:.2} {height:.2}" width="{width:.0}" height="{height:.0}">{}</svg>"#,
+            canvas.body
+        )
+    }
+}
+
+/// A drawing as a file of its own, with the styles of the cards embedded; `None` when
+/// nothing is drawn.
+pub fn standalone_svg(svg: &str) -> Option<String> {
+    if svg == EMPTY_SVG {
+        return None;
+    }
+    let (start, rest) = svg.split_at(svg.find('>')? + 1);
+    Some(format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+{start}<style>{DRAWING_STYLE}</style>{rest}"#
+    ))
+}
+
+/// Buttons downloading the drawing of a card as an SVG or PNG file named after the card.
+#[allow(non_snake_case)]
+#[component]
+pub fn DrawingDownload(
+    #[prop(into)] file_name: Signal<&'static str>,
+    #[prop(into)] svg: Signal<Option<String>>,
+) -> impl IntoView {
+    let i18n = use_context::<Memo<I18n>>().expect("I18n context not found");
+    let standalone = move || svg.get().as_deref().and_then(standalone_svg);
+
+    let download_svg = move |_| {
+        if let Some(svg) = standalone() {
+            let file_name = format!("{}.svg", file_name.get());
+            download_text(&file_name, "image/svg+xml", &svg);
+        }
+    };
+    let download_png = move |_| {
+        if let Some(svg) = standalone() {
+            download_svg_as_png(&format!("{}.png", file_name.get()), &svg, PNG_SCALE);
+        }
+    };
+
+    view! {
+        <div class="card__result__downloads">
+            <button on:click=download_svg disabled=move || standalone().is_none()>
+                {move || i18n.get().t("drawing_download_svg").to_string()}
+            </button>
+            <button on:click=download_png disabled=move || standalone().is_none()>
+                {move || i18n.get().t("drawing_download_png").to_string()}
+            </button>
+        </div>
+    }
+}
+
+/// Smallest box holding the points included so far.
+#[derive(Debug, Clone, Copy)]
+struct Bounds {
+    min: Point,
+    max: Point,
+}
+
+impl Default for Bounds {
+    fn default() -> Self {
+        Bounds {
+            min: (f64::INFINITY, f64::INFINITY),
+            max: (f64::NEG_INFINITY, f64::NEG_INFINITY),
+        }
+    }
+}
+
+impl Bounds {
+    fn include(&mut self, (x, y): Point) {
+        self.min = (self.min.0.min(x), self.min.1.min(y));
+        self.max = (self.max.0.max(x), self.max.1.max(y));
+    }
+
+    fn width(&self) -> f64 {
+        self.max.0 - self.min.0
+    }
+
+    fn height(&self) -> f64 {
+        self.max.1 - self.min.1
+    }
+}
+
+/// The SVG being written, in pixels with the y-axis down.
+struct Canvas {
+    /// Model point drawn at the top-left corner of the shapes.
+    origin: Point,
+    /// Pixels per model unit.
+    scale: f64,
+    /// Box around everything written so far.
+    view: Bounds,
+    body: String,
+}
+
+impl Canvas {
+    fn to_pixels(&self, (x, y): Point) -> Point {
+        (
+            (x - self.origin.0) * self.scale,
+            (self.origin.1 - y) * self.scale,
+        )
+    }
+
+    fn shape(&mut self, class: &str, shape: &Shape) {
+        for point in shape.extremes() {
+            let point = self.to_pixels(point);
+            self.view.include(point);
+        }
+        let element = match shape {
+            Shape::Polygon(points) => {
+                let points: Vec<Point> = points.iter().map(|p| self.to_pixels(*p)).collect();
+                format!(r#"<polygon points="{}""#, svg_points(&points))
+            }
+            Shape::Circle { center, radius } => {
+                let (cx, cy) = self.to_pixels(*center);
+                let r = radius * self.scale;
+                format!(r#"<circle cx="{cx:.2}" cy="{cy:.2}" r="{r:.2}""#)
+            }
+            Shape::Ellipse { center, rx, ry } => {
+                let (cx, cy) = self.to_pixels(*center);
+                let (rx, ry) = (rx * self.scale, ry * self.scale);
+                format!(r#"<ellipse cx="{cx:.2}" cy="{cy:.2}" rx="{rx:.2}" ry="{ry:.2}""#)
+            }
+            Shape::Hollow(outlines) => {
+                let path: Vec<String> = outlines.iter().map(|o| self.subpath(o)).collect();
+                format!(r#"<path fill-rule="evenodd" d="{}""#, path.join(" "))
+            }
+            Shape::Line(from, to) => {
+                let ((x1, y1), (x2, y2)) = (self.to_pixels(*from), self.to_pixels(*to));
+                format!(r#"<line x1="{x1:.2}" y1="{y1:.2}" x2="{x2:.2}" y2="{y2:.2}""#)
+            }
+        };
+        self.body.push_str(&element);
+        self.body.push_str(&format!(
+            r#" class="{class}" fill="none" stroke="black" stroke-width="1.5" />"#
+        ));
+    }
+
+    /// Path data of one outline of a hollow shape.
+    fn subpath(&self, outline: &Shape) -> String {
+        let ellipse = |center: &Point, rx: f64, ry: f64| {
+            let (cx, cy) = self.to_pixels(*center);
+            let (rx, ry) = (rx * self.scale, ry * self.scale);
+            format!(
+                "M {:.2} {cy:.2} a {rx:.2} {ry:.2} 0 1 0 {:.2} 0 a {rx:.2} {ry:.2} 0 1 0 {:.2} 0 Z",
+                cx - rx,
+                2.0 * rx,
+                -2.0 * rx
+            )
+        };
+        match outline {
+            Shape::Polygon(points) => {
+                let points: Vec<String> = points
+                    .iter()
+                    .map(|p| {
+                        let (x, y) = self.to_pixels(*p);
+                        format!("{x:.2} {y:.2}")
+                    })
+                    .collect();
+                format!("M {} Z", points.join(" L "))
+            }
+            Shape::Circle { center, radius } => ellipse(center, *radius, *radius),
+            Shape::Ellipse { center, rx, ry } => ellipse(center, *rx, *ry),
+            Shape::Hollow(outlines) => outlines
+                .iter()
+                .map(|o| self.subpath(o))
+                .collect::<Vec<_>>()
+                .join(" "),
+            Shape::Line(..) => String::new(),
+        }
+    }
+
+    fn dimension(&mut self, dimension: &Dimension) {
+        match dimension {
+            Dimension::Aligned { from, to, label } => self.linear(*from, *to, None, label),
+            Dimension::Horizontal { from, to, label } => {
+                self.linear(*from, *to, Some((1.0, 0.0)), label)
+            }
+            Dimension::Vertical { from, to, label } => {
+                self.linear(*from, *to, Some((0.0, 1.0)), label)
+            }
+            Dimension::Radius {
+                center,
+                radius,
+                angle,
+                label,
+            } => self.leader(*center, *radius, *angle, false, label),
+            Dimension::Diameter {
+                center,
+                radius,
+                angle,
+                label,
+            } => self.leader(*center, *radius, *angle, true, label),
+        }
+    }
+
+    /// A dimension line with extension lines, measured along `axis` in pixels or
+    /// along the line through the points.
+    fn linear(&mut self, from: Point, to: Point, axis: Option<Point>, label: &str) {
+        let (a, b) = (self.to_pixels(from), self.to_pixels(to));
+        let direction = match axis {
+            None => unit(sub(b, a)),
+            Some(axis) => unit(scaled(axis, dot(sub(b, a), axis))),
+        };
+        let Some(direction) = direction else {
+            return;
+        };
+        // Perpendicular, to the right of the direction as drawn
+        let normal = (-direction.1, direction.0);
+        let level = dot(a, normal).max(dot(b, normal)) + DIMENSION_OFFSET;
+        let on_line = |p: Point| add(p, scaled(normal, level - dot(p, normal)));
+        let (start, end) = (on_line(a), on_line(b));
+
+        for (point, foot) in [(a, start), (b, end)] {
+            let gap = add(point, scaled(normal, EXTENSION_GAP));
+            self.line(gap, add(foot, scaled(normal, EXTENSION_OVERSHOOT)));
+        }
+        // Arrows go outside when they don't fit between the extension lines
+        let length = dot(sub(end, start), direction);
+        if length > 2.5 * ARROW_LENGTH {
+            self.line(start, end);
+            self.arrow(start, scaled(direction, -1.0));
+            self.arrow(end, direction);
+        } else {
+            let outside = scaled(direction, 2.0 * ARROW_LENGTH);
+            self.line(sub(start, outside), add(end, outside));
+            self.arrow(start, direction);
+            self.arrow(end, scaled(direction, -1.0));
+        }
+
+        let middle = scaled(add(start, end), 0.5);
+        let position = add(middle, scaled(normal, LABEL_GAP + FONT_SIZE / 2.0));
+        let mut angle = direction.1.atan2(direction.0).to_degrees();
+        // Keep the text readable, vertical text reading upwards
+        if angle >= 90.0 - 1e-9 {
+            angle -= 180.0;
+        } else if angle < -90.0 - 1e-9 {
+            angle += 180.0;
+        }
+        self.text(position, angle, "middle", label);
+    }
+
+    /// A radius or diameter with a leader out to its label.
+    fn leader(&mut self, center: Point, radius: f64, angle: f64, diameter: bool, label: &str) {
+        let center = self.to_pixels(center);
+        let direction = (angle.cos(), -angle.sin());
+        let tip = add(center, scaled(direction, radius * self.scale));
+        let start = if diameter {
+            let opposite = sub(center, scaled(direction, radius * self.scale));
+            self.arrow(opposite, scaled(direction, -1.0));
+            opposite
+        } else {
+            center
+        };
+        let elbow = add(tip, scaled(direction, LEADER));
+        let side = if direction.0 < -1e-9 { -1.0 } else { 1.0 };
+        let shoulder = add(elbow, (side * SHOULDER, 0.0));
+        self.line(start, elbow);
+        self.line(elbow, shoulder);
+        self.arrow(tip, direction);
+        let anchor = if side < 0.0 { "end" } else { "start" };
+        self.text(add(shoulder, (side * LABEL_GAP, 0.0)), 0.0, anchor, label);
+    }
+
+    fn line(&mut self, from: Point, to: Point) {
+        self.view.include(from);
+        self.view.include(to);
+        let ((x1, y1), (x2, y2)) = (from, to);
+        self.body.push_str(&format!(
+            r#"<line x1="{x1:.2}" y1="{y1:.2}" x2="{x2:.2}" y2="{y2:.2}" />"#
+        ));
+    }
+
+    /// An arrowhead with its tip at `tip`, pointing in `direction`.
+    fn arrow(&mut self, tip: Point, direction: Point) {
+        let base = sub(tip, scaled(direction, ARROW_LENGTH));
+        let side = scaled((-direction.1, direction.0), ARROW_HALF_WIDTH);
+        let points = [tip, add(base, side), sub(base, side)];
+        points.iter().for_each(|p| self.view.include(*p));
+        self.body.push_str(&format!(
+            r#"<polygon class="arrow" points="{}" stroke="none" />"#,
+            svg_points(&points)
+        ));
+    }
+
+    /// A label rotated by `angle` degrees around its anchor.
+    fn text(&mut self, (x, y): Point, angle: f64, anchor: &str, label: &str) {
+        let width = label.chars().count() as f64 * FONT_SIZE * CHAR_WIDTH;
+        let (before, after) = match anchor {
+            "start" => (0.0, width),
+            "end" => (width, 0.0),
+            _ => (width / 2.0, width / 2.0),
+        };
+        let along = (angle.to_radians().cos(), angle.to_radians().sin());
+        let across = scaled((-along.1, along.0), FONT_SIZE / 2.0);
+        for offset in [-before, after] {
+            let end = add((x, y), scaled(along, offset));
+            self.view.include(add(end, across));
+            self.view.include(sub(end, across));
+        }
+        let rotation = if angle == 0.0 {
+            String::new()
+        } else {
+            format!(r#" transform="rotate({angle:.1} {x:.2} {y:.2})""#)
+        };
+        self.body.push_str(&format!(
+            r#"<text x="{x:.2}" y="{y:.2}"{rotation} text-anchor="{anchor}" dominant-baseline="middle" font-size="{FONT_SIZE}" font-family="sans-serif" stroke="none">{}</text>"#,
+            escape(label)
+        ));
+    }
+}
+
+/// Formats a list of points for the `points` attribute of an SVG polygon.
+fn svg_points(points: &[Point]) -> String {
+    points
+        .iter()
+        .map(|(x, y)| format!("{:.2},{:.2}", x, y))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn add(a: Point, b: Point) -> Point {
+    (a.0 + b.0, a.1 + b.1)
+}
+
+fn sub(a: Point, b: Point) -> Point {
+    (a.0 - b.0, a.1 - b.1)
+}
+
+fn scaled(a: Point, factor: f64) -> Point {
+    (a.0 * factor, a.1 * factor)
+}
+
+fn dot(a: Point, b: Point) -> f64 {
+    a.0 * b.0 + a.1 * b.1
+}
+
+/// The vector with length one in the direction of `a`, if it has a direction.
+fn unit(a: Point) -> Option<Point> {
+    let length = dot(a, a).sqrt();
+    (length > 1e-9).then(|| scaled(a, 1.0 / length))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plate(width: f64, height: f64) -> String {
+        let labels = Labels::new(NumberFormat::new("en"), LengthUnit::Millimetre);
+        Drawing::new()
+            .with_shape("shape", Shape::rectangle(0.0, 0.0, width, height))
+            .with_dimension(Dimension::Aligned {
+                from: (0.0, 0.0),
+                to: (width, 0.0),
+                label: labels.length(width),
+            })
+            .with_dimension(Dimension::Aligned {
+                from: (width, 0.0),
+                to: (width, height),
+                label: labels.length(height),
+            })
+            .to_svg()
+    }
+
+    fn view_box(svg: &str) -> Vec<f64> {
+        let start = svg.find("viewBox=\"").unwrap() + 9;
+        let end = start + svg[start..].find('"').unwrap();
+        svg[start..end]
+            .split(' ')
+            .map(|n| n.parse().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_scaled_to_fit() {
+        // The same proportions fill the same view box, whatever their size
+        let large = plate(5000.0, 2500.0);
+        let small = plate(0.1, 0.05);
+        assert_eq!(view_box(&large).len(), 4);
+        assert!(large.contains(r#"points="0.00,120.00 240.00,120.00 240.00,0.00 0.00,0.00""#));
+        assert!(small.contains(r#"points="0.00,120.00 240.00,120.00 240.00,0.00 0.00,0.00""#));
+
+        // The dimensions below and to the right are inside the view box
+        let [x, y, width, height] = view_box(&large)[..] else {
+            panic!("view box has four numbers");
+        };
+        assert!(x < 0.0 && y < 0.0);
+        assert!(x + width > 240.0 + DIMENSION_OFFSET);
+        assert!(y + height > 120.0 + DIMENSION_OFFSET);
+        assert!(large.contains(">5,000 mm</text>"));
+        assert!(large.contains(r#"transform="rotate(-90.0"#));
+        assert_eq!(large.matches(r#"class="arrow""#).count(), 4);
+    }
+
+    #[test]
+    fn test_empty_and_leaders() {
+        assert_eq!(plate(0.0, 0.0), EMPTY_SVG);
+        assert_eq!(standalone_svg(EMPTY_SVG), None);
+        let standalone = standalone_svg(&plate(2.0, 1.0)).unwrap();
+        assert!(standalone.starts_with("<?xml"));
+        assert!(standalone.contains(r#""><style>/*"#));
+        assert!(standalone.contains(".neutral-axis {"));
+        assert_eq!(Drawing::new().to_svg(), EMPTY_SVG);
+
+        let labels = Labels::new(NumberFormat::new("nl"), LengthUnit::Metre);
+        let circle = Drawing::new()
+            .with_shape(
+                "shape",
+                Shape::Circle {
+                    center: (0.0, 0.0),
+                    radius: 1.5,
+                },
+            )
+            .with_dimension(Dimension::Diameter {
+                center: (0.0, 0.0),
+                radius: 1.5,
+                angle: RADIUS_ANGLE,
+                label: labels.diameter(3.0),
+            })
+            .to_svg();
+        assert!(circle.contains(r#"r="120.00""#));
+        assert!(circle.contains(">Ø 3 m</text>"));
+        assert_eq!(circle.matches(r#"class="arrow""#).count(), 2);
+    }
+}
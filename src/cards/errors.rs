@@ -0,0 +1,37 @@
+use crate::calculations::errors::CalculationError;
+use crate::locales::i18n::I18n;
+use leptos::prelude::*;
+
+/// Id of the [`FieldError`] of the input with id `input`.
+pub fn error_id(input: &str) -> String {
+    format!("{input}-error")
+}
+
+/// Localized message shown next to the input that caused the error.
+#[allow(non_snake_case)]
+#[component]
+pub fn FieldError(
+    #[prop(into)] error: Signal<Option<CalculationError>>,
+    /// Parameters of the calculation entered in this input; empty shows every error.
+    parameters: &'static [&'static str],
+    /// Id of the input the message describes, if it belongs to one.
+    #[prop(optional)]
+    input_id: Option<String>,
+) -> impl IntoView {
+    let i18n = use_context::<Memo<I18n>>().expect("I18n context not found");
+
+    let message = move || {
+        error
+            .get()
+            .filter(|err| parameters.is_empty() || parameters.contains(&err.parameter()))
+            .map(|err| i18n.get().t(err.i18n_key()).to_string())
+    };
+
+    view! {
+        <Show when=move || message().is_some()>
+            <p id=input_id.as_deref().map(error_id) class="card__inputs__error" role="alert">
+                {message}
+            </p>
+        </Show>
+    }
+}
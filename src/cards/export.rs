@@ -0,0 +1,274 @@
+//! Copying the results of a card, as a value with its unit, a table for spreadsheets,
+//! Markdown or the LaTeX of the formula.
+
+use crate::cards::history::HistoryValue;
+use crate::dom::clipboard::copy_text;
+use crate::locales::i18n::I18n;
+use leptos::prelude::*;
+use std::time::Duration;
+
+/// How long the message after copying stays in view.
+const TOAST_DURATION: Duration = Duration::from_secs(3);
+
+/// The values of a calculation as they are copied, in the units they are shown in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResultExport {
+    pub inputs: Vec<HistoryValue>,
+    /// Results, the main result first.
+    pub results: Vec<HistoryValue>,
+    /// LaTeX of the formula, if the card shows one.
+    pub latex: Option<String>,
+}
+
+/// Text a card's results are copied as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopyFormat {
+    /// The main result with its unit, like `6 m²`.
+    Value,
+    /// Tab-separated rows of the inputs and results, which spreadsheets paste as cells.
+    Table,
+    Markdown,
+    Latex,
+}
+
+impl CopyFormat {
+    pub const ALL: [CopyFormat; 4] = [
+        CopyFormat::Value,
+        CopyFormat::Table,
+        CopyFormat::Markdown,
+        CopyFormat::Latex,
+    ];
+
+    pub fn id(&self) -> &'static str {
+        match self {
+            CopyFormat::Value => "value",
+            CopyFormat::Table => "table",
+            CopyFormat::Markdown => "markdown",
+            CopyFormat::Latex => "latex",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Self {
+        CopyFormat::ALL
+            .into_iter()
+            .find(|format| format.id() == id)
+            .unwrap_or(CopyFormat::Value)
+    }
+
+    pub fn i18n_key(&self) -> &'static str {
+        match self {
+            CopyFormat::Value => "copy_value",
+            CopyFormat::Table => "copy_table",
+            CopyFormat::Markdown => "copy_markdown",
+            CopyFormat::Latex => "copy_latex",
+        }
+    }
+}
+
+impl ResultExport {
+    /// The text to copy in a format, with numbers rounded and written as in the
+    /// language; `None` if the calculation has nothing to copy in that format.
+    pub fn text(&self, format: CopyFormat, i18n: &I18n) -> Option<String> {
+        let numbers = i18n.number_format();
+        let header = [
+            i18n.t("copy_symbol"),
+            i18n.t("copy_number"),
+            i18n.t("copy_unit"),
+        ];
+        let values = || self.inputs.iter().chain(&self.results);
+        match format {
+            CopyFormat::Value => {
+                let result = self.results.first()?;
+                let value = numbers.format(result.value);
+                Some(match result.unit.as_str() {
+                    "" => value,
+                    unit => format!("{value} {unit}"),
+                })
+            }
+            CopyFormat::Table => {
+                let rows = values().map(|value| {
+                    format!(
+                        "{}\t{}\t{}",
+                        value.symbol,
+                        numbers.format_plain(value.value),
+                        value.unit
+                    )
+                });
+                Some(
+                    std::iter::once(header.join("\t"))
+                        .chain(rows)
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                )
+            }
+            CopyFormat::Markdown => {
+                // Results are set in bold below the inputs
+                let inputs = self.inputs.iter().map(|value| (value, ""));
+                let results = self.results.iter().map(|value| (value, "**"));
+                let rows = inputs.chain(results).map(|(value, bold)| {
+                    format!(
+                        "| {bold}{}{bold} | {bold}{}{bold} | {} |",
+                        value.symbol,
+                        numbers.format(value.value),
+                        value.unit
+                    )
+                });
+                let lines = [
+                    format!("| {} |", header.join(" | ")),
+                    "| --- | ---: | --- |".to_string(),
+                ];
+                Some(lines.into_iter().chain(rows).collect::<Vec<_>>().join("\n"))
+            }
+            CopyFormat::Latex => self.latex.clone(),
+        }
+    }
+}
+
+/// Message telling whether a copy to the clipboard worked, shown for a while.
+#[derive(Debug, Clone, Copy)]
+pub struct CopyToast {
+    /// Key of the message and the copy it belongs to, so an earlier copy doesn't hide it
+    message: RwSignal<Option<(u32, &'static str)>>,
+    copies: StoredValue<u32>,
+}
+
+impl Default for CopyToast {
+    fn default() -> Self {
+        CopyToast {
+            message: RwSignal::new(None),
+            copies: StoredValue::new(0),
+        }
+    }
+}
+
+impl CopyToast {
+    /// Shows whether the browser allowed a copy, until a later copy replaces it.
+    pub fn show(self, copied: bool) {
+        self.copies.update_value(|copies| *copies += 1);
+        let copy = self.copies.get_value();
+        let key = if copied { "copy_done" } else { "copy_failed" };
+        self.message.set(Some((copy, key)));
+        set_timeout(
+            move || {
+                if self
+                    .message
+                    .get_untracked()
+                    .is_some_and(|(shown, _)| shown == copy)
+                {
+                    self.message.set(None);
+                }
+            },
+            TOAST_DURATION,
+        );
+    }
+
+    /// The message while it is shown.
+    pub fn view(self) -> impl IntoView {
+        let i18n = use_context::<Memo<I18n>>().expect("I18n context not found");
+        move || {
+            self.message.get().map(|(_, key)| {
+                view! {
+                    <p class="card__toast" role="status">
+                        {move || i18n.get().t(key).to_string()}
+                    </p>
+                }
+            })
+        }
+    }
+}
+
+/// Copies the results of a card in the format the user picks, telling on `toast`
+/// whether the browser allowed it.
+#[allow(non_snake_case)]
+#[component]
+pub fn CopyResult(
+    #[prop(into)] export: Signal<Option<ResultExport>>,
+    toast: CopyToast,
+) -> impl IntoView {
+    let i18n = use_context::<Memo<I18n>>().expect("I18n context not found");
+    let format = RwSignal::new(CopyFormat::Value);
+
+    let text = move || export.get()?.text(format.get(), &i18n.get());
+    let copy = move |_| {
+        if let Some(text) = text() {
+            copy_text(text, move |copied| toast.show(copied));
+        }
+    };
+
+    view! {
+        <div class="card__result__copy">
+            <select
+                prop:value=move || format.get().id()
+                on:change=move |ev| format.set(CopyFormat::from_id(&event_target_value(&ev)))
+            >
+                {CopyFormat::ALL
+                    .into_iter()
+                    .map(|option| {
+                        view! {
+                            <option value=option.id()>
+                                {move || i18n.get().t(option.i18n_key()).to_string()}
+                            </option>
+                        }
+                    })
+                    .collect_view()}
+            </select>
+            <button on:click=copy disabled=move || text().is_none()>
+                {move || i18n.get().t("copy_to_clipboard").to_string()}
+            </button>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn export() -> ResultExport {
+        ResultExport {
+            inputs: vec![
+                HistoryValue::new("w", 2.5, "m"),
+                HistoryValue::new("h", 3.0, "m"),
+            ],
+            results: vec![HistoryValue::new("A", 7500.0, "m²")],
+            latex: None,
+        }
+    }
+
+    #[test]
+    fn test_export_text() {
+        let nl = I18n::new("nl");
+        let en = I18n::new("en");
+        assert_eq!(
+            export().text(CopyFormat::Value, &nl),
+            Some("7.500 m²".to_string())
+        );
+        assert_eq!(
+            export().text(CopyFormat::Table, &nl),
+            Some("Symbool\tWaarde\tEenheid\nw\t2,5\tm\nh\t3\tm\nA\t7500\tm²".to_string())
+        );
+        assert_eq!(
+            export().text(CopyFormat::Markdown, &en),
+            Some(
+                "| Symbol | Value | Unit |\n| --- | ---: | --- |\n| w | 2.5 | m |\n| h | 3 | m |\n\
+                 | **A** | **7,500** | m² |"
+                    .to_string()
+            )
+        );
+        assert_eq!(export().text(CopyFormat::Latex, &en), None);
+        let with_formula = ResultExport {
+            latex: Some(r"A = w \times h".to_string()),
+            ..export()
+        };
+        assert_eq!(
+            with_formula.text(CopyFormat::Latex, &en).as_deref(),
+            Some(r"A = w \times h")
+        );
+
+        let unitless = ResultExport {
+            results: vec![HistoryValue::new("n", 6.0, "")],
+            ..export()
+        };
+        assert_eq!(unitless.text(CopyFormat::Value, &en), Some("6".to_string()));
+        assert_eq!(CopyFormat::from_id("markdown"), CopyFormat::Markdown);
+    }
+}
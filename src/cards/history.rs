@@ -0,0 +1,328 @@
+//! History of completed calculations, kept in localStorage so it survives a reload.
+
+use crate::cards::share::CardInfo;
+use crate::dom::storage::{next_id, LocalStore};
+use crate::locales::i18n::I18n;
+use crate::locales::numbers::NumberFormat;
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Key of the history in localStorage.
+const STORAGE_KEY: &str = "history";
+
+/// Number of unpinned entries kept; pinned entries are kept until deleted.
+pub const HISTORY_LIMIT: usize = 50;
+
+/// How long the inputs of a card stay unchanged before its calculation is recorded,
+/// so typing a number does not record every digit.
+const RECORD_AFTER: Duration = Duration::from_millis(1_500);
+
+/// A value of a calculation in the unit it was entered or shown in.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryValue {
+    pub symbol: String,
+    pub value: f64,
+    /// Symbol of the unit, empty for plain numbers.
+    pub unit: String,
+}
+
+impl HistoryValue {
+    pub fn new(symbol: &str, value: f64, unit: &str) -> Self {
+        HistoryValue {
+            symbol: symbol.to_string(),
+            value,
+            unit: unit.to_string(),
+        }
+    }
+
+    fn text(&self, numbers: NumberFormat) -> String {
+        let text = format!("{} = {}", self.symbol, numbers.format(self.value));
+        match self.unit.as_str() {
+            "" => text,
+            unit => format!("{text} {unit}"),
+        }
+    }
+}
+
+/// One completed calculation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub id: u64,
+    /// Id of the card, such as `rectangle`.
+    pub card: String,
+    pub title_key: String,
+    /// Name given by the user, shown instead of the title of the card.
+    pub name: Option<String>,
+    /// Link that re-opens the card with this calculation.
+    pub link: String,
+    pub inputs: Vec<HistoryValue>,
+    pub results: Vec<HistoryValue>,
+    /// Milliseconds since the Unix epoch.
+    pub timestamp: f64,
+    pub pinned: bool,
+}
+
+impl HistoryEntry {
+    /// A calculation completed just now; its id is given when it is recorded.
+    pub fn new(
+        card: CardInfo,
+        link: String,
+        inputs: Vec<HistoryValue>,
+        results: Vec<HistoryValue>,
+    ) -> Self {
+        HistoryEntry {
+            id: 0,
+            card: card.id.to_string(),
+            title_key: card.title_key.to_string(),
+            name: None,
+            link,
+            inputs,
+            results,
+            timestamp: js_sys::Date::now(),
+            pinned: false,
+        }
+    }
+}
+
+/// Completed calculations, newest first.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct History {
+    pub entries: Vec<HistoryEntry>,
+}
+
+impl History {
+    /// Adds a calculation at the top. Repeating an earlier calculation moves that entry
+    /// up with its name and pin.
+    pub fn record(&mut self, mut entry: HistoryEntry) {
+        let same_calculation = self
+            .entries
+            .iter()
+            .position(|recorded| recorded.link == entry.link);
+        if let Some(index) = same_calculation {
+            let recorded = self.entries.remove(index);
+            entry.id = recorded.id;
+            entry.name = recorded.name;
+            entry.pinned = recorded.pinned;
+        } else {
+            entry.id = next_id(self.entries.iter().map(|entry| entry.id));
+        }
+        self.entries.insert(0, entry);
+
+        let mut unpinned = 0;
+        self.entries.retain(|entry| {
+            unpinned += usize::from(!entry.pinned);
+            entry.pinned || unpinned <= HISTORY_LIMIT
+        });
+    }
+
+    pub fn set_pinned(&mut self, id: u64, pinned: bool) {
+        if let Some(entry) = self.entries.iter_mut().find(|entry| entry.id == id) {
+            entry.pinned = pinned;
+        }
+    }
+
+    /// Names an entry; an empty name shows the title of the card again.
+    pub fn rename(&mut self, id: u64, name: &str) {
+        if let Some(entry) = self.entries.iter_mut().find(|entry| entry.id == id) {
+            let name = name.trim();
+            entry.name = (!name.is_empty()).then(|| name.to_string());
+        }
+    }
+
+    pub fn delete(&mut self, id: u64) {
+        self.entries.retain(|entry| entry.id != id);
+    }
+}
+
+/// The history shared by all cards, saved to localStorage on every change.
+pub type HistoryStore = LocalStore<History>;
+
+impl HistoryStore {
+    /// Provides the history as context; it is read from localStorage once in the browser.
+    pub fn provide() {
+        LocalStore::provide_with(STORAGE_KEY, |history: &mut History, stored| {
+            // Keep calculations recorded before the stored history was read
+            let recorded = std::mem::replace(history, stored);
+            for entry in recorded.entries.into_iter().rev() {
+                history.record(entry);
+            }
+        });
+    }
+}
+
+/// Records the calculation of a card once its inputs have stopped changing for
+/// [`RECORD_AFTER`].
+pub fn record_history(entry: impl Fn() -> Option<HistoryEntry> + 'static) {
+    let Some(store) = use_context::<HistoryStore>() else {
+        return;
+    };
+    // Counts the changes, so a pending record is dropped when the inputs change again
+    let changes = StoredValue::new(0u32);
+    Effect::new(move |_| {
+        let entry = entry();
+        changes.update_value(|changes| *changes += 1);
+        let change = changes.get_value();
+        let Some(entry) = entry else {
+            return;
+        };
+        set_timeout(
+            move || {
+                if changes.try_get_value() == Some(change) {
+                    store.update(|history| history.record(entry));
+                }
+            },
+            RECORD_AFTER,
+        );
+    });
+}
+
+/// Date and time of a timestamp in the time zone of the browser, as `2024-05-01 14:30`.
+pub fn format_time(timestamp: f64) -> String {
+    let date = js_sys::Date::new_0();
+    date.set_time(timestamp);
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}",
+        date.get_full_year(),
+        date.get_month() + 1,
+        date.get_date(),
+        date.get_hours(),
+        date.get_minutes()
+    )
+}
+
+/// Side panel listing the history, where calculations are re-opened, pinned, renamed
+/// and deleted.
+#[allow(non_snake_case)]
+#[component]
+pub fn HistoryPanel() -> impl IntoView {
+    let i18n = use_context::<Memo<I18n>>().expect("I18n context not found");
+    let numbers = Memo::new(move |_| i18n.get().number_format());
+    let store = use_context::<HistoryStore>().expect("History context not found");
+
+    let values_text = move |values: &[HistoryValue]| {
+        values
+            .iter()
+            .map(|value| value.text(numbers.get()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    let entry_view = move |entry: HistoryEntry| {
+        let id = entry.id;
+        let pinned = entry.pinned;
+        let title_key = entry.title_key.clone();
+        view! {
+            <li class="history__entry" class:history__entry--pinned=pinned>
+                <input
+                    type="text"
+                    class="history__entry__name"
+                    aria-label=move || i18n.get().t("history_name").to_string()
+                    placeholder=move || i18n.get().t(&title_key).to_string()
+                    prop:value=entry.name.clone().unwrap_or_default()
+                    on:change=move |ev| {
+                        store.update(|history| history.rename(id, &event_target_value(&ev)))
+                    }
+                />
+                <small>{format_time(entry.timestamp)}</small>
+                <p>{move || values_text(&entry.inputs)}</p>
+                <p class="history__entry__results">{move || values_text(&entry.results)}</p>
+                <div class="history__entry__actions">
+                    <a href=entry.link.clone()>{move || i18n.get().t("history_open").to_string()}</a>
+                    <button on:click=move |_| store.update(|history| history.set_pinned(id, !pinned))>
+                        {move || {
+                            let key = if pinned { "history_unpin" } else { "history_pin" };
+                            i18n.get().t(key).to_string()
+                        }}
+                    </button>
+                    <button on:click=move |_| store.update(|history| history.delete(id))>
+                        {move || i18n.get().t("history_delete").to_string()}
+                    </button>
+                </div>
+            </li>
+        }
+    };
+
+    view! {
+        <details class="history">
+            <summary>{move || i18n.get().t("history_title").to_string()}</summary>
+            <Show when=move || store.get().entries.is_empty()>
+                <p>{move || i18n.get().t("history_empty").to_string()}</p>
+            </Show>
+            <ul class="history__list">
+                // Changed entries are keyed anew, so their view shows the change
+                <For
+                    each=move || store.get().entries
+                    key=|entry| (entry.id, entry.timestamp.to_bits(), entry.pinned, entry.name.clone())
+                    children=entry_view
+                />
+            </ul>
+        </details>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(card: &str, link: &str, timestamp: f64) -> HistoryEntry {
+        HistoryEntry {
+            id: 0,
+            card: card.to_string(),
+            title_key: format!("{card}_title"),
+            name: None,
+            link: link.to_string(),
+            inputs: vec![HistoryValue::new("w", 2.0, "m")],
+            results: vec![HistoryValue::new("A", 6.0, "m²")],
+            timestamp,
+            pinned: false,
+        }
+    }
+
+    #[test]
+    fn test_record_keeps_calculations() {
+        let mut history = History::default();
+        history.record(entry("rectangle", "/r?w=1", 0.0));
+        history.record(entry("rectangle", "/r?w=12", 1_000.0));
+        history.record(entry("circle", "/c?r=1", 2_000.0));
+        assert_eq!(history.entries.len(), 3);
+        assert_eq!(history.entries[1].link, "/r?w=12");
+        let ids: Vec<u64> = history.entries.iter().map(|entry| entry.id).collect();
+        assert_eq!(ids, [2, 1, 0]);
+
+        // Repeating a calculation moves it up with its name and pin
+        history.rename(0, " Floor ");
+        history.set_pinned(0, true);
+        history.record(entry("rectangle", "/r?w=1", 500_000.0));
+        assert_eq!(history.entries.len(), 3);
+        assert_eq!(history.entries[0].id, 0);
+        assert_eq!(history.entries[0].timestamp, 500_000.0);
+        assert_eq!(history.entries[0].name.as_deref(), Some("Floor"));
+        assert!(history.entries[0].pinned);
+        history.rename(0, "");
+        assert_eq!(history.entries[0].name, None);
+    }
+
+    #[test]
+    fn test_history_limit_keeps_pinned() {
+        let mut history = History::default();
+        history.record(entry("rectangle", "/r?w=0", 0.0));
+        history.set_pinned(0, true);
+        for index in 1..=HISTORY_LIMIT + 5 {
+            let link = format!("/c?r={index}");
+            history.record(entry("circle", &link, index as f64));
+        }
+        assert_eq!(history.entries.len(), HISTORY_LIMIT + 1);
+        assert!(history.entries.iter().any(|entry| entry.pinned));
+        history.delete(0);
+        assert_eq!(history.entries.len(), HISTORY_LIMIT);
+    }
+
+    #[test]
+    fn test_history_json() {
+        let mut history = History::default();
+        history.record(entry("rectangle", "/r?w=1", 0.0));
+        let json = serde_json::to_string(&history).unwrap();
+        assert_eq!(serde_json::from_str::<History>(&json).unwrap(), history);
+    }
+}
@@ -0,0 +1,104 @@
+use crate::calculations::errors::CalculationError;
+use crate::locales::i18n::I18n;
+use leptos::prelude::*;
+
+/// Outcome of a card's calculation: `None` while an input is missing or invalid.
+pub type Outcome<T> = Option<Result<T, CalculationError>>;
+
+/// Calculation of a card, derived from its inputs. While live updating is on it
+/// follows every change; otherwise it keeps the outcome of the last explicit
+/// calculation, so results, formula and drawing always describe the same values.
+pub struct LiveCalculation<T: Send + Sync + 'static> {
+    live: RwSignal<bool>,
+    draft: Memo<Outcome<T>>,
+    applied: RwSignal<Outcome<T>>,
+    current: Memo<Outcome<T>>,
+}
+
+impl<T: Send + Sync + 'static> Clone for LiveCalculation<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Send + Sync + 'static> Copy for LiveCalculation<T> {}
+
+impl<T: Clone + PartialEq + Send + Sync + 'static> LiveCalculation<T> {
+    pub fn new(calculate: impl Fn() -> Outcome<T> + Send + Sync + 'static) -> Self {
+        let live = RwSignal::new(true);
+        let draft = Memo::new(move |_| calculate());
+        let applied = RwSignal::new(None);
+        let current = Memo::new(move |_| {
+            if live.get() {
+                draft.get()
+            } else {
+                applied.get()
+            }
+        });
+        LiveCalculation {
+            live,
+            draft,
+            applied,
+            current,
+        }
+    }
+
+    /// The calculated value, if the calculation succeeded.
+    pub fn value(&self) -> Option<T> {
+        self.current.get().and_then(Result::ok)
+    }
+
+    /// The error of the calculation, for showing next to the inputs.
+    pub fn error(&self) -> Signal<Option<CalculationError>> {
+        let current = self.current;
+        Signal::derive(move || current.get().and_then(Result::err))
+    }
+
+    /// Calculates from the current inputs, for when live updating is off.
+    pub fn apply(&self) {
+        self.applied.set(self.draft.get_untracked());
+    }
+
+    pub fn set_live(&self, live: bool) {
+        // Keep showing the current outcome until the next explicit calculation
+        if !live {
+            self.apply();
+        }
+        self.live.set(live);
+    }
+}
+
+/// Switch for live updating, with the calculate button for when it is off.
+#[allow(non_snake_case)]
+#[component]
+pub fn LiveControls<T: Clone + PartialEq + Send + Sync + 'static>(
+    calculation: LiveCalculation<T>,
+    /// Key of the text of the calculate button.
+    #[prop(into)]
+    calculate_key: Signal<&'static str>,
+    /// Whether every input passes its check.
+    #[prop(into)]
+    valid: Signal<bool>,
+) -> impl IntoView {
+    let i18n = use_context::<Memo<I18n>>().expect("I18n context not found");
+
+    view! {
+        <label class="card__inputs__live">
+            <input
+                type="checkbox"
+                prop:checked=move || calculation.live.get()
+                on:change=move |ev| calculation.set_live(event_target_checked(&ev))
+            />
+            {move || i18n.get().t("card_live_update").to_string()}
+        </label>
+        <Show when=move || !calculation.live.get()>
+            <button
+                class="card__inputs__button"
+                disabled=move || !valid.get()
+                on:click=move |_| calculation.apply()
+            >
+                {move || i18n.get().t(calculate_key.get()).to_string()}
+            </button>
+        </Show>
+    }
+}
@@ -0,0 +1,387 @@
+//! Renders the LaTeX formulas of the cards as MathML, which browsers display without
+//! a script, so formulas show on first paint and offline.
+//!
+//! Only the LaTeX the cards write is understood: numbers as written by
+//! [`NumberFormat::format_latex`](crate::locales::numbers::NumberFormat::format_latex),
+//! `^` and `_`, `\frac`, `\sqrt`, `\left` and `\right`, `\text`, Greek letters,
+//! functions such as `\sin`, operators such as `\times`, spacing, and `\\` between
+//! lines. Anything else is shown as an error in the formula instead of failing.
+
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// Renders a LaTeX formula as a MathML element, its lines below each other.
+pub fn latex_to_mathml(latex: &str) -> String {
+    let lines: Vec<String> = split_lines(latex)
+        .into_iter()
+        .map(|line| Parser::new(line).row())
+        .collect();
+    let body = match lines.as_slice() {
+        [line] => line.clone(),
+        lines => format!(
+            r#"<mtable columnalign="left">{}</mtable>"#,
+            lines
+                .iter()
+                .map(|line| format!("<mtr><mtd>{line}</mtd></mtr>"))
+                .collect::<String>()
+        ),
+    };
+    format!(r#"<math xmlns="http://www.w3.org/1998/Math/MathML" display="block">{body}</math>"#)
+}
+
+/// Splits a formula at the `\\` outside braces.
+fn split_lines(latex: &str) -> Vec<&str> {
+    let mut lines = Vec::new();
+    let (mut depth, mut start, mut escaped) = (0, 0, false);
+    for (index, c) in latex.char_indices() {
+        match c {
+            _ if escaped => {
+                escaped = false;
+                if c == '\\' && depth == 0 {
+                    lines.push(&latex[start..index - 1]);
+                    start = index + 1;
+                }
+            }
+            '\\' => escaped = true,
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            _ => {}
+        }
+    }
+    lines.push(&latex[start..]);
+    lines
+}
+
+struct Parser<'a> {
+    chars: Peekable<Chars<'a>>,
+    /// Number of `\left` groups the parser is in, which end at their `\right`.
+    open_lefts: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(latex: &'a str) -> Self {
+        Parser {
+            chars: latex.chars().peekable(),
+            open_lefts: 0,
+        }
+    }
+
+    /// Elements up to the end of the current group, in an `mrow`.
+    fn row(&mut self) -> String {
+        let mut elements = String::new();
+        let mut base: Option<String> = None;
+        loop {
+            self.skip_whitespace();
+            if self.open_lefts > 0 && self.at_right() {
+                break;
+            }
+            match self.chars.peek() {
+                None | Some('}') => break,
+                Some('^' | '_') => {
+                    let script = self.chars.next();
+                    let base_element = base.take().unwrap_or_else(|| "<mrow></mrow>".to_string());
+                    base = Some(self.scripts(base_element, script == Some('^')));
+                }
+                Some(_) => {
+                    if let Some(previous) = base.replace(self.atom()) {
+                        elements.push_str(&previous);
+                    }
+                }
+            }
+        }
+        elements.push_str(&base.unwrap_or_default());
+        format!("<mrow>{elements}</mrow>")
+    }
+
+    /// A superscript or subscript on `base`, with the other script if it follows.
+    fn scripts(&mut self, base: String, superscript: bool) -> String {
+        let first = self.argument();
+        self.skip_whitespace();
+        let other = if superscript { '_' } else { '^' };
+        if self.chars.next_if_eq(&other).is_some() {
+            let second = self.argument();
+            let (sub, sup) = if superscript {
+                (second, first)
+            } else {
+                (first, second)
+            };
+            return format!("<msubsup>{base}{sub}{sup}</msubsup>");
+        }
+        match superscript {
+            true => format!("<msup>{base}{first}</msup>"),
+            false => format!("<msub>{base}{first}</msub>"),
+        }
+    }
+
+    /// A group in braces, or else a single element.
+    fn argument(&mut self) -> String {
+        self.skip_whitespace();
+        if self.chars.next_if_eq(&'{').is_some() {
+            let group = self.row();
+            self.chars.next_if_eq(&'}');
+            return group;
+        }
+        match self.chars.peek() {
+            None | Some('}') => "<mrow></mrow>".to_string(),
+            Some(_) => self.atom(),
+        }
+    }
+
+    /// The text up to the closing brace of a group, for `\text`.
+    fn text_argument(&mut self) -> String {
+        self.skip_whitespace();
+        if self.chars.next_if_eq(&'{').is_none() {
+            return self.chars.next().map(String::from).unwrap_or_default();
+        }
+        let mut text = String::new();
+        let mut depth = 0;
+        for c in self.chars.by_ref() {
+            match c {
+                '{' => depth += 1,
+                '}' if depth == 0 => break,
+                '}' => depth -= 1,
+                _ => {}
+            }
+            text.push(c);
+        }
+        text
+    }
+
+    fn atom(&mut self) -> String {
+        let Some(c) = self.chars.next() else {
+            return String::new();
+        };
+        match c {
+            '\\' => self.command(),
+            '{' => {
+                let group = self.row();
+                self.chars.next_if_eq(&'}');
+                group
+            }
+            '0'..='9' | '.' => self.number(c),
+            '-' => "<mo>\u{2212}</mo>".to_string(),
+            '*' => "<mo>\u{2217}</mo>".to_string(),
+            '+' | '=' | '(' | ')' | '[' | ']' | '/' | '|' | '<' | '>' | ',' | ';' | ':' | '!' => {
+                format!("<mo>{}</mo>", escape(&c.to_string()))
+            }
+            '\'' => "<mo>\u{2032}</mo>".to_string(),
+            c if c.is_alphabetic() => format!("<mi>{c}</mi>"),
+            c => format!("<mo>{}</mo>", escape(&c.to_string())),
+        }
+    }
+
+    /// A number with its decimal and grouping separators, where a comma may be
+    /// written in braces.
+    fn number(&mut self, first: char) -> String {
+        let mut number = String::from(first);
+        loop {
+            let mut ahead = self.chars.clone();
+            match ahead.next() {
+                Some(c @ ('0'..='9' | '.')) => {
+                    number.push(c);
+                    self.chars.next();
+                }
+                Some(',') if ahead.peek().is_some_and(char::is_ascii_digit) => {
+                    number.push(',');
+                    self.chars.next();
+                }
+                Some('{') if ahead.next() == Some(',') && ahead.next() == Some('}') => {
+                    number.push(',');
+                    self.chars.nth(2);
+                }
+                _ => break,
+            }
+        }
+        format!("<mn>{number}</mn>")
+    }
+
+    fn command(&mut self) -> String {
+        let mut name = String::new();
+        while let Some(c) = self.chars.next_if(char::is_ascii_alphabetic) {
+            name.push(c);
+        }
+        if name.is_empty() {
+            // A command of a single symbol, such as a space
+            return match self.chars.next() {
+                Some(',') => space("0.1667em"),
+                Some(':' | '>') => space("0.2222em"),
+                Some(';') => space("0.2778em"),
+                Some(' ') => space("0.25em"),
+                Some('!') => String::new(),
+                Some(c @ ('{' | '}' | '%' | '#' | '&' | '_' | '$')) => {
+                    format!("<mo>{}</mo>", escape(&c.to_string()))
+                }
+                c => unknown(&c.map(String::from).unwrap_or_default()),
+            };
+        }
+        match name.as_str() {
+            "frac" => {
+                let numerator = self.argument();
+                let denominator = self.argument();
+                format!("<mfrac>{numerator}{denominator}</mfrac>")
+            }
+            "sqrt" => format!("<msqrt>{}</msqrt>", self.argument()),
+            "text" | "mathrm" => format!("<mtext>{}</mtext>", escape(&self.text_argument())),
+            "left" => {
+                let open = self.delimiter();
+                self.open_lefts += 1;
+                let inner = self.row();
+                self.open_lefts -= 1;
+                let close = match self.at_right() {
+                    true => {
+                        self.chars.nth("right".len());
+                        self.delimiter()
+                    }
+                    false => String::new(),
+                };
+                format!("<mrow>{open}{inner}{close}</mrow>")
+            }
+            // A `\right` without its `\left`
+            "right" => self.delimiter(),
+            "quad" => space("1em"),
+            "qquad" => space("2em"),
+            name => match symbol(name) {
+                Some(Symbol::Identifier(letter)) => format!("<mi>{letter}</mi>"),
+                Some(Symbol::Operator(operator)) => format!("<mo>{operator}</mo>"),
+                Some(Symbol::Function) => {
+                    format!(r#"<mi mathvariant="normal">{name}</mi><mo>&#x2061;</mo>"#)
+                }
+                None => unknown(name),
+            },
+        }
+    }
+
+    /// The delimiter after `\left` or `\right`, stretched to the group; `.` leaves it out.
+    fn delimiter(&mut self) -> String {
+        self.skip_whitespace();
+        match self.chars.next() {
+            Some('.') | None => String::new(),
+            Some(c) => format!(r#"<mo stretchy="true">{}</mo>"#, escape(&c.to_string())),
+        }
+    }
+
+    /// Whether `\right` follows, which closes the innermost `\left` group.
+    fn at_right(&self) -> bool {
+        let mut ahead = self.chars.clone();
+        ahead.next() == Some('\\')
+            && ahead.by_ref().take(5).eq("right".chars())
+            && !ahead.next().is_some_and(|c| c.is_ascii_alphabetic())
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|c| c.is_whitespace()).is_some() {}
+    }
+}
+
+enum Symbol {
+    Identifier(char),
+    Operator(char),
+    /// A function name written upright, such as `sin`.
+    Function,
+}
+
+fn symbol(name: &str) -> Option<Symbol> {
+    let letter = match name {
+        "alpha" => 'α',
+        "beta" => 'β',
+        "gamma" => 'γ',
+        "delta" => 'δ',
+        "epsilon" | "varepsilon" => 'ε',
+        "eta" => 'η',
+        "theta" => 'θ',
+        "lambda" => 'λ',
+        "mu" => 'μ',
+        "nu" => 'ν',
+        "pi" => 'π',
+        "rho" => 'ρ',
+        "sigma" => 'σ',
+        "tau" => 'τ',
+        "phi" | "varphi" => 'φ',
+        "omega" => 'ω',
+        "Delta" => 'Δ',
+        "Sigma" => 'Σ',
+        "Omega" => 'Ω',
+        _ => {
+            let operator = match name {
+                "times" => '×',
+                "cdot" => '⋅',
+                "approx" => '≈',
+                "pm" => '±',
+                "le" | "leq" => '≤',
+                "ge" | "geq" => '≥',
+                "neq" => '≠',
+                "infty" => return Some(Symbol::Identifier('∞')),
+                "sin" | "cos" | "tan" | "arcsin" | "arccos" | "arctan" | "ln" | "log" | "exp"
+                | "max" | "min" => return Some(Symbol::Function),
+                _ => return None,
+            };
+            return Some(Symbol::Operator(operator));
+        }
+    };
+    Some(Symbol::Identifier(letter))
+}
+
+fn space(width: &str) -> String {
+    format!(r#"<mspace width="{width}"></mspace>"#)
+}
+
+/// A command that is not understood, shown as written.
+fn unknown(name: &str) -> String {
+    format!(r"<merror><mtext>\{}</mtext></merror>", escape(name))
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inner(latex: &str) -> String {
+        let math = latex_to_mathml(latex);
+        let start = math.find('>').unwrap() + 1;
+        math[start..math.len() - "</math>".len()].to_string()
+    }
+
+    #[test]
+    fn test_latex_to_mathml() {
+        assert_eq!(
+            inner(r"A = \pi r^2"),
+            "<mrow><mi>A</mi><mo>=</mo><mi>π</mi><msup><mi>r</mi><mn>2</mn></msup></mrow>"
+        );
+        assert_eq!(
+            inner(r"\frac{1{,}5}{2}\,\text{m²}"),
+            "<mrow><mfrac><mrow><mn>1,5</mn></mrow><mrow><mn>2</mn></mrow></mfrac>\
+             <mspace width=\"0.1667em\"></mspace><mtext>m²</mtext></mrow>"
+        );
+        assert_eq!(
+            inner(r"e_{max}^2"),
+            "<mrow><msubsup><mi>e</mi><mrow><mi>m</mi><mi>a</mi><mi>x</mi></mrow><mn>2</mn></msubsup></mrow>"
+        );
+        assert!(inner(r"\sin(\alpha)").starts_with(r#"<mrow><mi mathvariant="normal">sin</mi>"#));
+        assert_eq!(
+            inner(r"\left(\frac{c}{2}\right)^2"),
+            r#"<mrow><msup><mrow><mo stretchy="true">(</mo><mrow><mfrac><mrow><mi>c</mi></mrow><mrow><mn>2</mn></mrow></mfrac></mrow><mo stretchy="true">)</mo></mrow><mn>2</mn></msup></mrow>"#
+        );
+        assert_eq!(
+            inner(r"\left. x \right|_0"),
+            "<mrow><msub><mrow><mrow><mi>x</mi></mrow><mo stretchy=\"true\">|</mo></mrow><mn>0</mn></msub></mrow>"
+        );
+        assert!(inner(r"\sqrt{x} - 1.234")
+            .contains("<msqrt><mrow><mi>x</mi></mrow></msqrt><mo>\u{2212}</mo><mn>1.234</mn>"));
+        assert!(inner(r"\foo").contains(r"<merror><mtext>\foo</mtext></merror>"));
+        assert_eq!(inner(r"\text{a<b}"), "<mrow><mtext>a&lt;b</mtext></mrow>");
+    }
+
+    #[test]
+    fn test_lines() {
+        let math = inner(r"A = w \times h \\ A = \frac{#A}{2} \\ x");
+        assert_eq!(math.matches("<mtr>").count(), 3);
+        assert!(math.starts_with(r#"<mtable columnalign="left">"#));
+        // A break inside braces stays on its line
+        assert_eq!(split_lines(r"a \\ {b \\ c}"), vec!["a ", r" {b \\ c}"]);
+    }
+}
@@ -1 +1,16 @@
 pub mod area;
+pub mod calculator;
+pub mod composite;
+pub mod conversion;
+pub mod drawing;
+pub mod errors;
+pub mod export;
+pub mod history;
+pub mod live;
+pub mod mathml;
+pub mod section;
+pub mod share;
+pub mod units;
+pub mod validation;
+pub mod volume;
+pub mod workspace;
@@ -0,0 +1,521 @@
+use crate::calculations::errors::*;
+use crate::calculations::formula::Step;
+use crate::calculations::sections::*;
+use crate::calculations::shapes_2d::*;
+use crate::calculations::units::*;
+use crate::cards::calculator::{
+    AnyQuantity, Calculator, CalculatorCard, CalculatorGroup, CalculatorInput, CalculatorOutput,
+    Measure, Scale,
+};
+use crate::cards::drawing::{Dimension, Drawing, Labels, Shape, EMPTY_SVG, RADIUS_ANGLE};
+use crate::cards::share::CardInfo;
+use crate::cards::validation::Rule;
+use leptos::prelude::*;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum SectionKind {
+    Rectangle,
+    Circle,
+    Tube,
+    RectangularHollow,
+    IProfile,
+    TProfile,
+    LProfile,
+    CProfile,
+}
+
+impl SectionKind {
+    pub const ALL: [SectionKind; 8] = [
+        SectionKind::Rectangle,
+        SectionKind::Circle,
+        SectionKind::Tube,
+        SectionKind::RectangularHollow,
+        SectionKind::IProfile,
+        SectionKind::TProfile,
+        SectionKind::LProfile,
+        SectionKind::CProfile,
+    ];
+
+    pub const fn id(&self) -> &'static str {
+        match self {
+            SectionKind::Rectangle => "rectangle",
+            SectionKind::Circle => "circle",
+            SectionKind::Tube => "tube",
+            SectionKind::RectangularHollow => "rhs",
+            SectionKind::IProfile => "i",
+            SectionKind::TProfile => "t",
+            SectionKind::LProfile => "l",
+            SectionKind::CProfile => "c",
+        }
+    }
+
+    pub const fn i18n_key(&self) -> &'static str {
+        match self {
+            SectionKind::Rectangle => "section_rectangle",
+            SectionKind::Circle => "section_circle",
+            SectionKind::Tube => "section_tube",
+            SectionKind::RectangularHollow => "section_rhs",
+            SectionKind::IProfile => "section_i_profile",
+            SectionKind::TProfile => "section_t_profile",
+            SectionKind::LProfile => "section_l_profile",
+            SectionKind::CProfile => "section_c_profile",
+        }
+    }
+
+    /// The dimensions this kind of section needs, those of its calculator.
+    pub fn inputs(&self) -> &'static [CalculatorInput] {
+        SECTION_KINDS[*self as usize].inputs
+    }
+}
+
+/// Dimensions entered on the section card, unused ones are ignored.
+#[derive(Clone, Copy, Default)]
+pub struct SectionDimensions {
+    pub b: f64,
+    pub h: f64,
+    pub d: f64,
+    pub t: f64,
+    pub tw: f64,
+    pub tf: f64,
+}
+
+impl SectionDimensions {
+    /// The dimensions of a kind of section in `unit` from its values, in the order of
+    /// [`SectionKind::inputs`].
+    pub fn from_inputs(kind: SectionKind, values: &[AnyQuantity], unit: LengthUnit) -> Self {
+        let mut dims = SectionDimensions::default();
+        for (input, value) in kind.inputs().iter().zip(values) {
+            let dimension = match input.symbol {
+                "b" => &mut dims.b,
+                "h" => &mut dims.h,
+                "d" => &mut dims.d,
+                "t" => &mut dims.t,
+                "tw" => &mut dims.tw,
+                _ => &mut dims.tf,
+            };
+            *dimension = value.length().value_in(unit);
+        }
+        dims
+    }
+}
+
+/// Builds a section from dimensions in one unit, the properties come out in that same unit.
+pub fn build_section(
+    kind: SectionKind,
+    dims: SectionDimensions,
+) -> Result<Box<dyn SectionProperties>, CalculationError> {
+    let length = Length::from_si;
+    let section: Box<dyn SectionProperties> = match kind {
+        SectionKind::Rectangle => Box::new(Rectangle::new(length(dims.b), length(dims.h))?),
+        SectionKind::Circle => Box::new(Circle::from_diameter(length(dims.d))?),
+        SectionKind::Tube => Box::new(Tube::new(length(dims.d), length(dims.t))?),
+        SectionKind::RectangularHollow => Box::new(RectangularHollowSection::new(
+            length(dims.b),
+            length(dims.h),
+            length(dims.t),
+        )?),
+        SectionKind::IProfile => Box::new(IProfile::new(
+            length(dims.h),
+            length(dims.b),
+            length(dims.tw),
+            length(dims.tf),
+        )?),
+        SectionKind::TProfile => Box::new(TProfile::new(
+            length(dims.h),
+            length(dims.b),
+            length(dims.tw),
+            length(dims.tf),
+        )?),
+        SectionKind::LProfile => Box::new(LProfile::new(
+            length(dims.h),
+            length(dims.b),
+            length(dims.t),
+        )?),
+        SectionKind::CProfile => Box::new(CProfile::new(
+            length(dims.h),
+            length(dims.b),
+            length(dims.tw),
+            length(dims.tf),
+        )?),
+    };
+    ensure_finite_section(section.as_ref())?;
+    Ok(section)
+}
+
+/// Outline of a section in section coordinates (y up), with the bottom-left corner of
+/// its bounding box at the origin.
+pub fn section_shape(kind: SectionKind, dims: SectionDimensions) -> Shape {
+    let SectionDimensions { b, h, d, t, tw, tf } = dims;
+    let polygon = |points: &[(f64, f64)]| Shape::Polygon(points.to_vec());
+    let circle = |radius: f64| Shape::Circle {
+        center: (d / 2.0, d / 2.0),
+        radius,
+    };
+    match kind {
+        SectionKind::Rectangle => Shape::rectangle(0.0, 0.0, b, h),
+        SectionKind::Circle => circle(d / 2.0),
+        SectionKind::Tube => Shape::Hollow(vec![circle(d / 2.0), circle(d / 2.0 - t)]),
+        SectionKind::RectangularHollow => Shape::Hollow(vec![
+            Shape::rectangle(0.0, 0.0, b, h),
+            Shape::rectangle(t, t, b - 2.0 * t, h - 2.0 * t),
+        ]),
+        SectionKind::IProfile => {
+            let (wl, wr) = ((b - tw) / 2.0, (b + tw) / 2.0);
+            polygon(&[
+                (0.0, 0.0),
+                (b, 0.0),
+                (b, tf),
+                (wr, tf),
+                (wr, h - tf),
+                (b, h - tf),
+                (b, h),
+                (0.0, h),
+                (0.0, h - tf),
+                (wl, h - tf),
+                (wl, tf),
+                (0.0, tf),
+            ])
+        }
+        SectionKind::TProfile => {
+            let (wl, wr) = ((b - tw) / 2.0, (b + tw) / 2.0);
+            polygon(&[
+                (wl, 0.0),
+                (wr, 0.0),
+                (wr, h - tf),
+                (b, h - tf),
+                (b, h),
+                (0.0, h),
+                (0.0, h - tf),
+                (wl, h - tf),
+            ])
+        }
+        SectionKind::LProfile => polygon(&[(0.0, 0.0), (b, 0.0), (b, t), (t, t), (t, h), (0.0, h)]),
+        SectionKind::CProfile => polygon(&[
+            (0.0, 0.0),
+            (b, 0.0),
+            (b, tf),
+            (tw, tf),
+            (tw, h - tf),
+            (b, h - tf),
+            (b, h),
+            (0.0, h),
+        ]),
+    }
+}
+
+/// Neutral axes through the centroid of a section, reaching past its bounding box.
+pub fn neutral_axes(
+    drawing: Drawing,
+    (width, height): (f64, f64),
+    (cx, cy): (f64, f64),
+) -> Drawing {
+    let overshoot = width.max(height) / 20.0;
+    drawing
+        .with_shape(
+            "neutral-axis",
+            Shape::Line((-overshoot, cy), (width + overshoot, cy)),
+        )
+        .with_shape(
+            "neutral-axis",
+            Shape::Line((cx, -overshoot), (cx, height + overshoot)),
+        )
+}
+
+/// Draws the section with its neutral axes through the centroid, and its width and
+/// height or diameter dimensioned.
+pub fn create_section_svg(kind: SectionKind, dims: SectionDimensions, labels: &Labels) -> String {
+    let Ok(section) = build_section(kind, dims) else {
+        return EMPTY_SVG.to_string();
+    };
+    let SectionDimensions { b, h, d, tw, .. } = dims;
+    let drawing = Drawing::new().with_shape("shape", section_shape(kind, dims));
+    let drawing = neutral_axes(drawing, section.bounding_box(), section.centroid());
+    let drawing = match kind {
+        SectionKind::Circle | SectionKind::Tube => drawing.with_dimension(Dimension::Diameter {
+            center: (d / 2.0, d / 2.0),
+            radius: d / 2.0,
+            angle: RADIUS_ANGLE,
+            label: labels.diameter(d),
+        }),
+        _ => {
+            // A T-profile stands on its web, so its width is dimensioned at the top
+            let width = match kind {
+                SectionKind::TProfile => Dimension::Horizontal {
+                    from: (b, h),
+                    to: (0.0, h),
+                    label: labels.length(b),
+                },
+                _ => Dimension::Horizontal {
+                    from: (0.0, 0.0),
+                    to: (b, 0.0),
+                    label: labels.length(b),
+                },
+            };
+            let bottom_left = match kind {
+                SectionKind::TProfile => ((b - tw) / 2.0, 0.0),
+                _ => (0.0, 0.0),
+            };
+            drawing
+                .with_dimension(width)
+                .with_dimension(Dimension::Vertical {
+                    from: (0.0, h),
+                    to: bottom_left,
+                    label: labels.length(h),
+                })
+        }
+    };
+    drawing.to_svg()
+}
+
+/// The properties of a section whose dimensions are in metres, in the order of
+/// [`SECTION_PROPERTIES`].
+pub fn section_values(section: &dyn SectionProperties) -> Vec<AnyQuantity> {
+    let (_, height) = section.bounding_box();
+    let (cx, cy) = section.centroid();
+    vec![
+        section.area_quantity().into(),
+        Length::from_si(cx).into(),
+        Length::from_si(cy).into(),
+        section.ix_quantity().into(),
+        section.iy_quantity().into(),
+        SecondMomentOfArea::from_si(section.ixy()).into(),
+        SecondMomentOfArea::from_si(section.polar_moment()).into(),
+        section.section_modulus_x_quantity().into(),
+        section.section_modulus_y_quantity().into(),
+        Length::from_si(section.radius_of_gyration_x()).into(),
+        Length::from_si(section.radius_of_gyration_y()).into(),
+        Length::from_si(cy.max(height - cy)).into(),
+    ]
+}
+
+/// A property of a section, shown after the given result text.
+const fn property(
+    symbol: &'static str,
+    measure: Measure,
+    label_key: &'static str,
+) -> CalculatorOutput {
+    CalculatorOutput {
+        symbol,
+        measure,
+        label_key: Some(label_key),
+    }
+}
+
+/// Properties every section calculator finds, followed by the distance from the
+/// centroid to the extreme fibre that its formula uses.
+pub const SECTION_PROPERTIES: &[CalculatorOutput] = &[
+    property("A", Measure::Area, "section_area"),
+    property("c_x", Measure::Length, "section_centroid_x"),
+    property("c_y", Measure::Length, "section_centroid_y"),
+    property("I_x", Measure::SecondMomentOfArea, "section_ix"),
+    property("I_y", Measure::SecondMomentOfArea, "section_iy"),
+    property("I_xy", Measure::SecondMomentOfArea, "section_ixy"),
+    property("I_p", Measure::SecondMomentOfArea, "section_ip"),
+    property("W_x", Measure::Volume, "section_wx"),
+    property("W_y", Measure::Volume, "section_wy"),
+    property("i_x", Measure::Length, "section_rx"),
+    property("i_y", Measure::Length, "section_ry"),
+    CalculatorOutput {
+        symbol: "e_max",
+        measure: Measure::Length,
+        label_key: None,
+    },
+];
+
+/// How the polar moment, section modulus and radius of gyration about the horizontal
+/// axis follow from the second moments of area.
+pub const SECTION_FORMULA: &[Step] = &[
+    Step::new("I_p", "I_x + I_y", "#I_x + #I_y"),
+    Step::new("W_x", r"\frac{I_x}{e_{max}}", r"\frac{#I_x}{#e_max}"),
+    Step::new("i_x", r"\sqrt{\frac{I_x}{A}}", r"\sqrt{\frac{#I_x}{#A}}"),
+];
+
+/// A positive dimension of a section.
+const fn dimension(parameter: &'static str, symbol: &'static str) -> CalculatorInput {
+    CalculatorInput {
+        parameter,
+        symbol,
+        measure: Measure::Length,
+        rule: Rule::Positive,
+    }
+}
+
+const WIDTH: (&str, &str) = ("b", "rec_area_breedte");
+const HEIGHT: (&str, &str) = ("h", "rec_area_hoogte");
+const DIAMETER: (&str, &str) = ("d", "section_diameter");
+const WALL: (&str, &str) = ("t", "section_wall_thickness");
+const WEB: (&str, &str) = ("tw", "section_web_thickness");
+const FLANGE: (&str, &str) = ("tf", "section_flange_thickness");
+
+/// Inputs of the I-, T- and C-profiles, which take the same dimensions.
+const PROFILE: &[CalculatorInput] = &[
+    dimension("flange_width", "b"),
+    dimension("height", "h"),
+    dimension("web_thickness", "tw"),
+    dimension("flange_thickness", "tf"),
+];
+
+/// The properties of a kind of section from its values, in the order of
+/// [`SectionKind::inputs`].
+fn kind_values(
+    kind: SectionKind,
+    values: &[AnyQuantity],
+) -> Result<Vec<AnyQuantity>, CalculationError> {
+    let dims = SectionDimensions::from_inputs(kind, values, LengthUnit::Metre);
+    Ok(section_values(build_section(kind, dims)?.as_ref()))
+}
+
+fn kind_svg(kind: SectionKind, values: &[AnyQuantity], labels: &Labels) -> String {
+    let dims = SectionDimensions::from_inputs(kind, values, labels.unit());
+    create_section_svg(kind, dims, labels)
+}
+
+/// The calculator of one kind of section on the section card.
+const fn section(
+    kind: SectionKind,
+    variables: &'static [(&'static str, &'static str)],
+    inputs: &'static [CalculatorInput],
+    constraints: &'static [&'static str],
+    compute: fn(&[AnyQuantity]) -> Result<Vec<AnyQuantity>, CalculationError>,
+    drawing: fn(&[AnyQuantity], &Labels) -> String,
+) -> Calculator {
+    Calculator {
+        id: kind.id(),
+        path: PATH,
+        title_key: kind.i18n_key(),
+        calculate_key: "section_calculate",
+        variables,
+        inputs,
+        constraints,
+        outputs: SECTION_PROPERTIES,
+        formula: SECTION_FORMULA,
+        compute,
+        drawing: Some(drawing),
+        solve_modes: &[],
+        scale: Scale::Millimetre,
+        parts: None,
+    }
+}
+
+const PATH: &str = "/cards/section/properties";
+
+/// The kinds of section, in the order of [`SectionKind::ALL`].
+pub static SECTION_KINDS: [Calculator; 8] = [
+    section(
+        SectionKind::Rectangle,
+        &[WIDTH, HEIGHT],
+        &[dimension("width", "b"), dimension("height", "h")],
+        &[],
+        |v| kind_values(SectionKind::Rectangle, v),
+        |v, labels| kind_svg(SectionKind::Rectangle, v, labels),
+    ),
+    section(
+        SectionKind::Circle,
+        &[DIAMETER],
+        &[dimension("diameter", "d")],
+        &[],
+        |v| kind_values(SectionKind::Circle, v),
+        |v, labels| kind_svg(SectionKind::Circle, v, labels),
+    ),
+    section(
+        SectionKind::Tube,
+        &[DIAMETER, WALL],
+        &[
+            dimension("outer_diameter", "d"),
+            dimension("wall_thickness", "t"),
+        ],
+        &["t < d/2"],
+        |v| kind_values(SectionKind::Tube, v),
+        |v, labels| kind_svg(SectionKind::Tube, v, labels),
+    ),
+    section(
+        SectionKind::RectangularHollow,
+        &[WIDTH, HEIGHT, WALL],
+        &[
+            dimension("width", "b"),
+            dimension("height", "h"),
+            dimension("wall_thickness", "t"),
+        ],
+        &["t < b/2", "t < h/2"],
+        |v| kind_values(SectionKind::RectangularHollow, v),
+        |v, labels| kind_svg(SectionKind::RectangularHollow, v, labels),
+    ),
+    section(
+        SectionKind::IProfile,
+        &[WIDTH, HEIGHT, WEB, FLANGE],
+        PROFILE,
+        &["tw ≤ b", "2·tf < h"],
+        |v| kind_values(SectionKind::IProfile, v),
+        |v, labels| kind_svg(SectionKind::IProfile, v, labels),
+    ),
+    section(
+        SectionKind::TProfile,
+        &[WIDTH, HEIGHT, WEB, FLANGE],
+        PROFILE,
+        &["tw ≤ b", "tf < h"],
+        |v| kind_values(SectionKind::TProfile, v),
+        |v, labels| kind_svg(SectionKind::TProfile, v, labels),
+    ),
+    section(
+        SectionKind::LProfile,
+        &[WIDTH, HEIGHT, WALL],
+        &[
+            dimension("width", "b"),
+            dimension("height", "h"),
+            dimension("thickness", "t"),
+        ],
+        &["t < b", "t < h"],
+        |v| kind_values(SectionKind::LProfile, v),
+        |v, labels| kind_svg(SectionKind::LProfile, v, labels),
+    ),
+    section(
+        SectionKind::CProfile,
+        &[WIDTH, HEIGHT, WEB, FLANGE],
+        PROFILE,
+        &["tw ≤ b", "2·tf < h"],
+        |v| kind_values(SectionKind::CProfile, v),
+        |v, labels| kind_svg(SectionKind::CProfile, v, labels),
+    ),
+];
+
+pub static SECTIONS: CalculatorGroup = CalculatorGroup {
+    card: CardInfo {
+        id: "section",
+        title_key: "section_calculator",
+        path: PATH,
+    },
+    kinds: &SECTION_KINDS,
+    initial: SectionKind::IProfile as usize,
+};
+
+#[allow(non_snake_case)]
+#[component]
+pub fn SectionPropertiesCard() -> impl IntoView {
+    view! { <CalculatorCard calculator=SECTIONS /> }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cards::calculator::AnyUnit;
+
+    #[test]
+    fn test_section_kinds() {
+        for (kind, calculator) in SectionKind::ALL.into_iter().zip(&SECTION_KINDS) {
+            assert_eq!(calculator.id, kind.id());
+            assert_eq!(calculator.title_key, kind.i18n_key());
+        }
+
+        // A 100 × 200 mm rectangle
+        let millimetres = |value| AnyQuantity::from(Length::new(value, LengthUnit::Millimetre));
+        let values = (SECTION_KINDS[0].compute)(&[millimetres(100.0), millimetres(200.0)]).unwrap();
+        assert_eq!(values.len(), SECTION_PROPERTIES.len());
+        let area = values[0].value_in(AnyUnit::Area(AreaUnit::SquareMillimetre));
+        assert!((area - 20_000.0).abs() < 1e-6);
+        let ix = values[3].value_in(AnyUnit::SecondMomentOfArea(
+            SecondMomentOfAreaUnit::MillimetreToTheFourth,
+        ));
+        assert!((ix - 100.0 * 200.0f64.powi(3) / 12.0).abs() < 1e-3);
+        assert!((values[11].length().si() - 0.1).abs() < 1e-12);
+    }
+}
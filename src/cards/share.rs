@@ -0,0 +1,151 @@
+//! Card state in the query of a link, so colleagues can open the exact same calculation.
+//!
+//! Numbers are written in Rust notation, whatever the language of the user sharing the
+//! link, and restored in the notation of the user opening it.
+
+use crate::calculations::units::Unit;
+use crate::cards::export::CopyToast;
+use crate::cards::workspace::{SavedCard, WorkspaceStore};
+use crate::dom::clipboard::link_to_clipboard;
+use crate::locales::i18n::I18n;
+use crate::locales::numbers::NumberFormat;
+use leptos::prelude::*;
+use leptos_router::hooks::{use_location, use_query_map};
+use leptos_router::params::ParamsMap;
+
+/// A card as it is known outside its page, such as in links.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CardInfo {
+    pub id: &'static str,
+    pub title_key: &'static str,
+    /// Path of the page holding only this card.
+    pub path: &'static str,
+}
+
+/// Restores a card from the query of the page when it shows only the card at `path`:
+/// right away, so the server renders the shared state, and again whenever a link to
+/// another state of the card is followed. Cards on an overview page start empty.
+pub fn restore_shared(path: &'static str, restore: impl Fn(&ParamsMap) + 'static) {
+    let location = use_location();
+    let query = use_query_map();
+    let shown_alone = move || location.pathname.get_untracked() == path;
+    if shown_alone() {
+        restore(&query.get_untracked());
+    }
+    Effect::watch(
+        move || query.get(),
+        move |query, _, _| {
+            if shown_alone() {
+                restore(query);
+            }
+        },
+        false,
+    );
+}
+
+/// Writes an entered number for a link, or `None` while it is not a number. A
+/// tolerance is left out; see [`link_tolerance`].
+pub fn link_number(text: &str, numbers: NumberFormat) -> Option<String> {
+    numbers
+        .parse_toleranced(text)
+        .map(|(number, _)| number.to_string())
+}
+
+/// Writes the tolerance of an entered number for a link under the key of the number
+/// followed by `_tol`, or `None` without one.
+pub fn link_tolerance(text: &str, numbers: NumberFormat) -> Option<String> {
+    numbers
+        .parse_toleranced(text)
+        .filter(|(_, tolerance)| *tolerance > 0.0)
+        .map(|(_, tolerance)| tolerance.to_string())
+}
+
+/// Reads a number written by [`link_number`].
+pub fn shared_number(query: &ParamsMap, key: &str) -> Option<f64> {
+    query.get_str(key)?.parse().ok()
+}
+
+/// Fills an input with the number under `key` and its tolerance, or empties it if the
+/// link has none.
+pub fn restore_number(
+    query: &ParamsMap,
+    key: &str,
+    value: RwSignal<String>,
+    numbers: NumberFormat,
+) {
+    let tolerance = shared_number(query, &format!("{key}_tol")).unwrap_or(0.0);
+    let number = shared_number(query, key);
+    value.set(number.map_or_else(String::new, |number| {
+        numbers.format_toleranced(number, tolerance)
+    }));
+}
+
+/// Selects the unit with the symbol under `key`, if the link has one.
+pub fn restore_unit<U: Unit>(query: &ParamsMap, key: &str, unit: RwSignal<U>) {
+    if let Some(restored) = query.get_str(key).and_then(U::from_symbol) {
+        unit.set(restored);
+    }
+}
+
+/// Link to the page of a card with its state in the query.
+pub fn card_link(path: &str, query: &ParamsMap) -> String {
+    format!("{path}{}", query.to_query_string())
+}
+
+/// Direct link to a card with its current state, with buttons copying it and saving
+/// it to the active workspace. Whether the copy worked is told on `toast`.
+#[allow(non_snake_case)]
+#[component]
+pub fn CardLink(
+    card: CardInfo,
+    #[prop(into)] state: Signal<ParamsMap>,
+    toast: CopyToast,
+) -> impl IntoView {
+    let i18n = use_context::<Memo<I18n>>().expect("I18n context not found");
+    let workspaces = use_context::<WorkspaceStore>();
+
+    let link = Signal::derive(move || card_link(card.path, &state.get()));
+    let save_to_workspace = move |_| {
+        let Some(workspaces) = workspaces else {
+            return;
+        };
+        let new_name = i18n.get_untracked().t("workspace_default_name").to_string();
+        let saved = SavedCard::new(card, state.get_untracked());
+        workspaces.update(|workspaces| workspaces.save_card(saved, &new_name));
+    };
+
+    view! {
+        <a href=link>{move || i18n.get().t("card_direct_link").to_string()}</a>
+        <button on:click=move |_| {
+            link_to_clipboard(&link.get_untracked(), move |copied| toast.show(copied))
+        }>
+            {move || i18n.get().t("card_copy_link").to_string()}
+        </button>
+        <button on:click=save_to_workspace>
+            {move || i18n.get().t("card_save_to_workspace").to_string()}
+        </button>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_link_number() {
+        let en = NumberFormat::new("en");
+        let nl = NumberFormat::new("nl");
+        assert_eq!(link_number("1.234,5", nl), Some("1234.5".to_string()));
+        assert_eq!(link_number("1,234.5", en), Some("1234.5".to_string()));
+        assert_eq!(link_number("1,5e-3", nl), Some("0.0015".to_string()));
+        assert_eq!(link_number("", nl), None);
+        assert_eq!(link_number("abc", nl), None);
+        assert_eq!(link_number("2,5 ± 0,1", nl), Some("2.5".to_string()));
+        assert_eq!(link_tolerance("2,5 ± 0,1", nl), Some("0.1".to_string()));
+        assert_eq!(link_tolerance("2,5", nl), None);
+
+        // A link shared in English opens with the same value in Dutch
+        let shared: f64 = link_number("0.1", en).unwrap().parse().unwrap();
+        assert_eq!(nl.format_exact(shared), "0,1");
+    }
+}
@@ -0,0 +1,59 @@
+use crate::calculations::errors::CalculationError;
+use crate::calculations::units::Unit;
+use crate::cards::validation::{InputMessage, NumberInput};
+use leptos::prelude::*;
+
+/// Drop-down to pick one of the units of a dimension.
+#[allow(non_snake_case)]
+#[component]
+pub fn UnitSelect<U: Unit>(unit: RwSignal<U>) -> impl IntoView {
+    view! {
+        <select
+            class="unit-select"
+            prop:value=move || unit.get().symbol()
+            on:change=move |ev| {
+                if let Some(selected) = U::from_symbol(&event_target_value(&ev)) {
+                    unit.set(selected);
+                }
+            }
+        >
+            {U::ALL
+                .iter()
+                .map(|option| view! { <option value=option.symbol()>{option.symbol()}</option> })
+                .collect_view()}
+        </select>
+    }
+}
+
+/// Text input for a value together with the unit it is entered in, and the
+/// message of its check when the value is invalid.
+#[allow(non_snake_case)]
+#[component]
+pub fn QuantityInput<U: Unit>(
+    value: RwSignal<String>,
+    unit: RwSignal<U>,
+    /// Id of the text input.
+    id: String,
+    #[prop(optional)] placeholder: &'static str,
+    #[prop(into)] label_key: Option<&'static str>,
+    check: Signal<Option<CalculationError>>,
+) -> impl IntoView {
+    view! {
+        <div class="quantity-input">
+            <NumberInput
+                value=value
+                id=id.clone()
+                placeholder=placeholder
+                label_key=label_key
+                check=check
+            />
+            <UnitSelect unit=unit />
+        </div>
+        <InputMessage value=value input_id=id check=check />
+    }
+}
+
+/// Formats a unit symbol to follow a number in a LaTeX formula.
+pub fn latex_unit(unit: impl Unit) -> String {
+    format!(r"\,\text{{{}}}", unit.symbol())
+}
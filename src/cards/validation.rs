@@ -0,0 +1,263 @@
+use crate::calculations::errors::*;
+use crate::calculations::tolerance::Toleranced;
+use crate::calculations::units::Unit;
+use crate::cards::errors::error_id;
+use crate::locales::i18n::I18n;
+use crate::locales::numbers::NumberFormat;
+use leptos::prelude::*;
+
+/// Constraint an input must meet before a card can calculate.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Rule {
+    /// Any finite number, such as a coordinate.
+    Finite,
+    /// A number greater than zero.
+    Positive,
+    /// A number of at least zero, such as the top diameter of a frustum.
+    NonNegative,
+    /// A number strictly between the bounds, such as an angle between 0 and π.
+    Between(f64, f64),
+    /// A whole number within the bounds, such as the number of sides.
+    Count { min: u32, max: u32 },
+}
+
+impl Rule {
+    /// Checks a value in SI units against the rule.
+    pub fn check(self, parameter: &'static str, value: f64) -> Result<(), CalculationError> {
+        if !value.is_finite() {
+            return Err(CalculationError::NotFinite { parameter, value });
+        }
+        match self {
+            Rule::Finite => Ok(()),
+            Rule::Positive => ensure_positive(parameter, value),
+            Rule::NonNegative if value >= 0.0 => Ok(()),
+            Rule::Between(min, max) if value > min && value < max => Ok(()),
+            Rule::Count { min, max }
+                if value.fract() == 0.0 && (min as f64..=max as f64).contains(&value) =>
+            {
+                Ok(())
+            }
+            Rule::NonNegative | Rule::Between(..) | Rule::Count { .. } => {
+                Err(CalculationError::OutOfDomain { parameter, value })
+            }
+        }
+    }
+}
+
+/// Parses the text of an input; text that is not a number is reported as not finite.
+pub fn parse_input(
+    parameter: &'static str,
+    text: &str,
+    numbers: NumberFormat,
+) -> Result<f64, CalculationError> {
+    numbers.parse(text).ok_or(CalculationError::NotFinite {
+        parameter,
+        value: f64::NAN,
+    })
+}
+
+/// Parses the text of an input that may hold a tolerance, like `100 ± 0.2`.
+pub fn parse_toleranced(
+    parameter: &'static str,
+    text: &str,
+    numbers: NumberFormat,
+) -> Result<Toleranced, CalculationError> {
+    numbers
+        .parse_toleranced(text)
+        .map(|(nominal, tolerance)| Toleranced::new(nominal, tolerance))
+        .ok_or(CalculationError::NotFinite {
+            parameter,
+            value: f64::NAN,
+        })
+}
+
+/// Parses the text of an input and checks the number against the rule.
+pub fn check_text(
+    parameter: &'static str,
+    text: &str,
+    rule: Rule,
+    numbers: NumberFormat,
+) -> Result<f64, CalculationError> {
+    let number = parse_input(parameter, text, numbers)?;
+    rule.check(parameter, number)?;
+    Ok(number)
+}
+
+/// Validates an input entered in `unit` while the user types.
+pub fn check_quantity<U: Unit>(
+    parameter: &'static str,
+    value: RwSignal<String>,
+    unit: RwSignal<U>,
+    rule: Rule,
+) -> Signal<Option<CalculationError>> {
+    let i18n = use_context::<Memo<I18n>>().expect("I18n context not found");
+    Signal::derive(move || {
+        parse_input(parameter, &value.get(), i18n.get().number_format())
+            .and_then(|number| rule.check(parameter, unit.get().to_si(number)))
+            .err()
+    })
+}
+
+/// Validates an input entered in `unit` that may hold a tolerance while the user
+/// types; the rule applies to the nominal value.
+pub fn check_toleranced_quantity<U: Unit>(
+    parameter: &'static str,
+    value: RwSignal<String>,
+    unit: RwSignal<U>,
+    rule: Rule,
+) -> Signal<Option<CalculationError>> {
+    let i18n = use_context::<Memo<I18n>>().expect("I18n context not found");
+    Signal::derive(move || {
+        parse_toleranced(parameter, &value.get(), i18n.get().number_format())
+            .and_then(|number| rule.check(parameter, unit.get().to_si(number.nominal)))
+            .err()
+    })
+}
+
+/// Validates a plain number input while the user types.
+pub fn check_number(
+    parameter: &'static str,
+    value: RwSignal<String>,
+    rule: Rule,
+) -> Signal<Option<CalculationError>> {
+    let i18n = use_context::<Memo<I18n>>().expect("I18n context not found");
+    Signal::derive(move || {
+        check_text(parameter, &value.get(), rule, i18n.get().number_format()).err()
+    })
+}
+
+/// The check of an input, ignored while it is still empty so untouched fields are not flagged.
+fn visible_error(
+    value: RwSignal<String>,
+    check: Signal<Option<CalculationError>>,
+) -> Option<CalculationError> {
+    if value.get().trim().is_empty() {
+        return None;
+    }
+    check.get()
+}
+
+/// Id of the [`InputMessage`] of the input with id `input`.
+pub fn message_id(input: &str) -> String {
+    format!("{input}-message")
+}
+
+/// Text input that is marked `aria-invalid` when its check fails and described by its
+/// [`InputMessage`] and [`FieldError`](crate::cards::errors::FieldError). It is named
+/// by the text under `label_key`, or by its placeholder without one.
+#[allow(non_snake_case)]
+#[component]
+pub fn NumberInput(
+    value: RwSignal<String>,
+    /// Id of the input, which the ids of its messages are made from.
+    id: String,
+    #[prop(optional)] placeholder: &'static str,
+    #[prop(into)] label_key: Option<&'static str>,
+    check: Signal<Option<CalculationError>>,
+) -> impl IntoView {
+    let i18n = use_context::<Memo<I18n>>().expect("I18n context not found");
+
+    // Rewrite the number in the new notation when the language changes, so it keeps its value
+    Effect::new(move |previous: Option<NumberFormat>| {
+        let numbers = i18n.get().number_format();
+        if let Some(previous) = previous.filter(|previous| *previous != numbers) {
+            value.update(|text| {
+                if let Some((nominal, tolerance)) = previous.parse_toleranced(text) {
+                    *text = numbers.format_toleranced(nominal, tolerance);
+                }
+            });
+        }
+        numbers
+    });
+
+    view! {
+        <input
+            type="text"
+            inputmode="decimal"
+            id=id.clone()
+            placeholder=placeholder
+            aria-label=move || match label_key {
+                Some(key) => i18n.get().t(key).to_string(),
+                None => placeholder.to_string(),
+            }
+            aria-describedby=format!("{} {}", message_id(&id), error_id(&id))
+            aria-invalid=move || visible_error(value, check).is_some().to_string()
+            bind:value=value
+        />
+    }
+}
+
+/// Localized message below an input whose check fails.
+#[allow(non_snake_case)]
+#[component]
+pub fn InputMessage(
+    value: RwSignal<String>,
+    /// Id of the input the message describes.
+    input_id: String,
+    check: Signal<Option<CalculationError>>,
+) -> impl IntoView {
+    let i18n = use_context::<Memo<I18n>>().expect("I18n context not found");
+
+    let message =
+        move || visible_error(value, check).map(|err| i18n.get().t(err.i18n_key()).to_string());
+
+    view! {
+        <Show when=move || message().is_some()>
+            <p id=message_id(&input_id) class="card__inputs__error" role="alert">{message}</p>
+        </Show>
+    }
+}
+
+/// Whether every check passes, for enabling the calculate button.
+pub fn all_valid(checks: &[Signal<Option<CalculationError>>]) -> bool {
+    checks.iter().all(|check| check.get().is_none())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_rules() {
+        assert!(Rule::Positive.check("width", 1.0).is_ok());
+        assert!(matches!(
+            Rule::Positive.check("width", -1.0),
+            Err(CalculationError::NonPositive { .. })
+        ));
+        assert!(Rule::Finite.check("x", -1.0).is_ok());
+        assert!(Rule::NonNegative.check("top_diameter", 0.0).is_ok());
+        assert!(matches!(
+            Rule::NonNegative.check("top_diameter", -1.0),
+            Err(CalculationError::OutOfDomain { .. })
+        ));
+        assert!(Rule::Between(0.0, PI).check("angle", PI / 2.0).is_ok());
+        assert!(matches!(
+            Rule::Between(0.0, PI).check("angle", PI),
+            Err(CalculationError::OutOfDomain { .. })
+        ));
+        let sides = Rule::Count { min: 3, max: 1000 };
+        assert!(sides.check("sides", 6.0).is_ok());
+        assert!(sides.check("sides", 2.0).is_err());
+        assert!(sides.check("sides", 4.5).is_err());
+        assert!(sides.check("sides", 4e9).is_err());
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let nl = NumberFormat::new("nl");
+        assert_eq!(parse_input("width", " 2,5 ", nl), Ok(2.5));
+        assert!(matches!(
+            parse_input("width", "abc", nl),
+            Err(CalculationError::NotFinite {
+                parameter: "width",
+                ..
+            })
+        ));
+        assert_eq!(
+            parse_toleranced("width", "2,5 ± 0,1", nl),
+            Ok(Toleranced::new(2.5, 0.1))
+        );
+        assert!(parse_toleranced("width", "2,5 ±", nl).is_err());
+    }
+}
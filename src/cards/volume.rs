@@ -0,0 +1,821 @@
+use crate::calculations::errors::*;
+use crate::calculations::formula::Step;
+use crate::calculations::shapes_2d::*;
+use crate::calculations::shapes_3d::*;
+use crate::calculations::units::*;
+use crate::cards::area::{regular_polygon_points, MAX_DRAWN_SIDES};
+use crate::cards::calculator::{
+    metres, AnyQuantity, Calculator, CalculatorCard, CalculatorGroup, CalculatorInput,
+    CalculatorOutput, Measure, Scale, SolveMode,
+};
+use crate::cards::drawing::{Dimension, Drawing, Labels, Shape, EMPTY_SVG, RADIUS_ANGLE};
+use crate::cards::share::CardInfo;
+use crate::cards::validation::Rule;
+use leptos::prelude::*;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum SolidKind {
+    Cuboid,
+    Cylinder,
+    Sphere,
+    Cone,
+    Frustum,
+    HollowTube,
+    PolygonPrism,
+}
+
+impl SolidKind {
+    pub const fn id(&self) -> &'static str {
+        match self {
+            SolidKind::Cuboid => "cuboid",
+            SolidKind::Cylinder => "cylinder",
+            SolidKind::Sphere => "sphere",
+            SolidKind::Cone => "cone",
+            SolidKind::Frustum => "frustum",
+            SolidKind::HollowTube => "tube",
+            SolidKind::PolygonPrism => "prism",
+        }
+    }
+
+    pub const fn i18n_key(&self) -> &'static str {
+        match self {
+            SolidKind::Cuboid => "solid_cuboid",
+            SolidKind::Cylinder => "solid_cylinder",
+            SolidKind::Sphere => "solid_sphere",
+            SolidKind::Cone => "solid_cone",
+            SolidKind::Frustum => "solid_frustum",
+            SolidKind::HollowTube => "solid_tube",
+            SolidKind::PolygonPrism => "solid_prism",
+        }
+    }
+
+    /// The inputs this kind of solid needs, those of its calculator: its dimensions
+    /// followed by the density.
+    pub fn inputs(&self) -> &'static [CalculatorInput] {
+        SOLID_KINDS[*self as usize].inputs
+    }
+}
+
+/// Dimensions entered on the solid card, unused ones are ignored.
+#[derive(Clone, Copy, Default)]
+pub struct SolidDimensions {
+    pub l: f64,
+    pub w: f64,
+    pub h: f64,
+    pub d: f64,
+    pub d_large: f64,
+    pub t: f64,
+    /// The number of sides as entered, read by [`RegularPolygon::sides`].
+    pub n: f64,
+    pub s: f64,
+}
+
+impl SolidDimensions {
+    /// The dimensions of a kind of solid in `unit` from its values, in the order of
+    /// [`SolidKind::inputs`].
+    pub fn from_inputs(kind: SolidKind, values: &[AnyQuantity], unit: LengthUnit) -> Self {
+        let mut dims = SolidDimensions::default();
+        for (input, value) in kind.inputs().iter().zip(values) {
+            let dimension = match input.symbol {
+                "n" => {
+                    dims.n = value.count();
+                    continue;
+                }
+                // The density is no dimension
+                "ρ" => continue,
+                "l" => &mut dims.l,
+                "w" => &mut dims.w,
+                "h" => &mut dims.h,
+                "d" => &mut dims.d,
+                "D" => &mut dims.d_large,
+                "t" => &mut dims.t,
+                _ => &mut dims.s,
+            };
+            *dimension = value.length().value_in(unit);
+        }
+        dims
+    }
+}
+
+/// Builds a solid from dimensions in one unit, the results come out in that same unit.
+pub fn build_solid(
+    kind: SolidKind,
+    dims: SolidDimensions,
+) -> Result<Box<dyn Geometric3D>, CalculationError> {
+    let length = Length::from_si;
+    let solid: Box<dyn Geometric3D> = match kind {
+        SolidKind::Cuboid => Box::new(Cuboid::new(length(dims.l), length(dims.w), length(dims.h))?),
+        SolidKind::Cylinder => Box::new(Cylinder::from_diameter(length(dims.d), length(dims.h))?),
+        SolidKind::Sphere => Box::new(Sphere::from_diameter(length(dims.d))?),
+        SolidKind::Cone => Box::new(Cone::from_diameter(length(dims.d), length(dims.h))?),
+        SolidKind::Frustum => Box::new(Frustum::from_diameters(
+            length(dims.d_large),
+            length(dims.d),
+            length(dims.h),
+        )?),
+        SolidKind::HollowTube => Box::new(HollowTube::new(
+            length(dims.d),
+            length(dims.t),
+            length(dims.l),
+        )?),
+        SolidKind::PolygonPrism => {
+            let base = RegularPolygon::new(RegularPolygon::sides(dims.n)?, length(dims.s))?;
+            Box::new(Prism::new(base, length(dims.h))?)
+        }
+    };
+    ensure_finite("V", solid.volume())?;
+    ensure_finite("S", solid.surface_area())?;
+    Ok(solid)
+}
+
+/// Draws the front elevation of a solid with its main dimensions.
+pub fn create_solid_svg(kind: SolidKind, dims: SolidDimensions, labels: &Labels) -> String {
+    if build_solid(kind, dims).is_err() {
+        return EMPTY_SVG.to_string();
+    }
+    let SolidDimensions {
+        l,
+        w: _,
+        h,
+        d,
+        d_large,
+        t,
+        n,
+        s,
+    } = dims;
+    let width = |from: (f64, f64), to: (f64, f64), value: f64| Dimension::Horizontal {
+        from,
+        to,
+        label: labels.length(value),
+    };
+    let height = |value: f64| Dimension::Vertical {
+        from: (0.0, value),
+        to: (0.0, 0.0),
+        label: labels.length(value),
+    };
+    let drawing = Drawing::new();
+    let drawing = match kind {
+        SolidKind::Cuboid => drawing
+            .with_shape("shape", Shape::rectangle(0.0, 0.0, l, h))
+            .with_dimension(width((0.0, 0.0), (l, 0.0), l))
+            .with_dimension(height(h)),
+        SolidKind::Cylinder => drawing
+            .with_shape("shape", Shape::rectangle(0.0, 0.0, d, h))
+            .with_dimension(Dimension::Horizontal {
+                from: (0.0, 0.0),
+                to: (d, 0.0),
+                label: labels.diameter(d),
+            })
+            .with_dimension(height(h)),
+        SolidKind::Sphere => drawing
+            .with_shape(
+                "shape",
+                Shape::Circle {
+                    center: (0.0, 0.0),
+                    radius: d / 2.0,
+                },
+            )
+            .with_dimension(Dimension::Diameter {
+                center: (0.0, 0.0),
+                radius: d / 2.0,
+                angle: RADIUS_ANGLE,
+                label: labels.diameter(d),
+            }),
+        SolidKind::Cone => drawing
+            .with_shape(
+                "shape",
+                Shape::Polygon(vec![(0.0, 0.0), (d, 0.0), (d / 2.0, h)]),
+            )
+            .with_dimension(Dimension::Horizontal {
+                from: (0.0, 0.0),
+                to: (d, 0.0),
+                label: labels.diameter(d),
+            })
+            .with_dimension(Dimension::Vertical {
+                from: (d / 2.0, h),
+                to: (0.0, 0.0),
+                label: labels.length(h),
+            }),
+        SolidKind::Frustum => {
+            let inset = (d_large - d) / 2.0;
+            drawing
+                .with_shape(
+                    "shape",
+                    Shape::Polygon(vec![(0.0, 0.0), (d_large, 0.0), (inset + d, h), (inset, h)]),
+                )
+                .with_dimension(Dimension::Horizontal {
+                    from: (0.0, 0.0),
+                    to: (d_large, 0.0),
+                    label: labels.diameter(d_large),
+                })
+                .with_dimension(Dimension::Horizontal {
+                    from: (inset + d, h),
+                    to: (inset, h),
+                    label: labels.diameter(d),
+                })
+                .with_dimension(Dimension::Vertical {
+                    from: (inset, h),
+                    to: (0.0, 0.0),
+                    label: labels.length(h),
+                })
+        }
+        // Lying down, with the inner wall dashed
+        SolidKind::HollowTube => drawing
+            .with_shape("shape", Shape::rectangle(0.0, 0.0, l, d))
+            .with_shape("hidden", Shape::Line((0.0, t), (l, t)))
+            .with_shape("hidden", Shape::Line((0.0, d - t), (l, d - t)))
+            .with_dimension(width((0.0, 0.0), (l, 0.0), l))
+            .with_dimension(Dimension::Vertical {
+                from: (0.0, d),
+                to: (0.0, 0.0),
+                label: labels.diameter(d),
+            }),
+        // The elevation with an edge at every corner in front, and the base beside it
+        SolidKind::PolygonPrism => {
+            // A whole number of sides, as `build_solid` checked
+            let sides = n as u32;
+            if sides > MAX_DRAWN_SIDES {
+                return EMPTY_SVG.to_string();
+            }
+            let base = regular_polygon_points(&RegularPolygon {
+                sides,
+                side_length: s,
+            });
+            let left = base.iter().map(|p| p.0).fold(f64::INFINITY, f64::min);
+            let right = base.iter().map(|p| p.0).fold(f64::NEG_INFINITY, f64::max);
+            let bottom = base.iter().map(|p| p.1).fold(f64::INFINITY, f64::min);
+            let across = right - left;
+            let drawing = base
+                .iter()
+                .filter(|(x, y)| *y < 0.0 && *x > left && *x < right)
+                .fold(drawing, |drawing, (x, _)| {
+                    drawing.with_shape("shape", Shape::Line((x - left, 0.0), (x - left, h)))
+                })
+                .with_shape("shape", Shape::rectangle(0.0, 0.0, across, h))
+                .with_dimension(height(h));
+            let (dx, dy) = (1.5 * across - left, -bottom);
+            let base: Vec<(f64, f64)> = base.into_iter().map(|(x, y)| (x + dx, y + dy)).collect();
+            let side = Dimension::Aligned {
+                from: base[0],
+                to: base[1],
+                label: labels.length(s),
+            };
+            drawing
+                .with_shape("shape", Shape::Polygon(base))
+                .with_dimension(side)
+        }
+    };
+    drawing.to_svg()
+}
+
+/// A positive dimension of a solid.
+const fn dimension(parameter: &'static str, symbol: &'static str) -> CalculatorInput {
+    CalculatorInput {
+        parameter,
+        symbol,
+        measure: Measure::Length,
+        rule: Rule::Positive,
+    }
+}
+
+/// The density, the last input of every solid.
+const DENSITY: CalculatorInput = CalculatorInput {
+    parameter: "density",
+    symbol: "ρ",
+    measure: Measure::Density,
+    rule: Rule::Positive,
+};
+
+/// The top diameter of a frustum, which may be 0 to make it a cone.
+const TOP_DIAMETER: CalculatorInput = CalculatorInput {
+    parameter: "top_diameter",
+    symbol: "d",
+    measure: Measure::Length,
+    rule: Rule::NonNegative,
+};
+
+/// The number of sides of the base of a prism.
+const SIDES: CalculatorInput = CalculatorInput {
+    parameter: "sides",
+    symbol: "n",
+    measure: Measure::Count,
+    rule: Rule::Count {
+        min: 3,
+        max: MAX_SIDES,
+    },
+};
+
+/// The volume, entered to solve a dimension from it.
+const VOLUME: CalculatorInput = CalculatorInput {
+    parameter: "volume",
+    symbol: "V",
+    measure: Measure::Volume,
+    rule: Rule::Positive,
+};
+
+/// The surface area, entered to solve the diameter of a sphere from it.
+const SURFACE_AREA: CalculatorInput = CalculatorInput {
+    parameter: "surface_area",
+    symbol: "S",
+    measure: Measure::Area,
+    rule: Rule::Positive,
+};
+
+const VARIABLES: &[(&str, &str)] = &[
+    ("V", "solid_volume"),
+    ("S", "solid_surface_area"),
+    ("m", "solid_mass"),
+    ("ρ", "solid_density"),
+    ("l, w, h", "solid_length_width_height"),
+    ("D, d", "section_diameter"),
+    ("t", "section_wall_thickness"),
+    ("n, s", "solid_polygon_sides"),
+];
+
+const OUTPUTS: &[CalculatorOutput] = &[
+    CalculatorOutput {
+        symbol: "V",
+        measure: Measure::Volume,
+        label_key: Some("solid_volume_result"),
+    },
+    CalculatorOutput {
+        symbol: "S",
+        measure: Measure::Area,
+        label_key: Some("solid_surface_area_result"),
+    },
+    CalculatorOutput {
+        symbol: "m",
+        measure: Measure::Mass,
+        label_key: Some("solid_mass_result"),
+    },
+];
+
+/// The volume, surface area and mass of a kind of solid from its values, in the
+/// order of [`SolidKind::inputs`].
+fn kind_values(
+    kind: SolidKind,
+    values: &[AnyQuantity],
+) -> Result<Vec<AnyQuantity>, CalculationError> {
+    let solid = build_solid(
+        kind,
+        SolidDimensions::from_inputs(kind, values, LengthUnit::Metre),
+    )?;
+    let density = values[kind.inputs().len() - 1].density();
+    ensure_positive("density", density.si())?;
+    Ok(vec![
+        solid.volume_quantity().into(),
+        solid.surface_area_quantity().into(),
+        solid.mass_quantity(density).into(),
+    ])
+}
+
+fn kind_svg(kind: SolidKind, values: &[AnyQuantity], labels: &Labels) -> String {
+    let dims = SolidDimensions::from_inputs(kind, values, labels.unit());
+    create_solid_svg(kind, dims, labels)
+}
+
+const CUBOID_VOLUME: Step = Step::new("V", r"l \cdot w \cdot h", r"#l \cdot #w \cdot #h");
+const CYLINDER_VOLUME: Step = Step::new(
+    "V",
+    r"\frac{\pi}{4} d^2 h",
+    r"\frac{\pi}{4} \cdot (#d)^2 \cdot #h",
+);
+const SPHERE_VOLUME: Step = Step::new("V", r"\frac{\pi}{6} d^3", r"\frac{\pi}{6} \cdot (#d)^3");
+const CONE_VOLUME: Step = Step::new(
+    "V",
+    r"\frac{\pi}{12} d^2 h",
+    r"\frac{\pi}{12} \cdot (#d)^2 \cdot #h",
+);
+/// Volume of a frustum, where `D` and `d` are the diameters of its bases.
+const FRUSTUM_VOLUME: Step = Step::new(
+    "V",
+    r"\frac{\pi h}{12} (D^2 + D d + d^2)",
+    r"\frac{\pi \cdot #h}{12} ((#D)^2 + #D \cdot #d + (#d)^2)",
+);
+const TUBE_VOLUME: Step = Step::new(
+    "V",
+    r"\frac{\pi}{4} (d^2 - (d - 2t)^2) \, l",
+    r"\frac{\pi}{4} ((#d)^2 - (#d - 2 \cdot #t)^2) \cdot #l",
+);
+const PRISM_VOLUME: Step = Step::new(
+    "V",
+    r"\frac{n s^2}{4 \tan(\pi / n)} h",
+    r"\frac{#n \cdot (#s)^2}{4 \tan(\pi / #n)} \cdot #h",
+);
+/// The mass from the volume, the last step of every solid.
+const MASS: Step = Step::new("m", r"\rho V", r"#ρ \cdot #V");
+
+const PATH: &str = "/cards/volume/solid";
+
+/// The calculator of one kind of solid on the solid card.
+const fn solid(
+    kind: SolidKind,
+    inputs: &'static [CalculatorInput],
+    constraints: &'static [&'static str],
+    formula: &'static [Step],
+    compute: fn(&[AnyQuantity]) -> Result<Vec<AnyQuantity>, CalculationError>,
+    drawing: fn(&[AnyQuantity], &Labels) -> String,
+    solve_modes: &'static [SolveMode],
+) -> Calculator {
+    Calculator {
+        id: kind.id(),
+        path: PATH,
+        title_key: kind.i18n_key(),
+        calculate_key: "solid_calculate",
+        variables: VARIABLES,
+        inputs,
+        constraints,
+        outputs: OUTPUTS,
+        formula,
+        compute,
+        drawing: Some(drawing),
+        solve_modes,
+        scale: Scale::Metre,
+        parts: None,
+    }
+}
+
+/// The kinds of solid, in the order of the variants of [`SolidKind`]. The formulas give the
+/// volume in terms of the inputs, followed by the mass.
+pub static SOLID_KINDS: [Calculator; 7] = [
+    solid(
+        SolidKind::Cuboid,
+        &[
+            dimension("length", "l"),
+            dimension("width", "w"),
+            dimension("height", "h"),
+            DENSITY,
+        ],
+        &[],
+        &[CUBOID_VOLUME, MASS],
+        |v| kind_values(SolidKind::Cuboid, v),
+        |v, labels| kind_svg(SolidKind::Cuboid, v, labels),
+        &[SolveMode {
+            id: "height",
+            label_key: "solve_solid_height",
+            variables: &[],
+            inputs: &[
+                VOLUME,
+                dimension("length", "l"),
+                dimension("width", "w"),
+                DENSITY,
+            ],
+            unknowns: &["h"],
+            solve: |v| {
+                let cuboid = Cuboid::from_volume(v[0].volume(), v[1].length(), v[2].length())?;
+                let mut dimensions = metres(&[cuboid.length, cuboid.width, cuboid.height]);
+                dimensions.push(v[3]);
+                Ok(dimensions)
+            },
+            formula: &[
+                Step::new("h", r"\frac{V}{l \cdot w}", r"\frac{#V}{#l \cdot #w}"),
+                MASS,
+            ],
+        }],
+    ),
+    solid(
+        SolidKind::Cylinder,
+        &[
+            dimension("diameter", "d"),
+            dimension("height", "h"),
+            DENSITY,
+        ],
+        &[],
+        &[CYLINDER_VOLUME, MASS],
+        |v| kind_values(SolidKind::Cylinder, v),
+        |v, labels| kind_svg(SolidKind::Cylinder, v, labels),
+        &[
+            SolveMode {
+                id: "height",
+                label_key: "solve_solid_height",
+                variables: &[],
+                inputs: &[VOLUME, dimension("diameter", "d"), DENSITY],
+                unknowns: &["h"],
+                solve: |v| {
+                    let cylinder =
+                        Cylinder::from_volume_and_diameter(v[0].volume(), v[1].length())?;
+                    let mut dimensions = metres(&[2.0 * cylinder.radius, cylinder.height]);
+                    dimensions.push(v[2]);
+                    Ok(dimensions)
+                },
+                formula: &[
+                    Step::new(
+                        "h",
+                        r"\frac{4 V}{\pi d^2}",
+                        r"\frac{4 \cdot #V}{\pi \cdot (#d)^2}",
+                    ),
+                    MASS,
+                ],
+            },
+            SolveMode {
+                id: "diameter",
+                label_key: "solve_solid_diameter",
+                variables: &[],
+                inputs: &[VOLUME, dimension("height", "h"), DENSITY],
+                unknowns: &["d"],
+                solve: |v| {
+                    let cylinder = Cylinder::from_volume_and_height(v[0].volume(), v[1].length())?;
+                    let mut dimensions = metres(&[2.0 * cylinder.radius, cylinder.height]);
+                    dimensions.push(v[2]);
+                    Ok(dimensions)
+                },
+                formula: &[
+                    Step::new(
+                        "d",
+                        r"\sqrt{\frac{4 V}{\pi h}}",
+                        r"\sqrt{\frac{4 \cdot #V}{\pi \cdot #h}}",
+                    ),
+                    MASS,
+                ],
+            },
+        ],
+    ),
+    solid(
+        SolidKind::Sphere,
+        &[dimension("diameter", "d"), DENSITY],
+        &[],
+        &[SPHERE_VOLUME, MASS],
+        |v| kind_values(SolidKind::Sphere, v),
+        |v, labels| kind_svg(SolidKind::Sphere, v, labels),
+        &[
+            SolveMode {
+                id: "diameter",
+                label_key: "solve_solid_diameter",
+                variables: &[],
+                inputs: &[VOLUME, DENSITY],
+                unknowns: &["d"],
+                solve: |v| {
+                    let sphere = Sphere::from_volume(v[0].volume())?;
+                    Ok(vec![Length::from_si(2.0 * sphere.radius).into(), v[1]])
+                },
+                formula: &[
+                    Step::new(
+                        "d",
+                        r"\left(\frac{6 V}{\pi}\right)^{1/3}",
+                        r"\left(\frac{6 \cdot #V}{\pi}\right)^{1/3}",
+                    ),
+                    MASS,
+                ],
+            },
+            SolveMode {
+                id: "surface-area",
+                label_key: "solve_sphere_from_surface_area",
+                variables: &[],
+                inputs: &[SURFACE_AREA, DENSITY],
+                unknowns: &["d"],
+                solve: |v| {
+                    let sphere = Sphere::from_surface_area(v[0].area())?;
+                    Ok(vec![Length::from_si(2.0 * sphere.radius).into(), v[1]])
+                },
+                formula: &[
+                    Step::new("d", r"\sqrt{\frac{S}{\pi}}", r"\sqrt{\frac{#S}{\pi}}"),
+                    SPHERE_VOLUME,
+                    MASS,
+                ],
+            },
+        ],
+    ),
+    solid(
+        SolidKind::Cone,
+        &[
+            dimension("diameter", "d"),
+            dimension("height", "h"),
+            DENSITY,
+        ],
+        &[],
+        &[CONE_VOLUME, MASS],
+        |v| kind_values(SolidKind::Cone, v),
+        |v, labels| kind_svg(SolidKind::Cone, v, labels),
+        &[SolveMode {
+            id: "height",
+            label_key: "solve_solid_height",
+            variables: &[],
+            inputs: &[VOLUME, dimension("diameter", "d"), DENSITY],
+            unknowns: &["h"],
+            solve: |v| {
+                let cone = Cone::from_volume_and_diameter(v[0].volume(), v[1].length())?;
+                let mut dimensions = metres(&[2.0 * cone.radius, cone.height]);
+                dimensions.push(v[2]);
+                Ok(dimensions)
+            },
+            formula: &[
+                Step::new(
+                    "h",
+                    r"\frac{12 V}{\pi d^2}",
+                    r"\frac{12 \cdot #V}{\pi \cdot (#d)^2}",
+                ),
+                MASS,
+            ],
+        }],
+    ),
+    solid(
+        SolidKind::Frustum,
+        &[
+            dimension("bottom_diameter", "D"),
+            TOP_DIAMETER,
+            dimension("height", "h"),
+            DENSITY,
+        ],
+        &[],
+        &[FRUSTUM_VOLUME, MASS],
+        |v| kind_values(SolidKind::Frustum, v),
+        |v, labels| kind_svg(SolidKind::Frustum, v, labels),
+        &[SolveMode {
+            id: "height",
+            label_key: "solve_solid_height",
+            variables: &[],
+            inputs: &[
+                VOLUME,
+                dimension("bottom_diameter", "D"),
+                TOP_DIAMETER,
+                DENSITY,
+            ],
+            unknowns: &["h"],
+            solve: |v| {
+                let frustum = Frustum::from_volume_and_diameters(
+                    v[0].volume(),
+                    v[1].length(),
+                    v[2].length(),
+                )?;
+                let mut dimensions = metres(&[
+                    2.0 * frustum.bottom_radius,
+                    2.0 * frustum.top_radius,
+                    frustum.height,
+                ]);
+                dimensions.push(v[3]);
+                Ok(dimensions)
+            },
+            formula: &[
+                Step::new(
+                    "h",
+                    r"\frac{12 V}{\pi (D^2 + D d + d^2)}",
+                    r"\frac{12 \cdot #V}{\pi ((#D)^2 + #D \cdot #d + (#d)^2)}",
+                ),
+                MASS,
+            ],
+        }],
+    ),
+    solid(
+        SolidKind::HollowTube,
+        &[
+            dimension("outer_diameter", "d"),
+            dimension("wall_thickness", "t"),
+            dimension("length", "l"),
+            DENSITY,
+        ],
+        &["t < d/2"],
+        &[TUBE_VOLUME, MASS],
+        |v| kind_values(SolidKind::HollowTube, v),
+        |v, labels| kind_svg(SolidKind::HollowTube, v, labels),
+        &[SolveMode {
+            id: "length",
+            label_key: "solve_solid_length",
+            variables: &[],
+            inputs: &[
+                VOLUME,
+                dimension("outer_diameter", "d"),
+                dimension("wall_thickness", "t"),
+                DENSITY,
+            ],
+            unknowns: &["l"],
+            solve: |v| {
+                let tube = HollowTube::from_volume(v[0].volume(), v[1].length(), v[2].length())?;
+                let mut dimensions =
+                    metres(&[tube.outer_diameter, tube.wall_thickness, tube.length]);
+                dimensions.push(v[3]);
+                Ok(dimensions)
+            },
+            formula: &[
+                Step::new(
+                    "l",
+                    r"\frac{4 V}{\pi (d^2 - (d - 2t)^2)}",
+                    r"\frac{4 \cdot #V}{\pi ((#d)^2 - (#d - 2 \cdot #t)^2)}",
+                ),
+                MASS,
+            ],
+        }],
+    ),
+    solid(
+        SolidKind::PolygonPrism,
+        &[
+            SIDES,
+            dimension("side_length", "s"),
+            dimension("height", "h"),
+            DENSITY,
+        ],
+        &[],
+        &[PRISM_VOLUME, MASS],
+        |v| kind_values(SolidKind::PolygonPrism, v),
+        |v, labels| kind_svg(SolidKind::PolygonPrism, v, labels),
+        &[SolveMode {
+            id: "height",
+            label_key: "solve_solid_height",
+            variables: &[],
+            inputs: &[VOLUME, SIDES, dimension("side_length", "s"), DENSITY],
+            unknowns: &["h"],
+            solve: |v| {
+                let base =
+                    RegularPolygon::new(RegularPolygon::sides(v[1].count())?, v[2].length())?;
+                let prism = Prism::from_volume(base, v[0].volume())?;
+                Ok(vec![
+                    AnyQuantity::Count(prism.base.sides as f64),
+                    Length::from_si(prism.base.side_length).into(),
+                    Length::from_si(prism.height).into(),
+                    v[3],
+                ])
+            },
+            formula: &[
+                Step::new(
+                    "h",
+                    r"\frac{4 V \tan(\pi / n)}{n s^2}",
+                    r"\frac{4 \cdot #V \tan(\pi / #n)}{#n \cdot (#s)^2}",
+                ),
+                MASS,
+            ],
+        }],
+    ),
+];
+
+pub static SOLIDS: CalculatorGroup = CalculatorGroup {
+    card: CardInfo {
+        id: "solid",
+        title_key: "solid_calculator",
+        path: PATH,
+    },
+    kinds: &SOLID_KINDS,
+    initial: SolidKind::Cuboid as usize,
+};
+
+#[allow(non_snake_case)]
+#[component]
+pub fn SolidCard() -> impl IntoView {
+    view! { <CalculatorCard calculator=SOLIDS /> }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cards::calculator::AnyUnit;
+
+    #[test]
+    fn test_solid_solve_modes() {
+        let with_density = |lengths: &[f64], density: f64| {
+            let mut values = metres(lengths);
+            values.push(Density::from_si(density).into());
+            values
+        };
+        let prism = [
+            AnyQuantity::Count(6.0),
+            Length::from_si(2.0).into(),
+            Length::from_si(3.0).into(),
+            Density::from_si(1000.0).into(),
+        ];
+        let dimensions = [
+            with_density(&[2.0, 3.0, 4.0], 1000.0),
+            with_density(&[2.0, 3.0], 1000.0),
+            with_density(&[2.0], 1000.0),
+            with_density(&[2.0, 3.0], 1000.0),
+            with_density(&[3.0, 2.0, 4.0], 1000.0),
+            with_density(&[2.0, 0.5, 3.0], 1000.0),
+            prism.to_vec(),
+        ];
+        for (calculator, dimensions) in SOLID_KINDS.iter().zip(dimensions) {
+            let outputs = (calculator.compute)(&dimensions).unwrap();
+            // Each mode finds the dimensions back from the volume or surface area
+            let value = |symbol: &str| match symbol {
+                "V" => outputs[0],
+                "S" => outputs[1],
+                _ => dimensions[calculator.input_index(symbol).unwrap()],
+            };
+            for (index, mode) in calculator.solve_modes.iter().enumerate() {
+                let values = mode
+                    .inputs
+                    .iter()
+                    .map(|input| value(input.symbol))
+                    .collect();
+                let solution = calculator.solve(Some(index), values).unwrap();
+                let inputs = calculator.inputs.iter();
+                for ((input, solved), expected) in inputs.zip(solution.dimensions).zip(&dimensions)
+                {
+                    let unit = AnyUnit::default_for(input.measure, Scale::Metre);
+                    assert!(
+                        (solved.value_in(unit) - expected.value_in(unit)).abs() < 1e-9,
+                        "{} {}",
+                        calculator.id,
+                        mode.id
+                    );
+                }
+            }
+        }
+
+        // A hole through the whole wall has no length
+        let tube = &SOLID_KINDS[SolidKind::HollowTube as usize];
+        let values = [
+            Volume::from_si(1.0).into(),
+            Length::from_si(2.0).into(),
+            Length::from_si(1.0).into(),
+            Density::from_si(1000.0).into(),
+        ];
+        assert!((tube.solve_modes[0].solve)(&values).is_err());
+    }
+}
@@ -0,0 +1,348 @@
+//! Workspaces: named sets of saved cards with notes, one per project, stored locally
+//! and exchanged as versioned JSON documents.
+
+use crate::cards::share::{card_link, CardInfo};
+use crate::dom::storage::{next_id, LocalStore};
+use leptos::prelude::*;
+use leptos_router::params::ParamsMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Key of the workspaces in localStorage.
+const STORAGE_KEY: &str = "workspaces";
+
+/// Value of the `format` field that marks a JSON document as a workspace.
+pub const WORKSPACE_FORMAT: &str = "engineertools-workspace";
+
+/// Upgrades a document to the next version.
+type Migration = fn(&mut Value) -> Result<(), WorkspaceError>;
+
+/// Upgrades of older documents: the one at index `i` takes a document of version
+/// `i + 1` to the next. Add one whenever the state a card saves changes, such as a
+/// renamed input, so archived workspaces keep opening.
+const MIGRATIONS: &[Migration] = &[];
+
+/// Version of the documents written by this build.
+pub const WORKSPACE_VERSION: u64 = MIGRATIONS.len() as u64 + 1;
+
+/// Why a document could not be imported as a workspace.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WorkspaceError {
+    /// The file is not JSON, or not laid out as a workspace of its version.
+    Unreadable,
+    /// The JSON is not a workspace document.
+    NotAWorkspace,
+    /// The document was written by a newer version of the site.
+    NewerVersion(u64),
+}
+
+impl WorkspaceError {
+    /// Key of the translated message in `I18n`.
+    pub fn i18n_key(&self) -> &'static str {
+        match self {
+            WorkspaceError::Unreadable => "workspace_error_unreadable",
+            WorkspaceError::NotAWorkspace => "workspace_error_not_a_workspace",
+            WorkspaceError::NewerVersion(_) => "workspace_error_newer_version",
+        }
+    }
+}
+
+/// The state of a card, saved to a workspace.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedCard {
+    pub id: u64,
+    /// Id of the card, such as `rectangle`.
+    pub card: String,
+    pub title_key: String,
+    pub path: String,
+    /// Query of the link to the card, as keys and values.
+    pub state: Vec<(String, String)>,
+    pub note: String,
+    /// Milliseconds since the Unix epoch.
+    pub saved_at: f64,
+}
+
+impl SavedCard {
+    /// The card in its current state; its id is given when it is added to a workspace.
+    pub fn new(card: CardInfo, state: ParamsMap) -> Self {
+        SavedCard {
+            id: 0,
+            card: card.id.to_string(),
+            title_key: card.title_key.to_string(),
+            path: card.path.to_string(),
+            state: state
+                .into_iter()
+                .map(|(key, value)| (key.into_owned(), value))
+                .collect(),
+            note: String::new(),
+            saved_at: js_sys::Date::now(),
+        }
+    }
+
+    /// Link that opens the card in the saved state.
+    pub fn link(&self) -> String {
+        card_link(&self.path, &self.state.iter().cloned().collect())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Workspace {
+    pub id: u64,
+    pub name: String,
+    pub notes: String,
+    pub cards: Vec<SavedCard>,
+}
+
+impl Workspace {
+    pub fn new(id: u64, name: &str) -> Self {
+        Workspace {
+            id,
+            name: name.to_string(),
+            notes: String::new(),
+            cards: Vec::new(),
+        }
+    }
+
+    pub fn add_card(&mut self, mut card: SavedCard) {
+        card.id = next_id(self.cards.iter().map(|card| card.id));
+        self.cards.push(card);
+    }
+
+    pub fn remove_card(&mut self, id: u64) {
+        self.cards.retain(|card| card.id != id);
+    }
+
+    pub fn set_card_note(&mut self, id: u64, note: &str) {
+        if let Some(card) = self.cards.iter_mut().find(|card| card.id == id) {
+            card.note = note.to_string();
+        }
+    }
+}
+
+/// All workspaces, with the one cards are saved to.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Workspaces {
+    pub workspaces: Vec<Workspace>,
+    pub active: Option<u64>,
+}
+
+impl Workspaces {
+    fn next_id(&self) -> u64 {
+        next_id(self.workspaces.iter().map(|workspace| workspace.id))
+    }
+
+    pub fn active(&self) -> Option<&Workspace> {
+        self.workspaces
+            .iter()
+            .find(|workspace| Some(workspace.id) == self.active)
+    }
+
+    /// Adds a workspace and makes it the active one.
+    pub fn add(&mut self, mut workspace: Workspace) -> u64 {
+        let id = self.next_id();
+        workspace.id = id;
+        self.workspaces.push(workspace);
+        self.active = Some(id);
+        id
+    }
+
+    pub fn select(&mut self, id: u64) {
+        if self.workspaces.iter().any(|workspace| workspace.id == id) {
+            self.active = Some(id);
+        }
+    }
+
+    /// Deletes a workspace; the first one left becomes the active one.
+    pub fn delete(&mut self, id: u64) {
+        self.workspaces.retain(|workspace| workspace.id != id);
+        if self.active == Some(id) {
+            self.active = self.workspaces.first().map(|workspace| workspace.id);
+        }
+    }
+
+    pub fn edit(&mut self, id: u64, change: impl FnOnce(&mut Workspace)) {
+        if let Some(workspace) = self.workspaces.iter_mut().find(|w| w.id == id) {
+            change(workspace);
+        }
+    }
+
+    pub fn edit_active(&mut self, change: impl FnOnce(&mut Workspace)) {
+        if let Some(id) = self.active {
+            self.edit(id, change);
+        }
+    }
+
+    /// Saves a card to the active workspace, creating one named `new_name` if there is none.
+    pub fn save_card(&mut self, card: SavedCard, new_name: &str) {
+        let id = match self.active() {
+            Some(workspace) => workspace.id,
+            None => self.add(Workspace::new(0, new_name)),
+        };
+        self.edit(id, |workspace| workspace.add_card(card));
+    }
+}
+
+/// Layout of an exported workspace.
+#[derive(Serialize, Deserialize)]
+struct WorkspaceDocument {
+    format: String,
+    version: u64,
+    workspace: Workspace,
+}
+
+/// Writes a workspace as a JSON document of the current version.
+pub fn export_workspace(workspace: &Workspace) -> String {
+    let document = WorkspaceDocument {
+        format: WORKSPACE_FORMAT.to_string(),
+        version: WORKSPACE_VERSION,
+        workspace: workspace.clone(),
+    };
+    serde_json::to_string_pretty(&document).expect("a workspace is always valid JSON")
+}
+
+/// Reads a workspace document of this or an older version.
+pub fn import_workspace(json: &str) -> Result<Workspace, WorkspaceError> {
+    import_with(json, MIGRATIONS)
+}
+
+fn import_with(json: &str, migrations: &[Migration]) -> Result<Workspace, WorkspaceError> {
+    let mut document: Value = serde_json::from_str(json).map_err(|_| WorkspaceError::Unreadable)?;
+    if document.get("format").and_then(Value::as_str) != Some(WORKSPACE_FORMAT) {
+        return Err(WorkspaceError::NotAWorkspace);
+    }
+    let version = document
+        .get("version")
+        .and_then(Value::as_u64)
+        .filter(|version| *version > 0)
+        .ok_or(WorkspaceError::NotAWorkspace)?;
+    let current = migrations.len() as u64 + 1;
+    if version > current {
+        return Err(WorkspaceError::NewerVersion(version));
+    }
+    for migration in &migrations[version as usize - 1..] {
+        migration(&mut document)?;
+    }
+    document["version"] = current.into();
+    serde_json::from_value::<WorkspaceDocument>(document)
+        .map(|document| document.workspace)
+        .map_err(|_| WorkspaceError::Unreadable)
+}
+
+/// The workspaces shared by the cards and the workspace page, saved to localStorage on
+/// every change.
+pub type WorkspaceStore = LocalStore<Workspaces>;
+
+impl WorkspaceStore {
+    /// Provides the workspaces as context; they are read from localStorage once in the browser.
+    pub fn provide() {
+        LocalStore::provide_with(STORAGE_KEY, |workspaces: &mut Workspaces, stored| {
+            *workspaces = stored;
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn saved_card(card: &str, state: &[(&str, &str)]) -> SavedCard {
+        SavedCard {
+            id: 0,
+            card: card.to_string(),
+            title_key: format!("{card}_title"),
+            path: format!("/cards/area/{card}"),
+            state: state
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+            note: String::new(),
+            saved_at: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_workspaces() {
+        let mut workspaces = Workspaces::default();
+        workspaces.save_card(saved_card("circle", &[("r", "2")]), "Project");
+        workspaces.save_card(saved_card("circle", &[("r", "3")]), "Project");
+        let first = workspaces.active().unwrap().id;
+        assert_eq!(workspaces.workspaces.len(), 1);
+        assert_eq!(workspaces.active().unwrap().name, "Project");
+        let ids: Vec<u64> = workspaces
+            .active()
+            .unwrap()
+            .cards
+            .iter()
+            .map(|c| c.id)
+            .collect();
+        assert_eq!(ids, [0, 1]);
+
+        let second = workspaces.add(Workspace::new(0, "Bridge"));
+        assert_eq!(workspaces.active, Some(second));
+        workspaces.edit(first, |workspace| {
+            workspace.set_card_note(1, "Column");
+            workspace.remove_card(0);
+        });
+        workspaces.delete(second);
+        assert_eq!(workspaces.active, Some(first));
+        assert_eq!(workspaces.active().unwrap().cards[0].note, "Column");
+    }
+
+    #[test]
+    fn test_export_import() {
+        let mut workspace = Workspace::new(3, "Hall");
+        workspace.notes = "Roof beams".to_string();
+        workspace.add_card(saved_card("rectangle", &[("w", "2"), ("w_unit", "m")]));
+        let json = export_workspace(&workspace);
+        assert_eq!(import_workspace(&json), Ok(workspace));
+
+        assert_eq!(
+            import_workspace("not json"),
+            Err(WorkspaceError::Unreadable)
+        );
+        assert_eq!(import_workspace("{}"), Err(WorkspaceError::NotAWorkspace));
+        let newer = json.replacen(
+            &format!("\"version\": {WORKSPACE_VERSION}"),
+            &format!("\"version\": {}", WORKSPACE_VERSION + 1),
+            1,
+        );
+        assert_eq!(
+            import_workspace(&newer),
+            Err(WorkspaceError::NewerVersion(WORKSPACE_VERSION + 1))
+        );
+    }
+
+    #[test]
+    fn test_migration() {
+        // A version 1 rectangle named its width `b`; version 2 names it `w`
+        fn rename_rectangle_width(document: &mut Value) -> Result<(), WorkspaceError> {
+            let cards = document["workspace"]["cards"]
+                .as_array_mut()
+                .ok_or(WorkspaceError::Unreadable)?;
+            for card in cards.iter_mut().filter(|card| card["card"] == "rectangle") {
+                for pair in card["state"].as_array_mut().into_iter().flatten() {
+                    if pair[0] == "b" {
+                        pair[0] = "w".into();
+                    }
+                }
+            }
+            Ok(())
+        }
+
+        let mut workspace = Workspace::new(0, "Hall");
+        workspace.add_card(saved_card("rectangle", &[("b", "2"), ("h", "3")]));
+        let version_1 = export_workspace(&workspace).replacen(
+            &format!("\"version\": {WORKSPACE_VERSION}"),
+            "\"version\": 1",
+            1,
+        );
+        let migrated = import_with(&version_1, &[rename_rectangle_width]).unwrap();
+        assert_eq!(
+            migrated.cards[0].state,
+            [
+                ("w".to_string(), "2".to_string()),
+                ("h".to_string(), "3".to_string())
+            ]
+        );
+    }
+}
@@ -1,14 +1,27 @@
-pub fn float_to_clipboard(float_value: f64) -> impl Fn(web_sys::MouseEvent) {
-    let copy_to_clipboard = move |_| {
-        if let Some(window) = web_sys::window() {
-            let navigator = window.navigator(); // Access the Navigator object
-            let clipboard = navigator.clipboard();
-            let result_text = format!("{:.2}", float_value);
-            let _ = clipboard.write_text(&result_text);
-            leptos::logging::log!("Copied to clipboard: {}", result_text);
-        } else {
-            leptos::logging::error!("Window object not available");
-        }
+/// Copies a text, then tells `done` whether the browser allowed it.
+pub fn copy_text(text: String, done: impl FnOnce(bool) + 'static) {
+    let Some(window) = web_sys::window() else {
+        leptos::logging::error!("Window object not available");
+        done(false);
+        return;
     };
-    copy_to_clipboard
+    let promise = window.navigator().clipboard().write_text(&text);
+    leptos::task::spawn_local(async move {
+        match wasm_bindgen_futures::JsFuture::from(promise).await {
+            Ok(_) => done(true),
+            Err(e) => {
+                leptos::logging::error!("Could not copy to clipboard: {:?}", e);
+                done(false);
+            }
+        }
+    });
+}
+
+/// Copies a link to a page of this site, completed with the origin it is served from,
+/// then tells `done` whether the browser allowed it.
+pub fn link_to_clipboard(link: &str, done: impl FnOnce(bool) + 'static) {
+    let origin = web_sys::window()
+        .and_then(|window| window.location().origin().ok())
+        .unwrap_or_default();
+    copy_text(format!("{origin}{link}"), done);
 }
@@ -0,0 +1,131 @@
+use js_sys::wasm_bindgen::closure::Closure;
+use js_sys::wasm_bindgen::{JsCast, JsValue};
+use std::time::Duration;
+
+/// How long the URL of a downloaded file stays valid after the download is started.
+const REVOKE_AFTER: Duration = Duration::from_secs(1);
+
+/// Lets the browser download a text as a file.
+pub fn download_text(file_name: &str, mime_type: &str, text: &str) {
+    if let Some(blob) = text_blob(file_name, mime_type, text) {
+        download_blob(file_name, &blob);
+    }
+}
+
+/// Lets the browser download an SVG drawing as a PNG image, `scale` times its size on a
+/// white background.
+pub fn download_svg_as_png(file_name: &str, svg: &str, scale: f64) {
+    let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+        leptos::logging::error!("Document not available");
+        return;
+    };
+    let Some(blob) = text_blob(file_name, "image/svg+xml", svg) else {
+        return;
+    };
+    let (Ok(url), Ok(image)) = (
+        web_sys::Url::create_object_url_with_blob(&blob),
+        web_sys::HtmlImageElement::new(),
+    ) else {
+        return;
+    };
+
+    // The image has its size only once it is loaded
+    let file_name = file_name.to_string();
+    let loaded = image.clone();
+    let source = url.clone();
+    let on_load = Closure::once_into_js(move || {
+        let _ = web_sys::Url::revoke_object_url(&source);
+        let (width, height) = (
+            loaded.natural_width() as f64 * scale,
+            loaded.natural_height() as f64 * scale,
+        );
+        let Some(canvas) = document
+            .create_element("canvas")
+            .ok()
+            .and_then(|canvas| canvas.dyn_into::<web_sys::HtmlCanvasElement>().ok())
+        else {
+            return;
+        };
+        canvas.set_width(width.ceil() as u32);
+        canvas.set_height(height.ceil() as u32);
+        let Some(context) = canvas
+            .get_context("2d")
+            .ok()
+            .flatten()
+            .and_then(|context| context.dyn_into::<web_sys::CanvasRenderingContext2d>().ok())
+        else {
+            return;
+        };
+        context.set_fill_style_str("white");
+        context.fill_rect(0.0, 0.0, width, height);
+        let _ = context
+            .draw_image_with_html_image_element_and_dw_and_dh(&loaded, 0.0, 0.0, width, height);
+        let on_blob = Closure::once_into_js(move |blob: JsValue| {
+            if let Ok(blob) = blob.dyn_into::<web_sys::Blob>() {
+                download_blob(&file_name, &blob);
+            }
+        });
+        if canvas.to_blob(on_blob.unchecked_ref()).is_err() {
+            leptos::logging::error!("Could not draw the image");
+        }
+    });
+    image.set_onload(Some(on_load.unchecked_ref()));
+    image.set_src(&url);
+}
+
+fn text_blob(file_name: &str, mime_type: &str, text: &str) -> Option<web_sys::Blob> {
+    let parts = js_sys::Array::of1(&js_sys::JsString::from(text));
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let blob = web_sys::Blob::new_with_str_sequence_and_options(&parts, &options).ok();
+    if blob.is_none() {
+        leptos::logging::error!("Could not create the file {}", file_name);
+    }
+    blob
+}
+
+fn download_blob(file_name: &str, blob: &web_sys::Blob) {
+    let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+        leptos::logging::error!("Document not available");
+        return;
+    };
+    let (Some(body), Ok(url)) = (
+        document.body(),
+        web_sys::Url::create_object_url_with_blob(blob),
+    ) else {
+        return;
+    };
+    // Some browsers only follow a link that is in the document
+    if let Ok(anchor) = document.create_element("a") {
+        let anchor: web_sys::HtmlAnchorElement = anchor.unchecked_into();
+        anchor.set_href(&url);
+        anchor.set_download(file_name);
+        if body.append_child(&anchor).is_ok() {
+            anchor.click();
+            anchor.remove();
+        }
+    }
+    // The download starts after the click returns, so the URL has to outlive it
+    leptos::prelude::set_timeout(
+        move || {
+            let _ = web_sys::Url::revoke_object_url(&url);
+        },
+        REVOKE_AFTER,
+    );
+}
+
+/// Reads the file chosen in a file input and passes its text to `on_text`.
+pub fn read_chosen_file(event: &web_sys::Event, on_text: impl FnOnce(String) + 'static) {
+    let input: web_sys::HtmlInputElement = leptos::prelude::event_target(event);
+    let Some(file) = input.files().and_then(|files| files.get(0)) else {
+        return;
+    };
+    // Choosing the same file again should read it again
+    input.set_value("");
+    leptos::task::spawn_local(async move {
+        match wasm_bindgen_futures::JsFuture::from(file.text()).await {
+            Ok(text) => on_text(text.as_string().unwrap_or_default()),
+            Err(e) => leptos::logging::error!("Could not read {}: {:?}", file.name(), e),
+        }
+    });
+}
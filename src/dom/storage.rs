@@ -1,3 +1,7 @@
+use leptos::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
 pub fn get_local_storage() -> Option<web_sys::Storage> {
     leptos::prelude::window().local_storage().ok().flatten()
 }
@@ -17,3 +21,69 @@ pub fn set_local_storage_item(key: &str, value: &str) -> Result<(), web_sys::Err
         Err(web_sys::ErrorEvent::new("Local storage not available").unwrap())
     }
 }
+
+/// Reads a value stored as JSON; `None` if it is missing or no longer readable.
+pub fn get_local_storage_json<T: DeserializeOwned>(key: &str) -> Option<T> {
+    serde_json::from_str(&get_local_storage_item(key)?).ok()
+}
+
+pub fn set_local_storage_json<T: Serialize>(
+    key: &str,
+    value: &T,
+) -> Result<(), web_sys::ErrorEvent> {
+    let json = serde_json::to_string(value)
+        .map_err(|_| web_sys::ErrorEvent::new("Value not serializable").unwrap())?;
+    set_local_storage_item(key, &json)
+}
+
+/// Id following the largest of `ids`, or 0 for the first, so items stored in a list
+/// keep their ids when others are removed.
+pub fn next_id(ids: impl IntoIterator<Item = u64>) -> u64 {
+    ids.into_iter().map(|id| id + 1).max().unwrap_or(0)
+}
+
+/// A value shared as context and saved as JSON to localStorage under its key on every
+/// change.
+pub struct LocalStore<T: 'static> {
+    value: RwSignal<T>,
+    key: &'static str,
+}
+
+impl<T> Clone for LocalStore<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for LocalStore<T> {}
+
+impl<T> LocalStore<T>
+where
+    T: Clone + Default + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    /// Provides the value as context; it is read from localStorage once in the browser
+    /// and handed to `restore` with the value changed before then.
+    pub fn provide_with(key: &'static str, restore: impl Fn(&mut T, T) + 'static) {
+        let value = RwSignal::new(T::default());
+        Effect::new(move |_| {
+            if let Some(stored) = get_local_storage_json::<T>(key) {
+                value.update(|value| restore(value, stored));
+            }
+        });
+        provide_context(LocalStore { value, key });
+    }
+
+    pub fn get(&self) -> T {
+        self.value.get()
+    }
+
+    pub fn update(&self, change: impl FnOnce(&mut T)) {
+        self.value.update(change);
+        if let Err(e) = self
+            .value
+            .with_untracked(|value| set_local_storage_json(self.key, value))
+        {
+            leptos::logging::error!("Error setting localStorage item: {:?}", e);
+        }
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "ssr")]
+mod api;
 mod app;
 mod calculations;
 mod cards;
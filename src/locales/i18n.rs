@@ -1,3 +1,4 @@
+use crate::locales::numbers::{NumberFormat, Rounding};
 use std::borrow::Cow;
 use std::collections::HashMap;
 
@@ -5,6 +6,7 @@ use std::collections::HashMap;
 pub struct I18n {
     lang: String,
     translations: HashMap<&'static str, &'static str>,
+    rounding: Rounding,
 }
 
 impl I18n {
@@ -16,15 +18,26 @@ impl I18n {
         Self {
             lang: lang.to_string(),
             translations,
+            rounding: Rounding::default(),
         }
     }
 
+    /// The same language with results rounded by the user's policy.
+    pub fn with_rounding(self, rounding: Rounding) -> Self {
+        Self { rounding, ..self }
+    }
+
     pub fn t<'a>(&'a self, key: &'a str) -> Cow<'a, str> {
         match self.translations.get(key) {
             Some(&val) => Cow::Borrowed(val),
             None => Cow::Borrowed(key),
         }
     }
+
+    /// How numbers are read and written in this language, rounded by the user's policy.
+    pub fn number_format(&self) -> NumberFormat {
+        NumberFormat::new(&self.lang).with_rounding(self.rounding)
+    }
 }
 
 fn en_translations() -> HashMap<&'static str, &'static str> {
@@ -33,6 +46,7 @@ fn en_translations() -> HashMap<&'static str, &'static str> {
         ("welcome_to_engineertools", "Welcome to engineertools.nl"),
         ("menu_home", "Home"),
         ("menu_basics", "Basic calculations"),
+        ("menu_conversions", "Unit conversions"),
         // home text
         ("home_welcome", "Welcome to engineertools.nl"),
         ("home_description", "This is a website for engineers!"),
@@ -52,9 +66,195 @@ fn en_translations() -> HashMap<&'static str, &'static str> {
         ("circle_area_radius", "Radius"),
         ("circle_area_calculate", "Calculate circle area"),
         ("circle_area_result", "The area of circle is: "),
+        // triangle area calculator
+        ("triangle_area_calculator", "Triangle area calculator"),
+        ("triangle_area_sides", "Sides"),
+        ("triangle_area_calculate", "Calculate triangle area"),
+        ("triangle_area_result", "The area of triangle is: "),
+        // trapezoid area calculator
+        ("trapezoid_area_calculator", "Trapezoid area calculator"),
+        ("trapezoid_area_bottom", "Bottom base"),
+        ("trapezoid_area_top", "Top base"),
+        ("trapezoid_area_calculate", "Calculate trapezoid area"),
+        ("trapezoid_area_result", "The area of trapezoid is: "),
+        // ellipse area calculator
+        ("ellipse_area_calculator", "Ellipse area calculator"),
+        ("ellipse_area_semi_major", "Semi-major axis"),
+        ("ellipse_area_semi_minor", "Semi-minor axis"),
+        ("ellipse_area_perimeter", "Perimeter"),
+        ("ellipse_area_h", "(a - b)² / (a + b)²"),
+        ("ellipse_perimeter_result", "The perimeter of ellipse is: "),
+        ("ellipse_area_calculate", "Calculate ellipse area"),
+        ("ellipse_area_result", "The area of ellipse is: "),
+        // regular polygon area calculator
+        ("polygon_area_calculator", "Regular polygon area calculator"),
+        ("polygon_area_sides", "Number of sides"),
+        ("polygon_area_side_length", "Side length"),
+        ("polygon_area_calculate", "Calculate polygon area"),
+        ("polygon_area_result", "The area of polygon is: "),
+        // parallelogram area calculator
+        ("parallelogram_area_calculator", "Parallelogram area calculator"),
+        ("parallelogram_area_base", "Base"),
+        ("parallelogram_area_side", "Side"),
+        ("parallelogram_area_angle", "Angle (degrees)"),
+        ("parallelogram_area_calculate", "Calculate parallelogram area"),
+        ("parallelogram_area_result", "The area of parallelogram is: "),
+        // section properties calculator
+        ("section_calculator", "Cross-section properties"),
+        ("section_calculate", "Calculate section properties"),
+        ("section_area", "Area A: "),
+        ("section_centroid_x", "Centroid cx: "),
+        ("section_centroid_y", "Centroid cy: "),
+        ("section_ix", "Second moment of area Ix: "),
+        ("section_iy", "Second moment of area Iy: "),
+        ("section_ixy", "Product of inertia Ixy: "),
+        ("section_ip", "Polar moment Ip: "),
+        ("section_wx", "Section modulus Wx: "),
+        ("section_wy", "Section modulus Wy: "),
+        ("section_rx", "Radius of gyration ix: "),
+        ("section_ry", "Radius of gyration iy: "),
+        ("section_diameter", "Diameter"),
+        ("section_wall_thickness", "Wall thickness"),
+        ("section_web_thickness", "Web thickness"),
+        ("section_flange_thickness", "Flange thickness"),
+        ("section_rectangle", "Rectangle"),
+        ("section_circle", "Circle"),
+        ("section_tube", "Tube"),
+        ("section_rhs", "Rectangular hollow section"),
+        ("section_i_profile", "I-profile"),
+        ("section_t_profile", "T-profile"),
+        ("section_l_profile", "L-profile"),
+        ("section_c_profile", "C-profile"),
+        // composite section calculator
+        ("composite_calculator", "Composite section properties"),
+        ("composite_position", "Position of the bottom-left corner of a part"),
+        ("composite_dimensions", "Dimensions of a part"),
+        ("composite_subtract", "Hole"),
+        ("composite_add_part", "Add part"),
+        // solid volume and mass calculator
+        ("solid_calculator", "Volume and mass calculator"),
+        ("solid_calculate", "Calculate volume and mass"),
+        ("solid_volume", "Volume"),
+        ("solid_surface_area", "Surface area"),
+        ("solid_mass", "Mass"),
+        ("solid_density", "Density"),
+        ("solid_volume_result", "Volume V: "),
+        ("solid_surface_area_result", "Surface area S: "),
+        ("solid_mass_result", "Mass m: "),
+        ("solid_length_width_height", "Length, width, height"),
+        ("solid_polygon_sides", "Number of sides, side length"),
+        ("solid_cuboid", "Cuboid"),
+        ("solid_cylinder", "Cylinder"),
+        ("solid_sphere", "Sphere"),
+        ("solid_cone", "Cone"),
+        ("solid_frustum", "Frustum"),
+        ("solid_tube", "Tube"),
+        ("solid_prism", "Regular polygon prism"),
+        // conversions page
+        ("conversions_page_title", "Unit Conversions"),
+        ("conversions_page_description", "Convert a value to all metric and imperial units of the same quantity."),
+        ("conversion_length", "Length"),
+        ("conversion_area", "Area"),
+        ("conversion_volume", "Volume"),
+        ("conversion_mass", "Mass"),
+        ("conversion_force", "Force"),
+        ("conversion_pressure", "Pressure and stress"),
+        ("conversion_energy", "Energy"),
+        ("conversion_power", "Power"),
+        ("conversion_torque", "Torque"),
+        ("conversion_temperature", "Temperature"),
+        ("conversion_flow_rate", "Flow rate"),
+        ("conversion_angle", "Angle"),
+        // calculation errors
+        ("error_non_positive", "Must be greater than zero."),
+        ("error_out_of_domain", "Is outside the valid range."),
+        ("error_inconsistent", "Does not fit the other dimensions."),
+        ("error_not_finite", "Is not a valid number."),
+        ("error_unit_mismatch", "Has a unit of the wrong quantity."),
         // other keys
         ("copy_to_clipboard", "Copy to clipboard"),
+        ("copy_value", "Value with unit"),
+        ("copy_table", "Table for Excel"),
+        ("copy_markdown", "Markdown table"),
+        ("copy_latex", "LaTeX formula"),
+        ("copy_symbol", "Symbol"),
+        ("copy_number", "Value"),
+        ("copy_unit", "Unit"),
+        ("copy_done", "Copied to clipboard"),
+        ("copy_failed", "Could not copy to clipboard"),
         ("card_direct_link", "Direct link to this card"),
+        ("card_copy_link", "Copy link"),
+        ("card_live_update", "Update live"),
+        ("history_title", "History"),
+        ("history_empty", "No calculations yet"),
+        ("history_name", "Name"),
+        ("history_open", "Open"),
+        ("history_pin", "Pin"),
+        ("history_unpin", "Unpin"),
+        ("history_delete", "Delete"),
+        ("card_save_to_workspace", "Save to workspace"),
+        ("drawing_download_svg", "Download SVG"),
+        ("drawing_download_png", "Download PNG"),
+        ("tolerance_hint", "Add a tolerance to a value as 100 ± 0.2."),
+        ("tolerance_range", "Range"),
+        ("tolerance_uncertainty", "uncertainty (RSS)"),
+        // rounding of results
+        ("rounding", "Rounding"),
+        ("rounding_decimals", "Decimals"),
+        ("rounding_significant", "Significant figures"),
+        ("rounding_engineering", "Engineering notation"),
+        ("menu_workspaces", "Workspaces"),
+        ("workspaces_page_title", "Workspaces"),
+        ("workspaces_page_description", "Group the calculations of a project with notes, and archive them as a file next to the project."),
+        ("workspace_default_name", "Project"),
+        ("workspace_none", "No workspaces yet."),
+        ("workspace_new", "New workspace"),
+        ("workspace_select", "Workspace"),
+        ("workspace_name", "Name"),
+        ("workspace_notes", "Notes"),
+        ("workspace_delete", "Delete workspace"),
+        ("workspace_export", "Export"),
+        ("workspace_import", "Import"),
+        ("workspace_empty", "Save cards here with the button \"Save to workspace\" on a card."),
+        ("workspace_open", "Open"),
+        ("workspace_card_note", "Note"),
+        ("workspace_remove_card", "Remove"),
+        ("workspace_error_unreadable", "The file could not be read as a workspace."),
+        ("workspace_error_not_a_workspace", "The file is not an exported workspace."),
+        ("workspace_error_newer_version", "The file was made with a newer version of this site."),
+        ("workspace_report", "Report"),
+        ("report_title", "Calculation report"),
+        ("report_made", "made on"),
+        ("report_print", "Print"),
+        ("report_empty", "The report holds no cards."),
+        ("report_inputs", "Inputs"),
+        ("report_calculation", "Calculation"),
+        ("report_results", "Results"),
+        ("report_incomplete", "Not all inputs of this card are filled in."),
+        ("solve_mode", "Known values"),
+        ("solve_from_dimensions", "From dimensions"),
+        ("solve_rectangle_width", "Width from area and height"),
+        ("solve_rectangle_height", "Height from area and width"),
+        ("solve_circle_from_area", "Radius from area"),
+        ("solve_circle_from_circumference", "Radius from circumference"),
+        ("circle_circumference", "Circumference"),
+        ("solve_triangle_sas", "Two sides and the included angle"),
+        ("solve_triangle_asa", "Two angles and the included side"),
+        ("solve_triangle_base_height", "Isosceles from base and height"),
+        ("triangle_angle_gamma", "Angle between a and b"),
+        ("triangle_angles_alpha_beta", "Angles at both ends of c"),
+        ("triangle_height", "Height on c"),
+        ("solve_trapezoid_height", "Height from area and parallel sides"),
+        ("solve_ellipse_semi_minor", "Semi-minor axis from area"),
+        ("solve_polygon_circumradius", "Side length from circumradius"),
+        ("solve_polygon_apothem", "Side length from apothem"),
+        ("polygon_circumradius", "Circumradius (centre to vertex)"),
+        ("polygon_apothem", "Apothem (centre to middle of a side)"),
+        ("solve_parallelogram_side", "Side from height"),
+        ("solve_solid_height", "Height from volume"),
+        ("solve_solid_length", "Length from volume"),
+        ("solve_solid_diameter", "Diameter from volume"),
+        ("solve_sphere_from_surface_area", "Diameter from surface area"),
         // footer
         ("copyright", "Copyright 2025 engineertools.nl"),
         // ...add more keys
@@ -67,6 +267,7 @@ fn nl_translations() -> HashMap<&'static str, &'static str> {
         ("welcome_to_engineertools", "Welkom bij engineertools.nl"),
         ("menu_home", "Home"),
         ("menu_basics", "Basis berekeningen"),
+        ("menu_conversions", "Eenheden omrekenen"),
         // home text
         ("home_welcome", "Welkom bij engineertools.nl"),
         ("home_description", "Dit is een website voor engineers!"),
@@ -86,9 +287,195 @@ fn nl_translations() -> HashMap<&'static str, &'static str> {
         ("circle_area_radius", "Straal"),
         ("circle_area_calculate", "Bereken oppervlakte"),
         ("circle_area_result", "De oppervlakte van de cirkel is: "),
+        // triangle area calculator
+        ("triangle_area_calculator", "Driehoek oppervlakte calculator"),
+        ("triangle_area_sides", "Zijden"),
+        ("triangle_area_calculate", "Bereken oppervlakte"),
+        ("triangle_area_result", "De oppervlakte van de driehoek is: "),
+        // trapezoid area calculator
+        ("trapezoid_area_calculator", "Trapezium oppervlakte calculator"),
+        ("trapezoid_area_bottom", "Onderzijde"),
+        ("trapezoid_area_top", "Bovenzijde"),
+        ("trapezoid_area_calculate", "Bereken oppervlakte"),
+        ("trapezoid_area_result", "De oppervlakte van het trapezium is: "),
+        // ellipse area calculator
+        ("ellipse_area_calculator", "Ellips oppervlakte calculator"),
+        ("ellipse_area_semi_major", "Halve lange as"),
+        ("ellipse_area_semi_minor", "Halve korte as"),
+        ("ellipse_area_perimeter", "Omtrek"),
+        ("ellipse_area_h", "(a - b)² / (a + b)²"),
+        ("ellipse_perimeter_result", "De omtrek van de ellips is: "),
+        ("ellipse_area_calculate", "Bereken oppervlakte"),
+        ("ellipse_area_result", "De oppervlakte van de ellips is: "),
+        // regular polygon area calculator
+        ("polygon_area_calculator", "Regelmatige veelhoek oppervlakte calculator"),
+        ("polygon_area_sides", "Aantal zijden"),
+        ("polygon_area_side_length", "Zijdelengte"),
+        ("polygon_area_calculate", "Bereken oppervlakte"),
+        ("polygon_area_result", "De oppervlakte van de veelhoek is: "),
+        // parallelogram area calculator
+        ("parallelogram_area_calculator", "Parallellogram oppervlakte calculator"),
+        ("parallelogram_area_base", "Basis"),
+        ("parallelogram_area_side", "Zijde"),
+        ("parallelogram_area_angle", "Hoek (graden)"),
+        ("parallelogram_area_calculate", "Bereken oppervlakte"),
+        ("parallelogram_area_result", "De oppervlakte van het parallellogram is: "),
+        // section properties calculator
+        ("section_calculator", "Doorsnede-eigenschappen"),
+        ("section_calculate", "Bereken doorsnede-eigenschappen"),
+        ("section_area", "Oppervlakte A: "),
+        ("section_centroid_x", "Zwaartepunt cx: "),
+        ("section_centroid_y", "Zwaartepunt cy: "),
+        ("section_ix", "Traagheidsmoment Ix: "),
+        ("section_iy", "Traagheidsmoment Iy: "),
+        ("section_ixy", "Traagheidsproduct Ixy: "),
+        ("section_ip", "Polair traagheidsmoment Ip: "),
+        ("section_wx", "Weerstandsmoment Wx: "),
+        ("section_wy", "Weerstandsmoment Wy: "),
+        ("section_rx", "Traagheidsstraal ix: "),
+        ("section_ry", "Traagheidsstraal iy: "),
+        ("section_diameter", "Diameter"),
+        ("section_wall_thickness", "Wanddikte"),
+        ("section_web_thickness", "Lijfdikte"),
+        ("section_flange_thickness", "Flensdikte"),
+        ("section_rectangle", "Rechthoek"),
+        ("section_circle", "Cirkel"),
+        ("section_tube", "Buis"),
+        ("section_rhs", "Kokerprofiel"),
+        ("section_i_profile", "I-profiel"),
+        ("section_t_profile", "T-profiel"),
+        ("section_l_profile", "L-profiel"),
+        ("section_c_profile", "U-profiel"),
+        // composite section calculator
+        ("composite_calculator", "Samengestelde doorsnede"),
+        ("composite_position", "Positie van de linkeronderhoek van een deel"),
+        ("composite_dimensions", "Afmetingen van een deel"),
+        ("composite_subtract", "Gat"),
+        ("composite_add_part", "Deel toevoegen"),
+        // solid volume and mass calculator
+        ("solid_calculator", "Volume en massa calculator"),
+        ("solid_calculate", "Bereken volume en massa"),
+        ("solid_volume", "Volume"),
+        ("solid_surface_area", "Oppervlakte"),
+        ("solid_mass", "Massa"),
+        ("solid_density", "Dichtheid"),
+        ("solid_volume_result", "Volume V: "),
+        ("solid_surface_area_result", "Oppervlakte S: "),
+        ("solid_mass_result", "Massa m: "),
+        ("solid_length_width_height", "Lengte, breedte, hoogte"),
+        ("solid_polygon_sides", "Aantal zijden, zijdelengte"),
+        ("solid_cuboid", "Balk"),
+        ("solid_cylinder", "Cilinder"),
+        ("solid_sphere", "Bol"),
+        ("solid_cone", "Kegel"),
+        ("solid_frustum", "Afgeknotte kegel"),
+        ("solid_tube", "Buis"),
+        ("solid_prism", "Regelmatig prisma"),
+        // conversions page
+        ("conversions_page_title", "Eenheden omrekenen"),
+        ("conversions_page_description", "Reken een waarde om naar alle metrische en imperiale eenheden van dezelfde grootheid."),
+        ("conversion_length", "Lengte"),
+        ("conversion_area", "Oppervlakte"),
+        ("conversion_volume", "Volume"),
+        ("conversion_mass", "Massa"),
+        ("conversion_force", "Kracht"),
+        ("conversion_pressure", "Druk en spanning"),
+        ("conversion_energy", "Energie"),
+        ("conversion_power", "Vermogen"),
+        ("conversion_torque", "Koppel"),
+        ("conversion_temperature", "Temperatuur"),
+        ("conversion_flow_rate", "Debiet"),
+        ("conversion_angle", "Hoek"),
+        // calculation errors
+        ("error_non_positive", "Moet groter dan nul zijn."),
+        ("error_out_of_domain", "Valt buiten het geldige bereik."),
+        ("error_inconsistent", "Past niet bij de andere afmetingen."),
+        ("error_not_finite", "Is geen geldig getal."),
+        ("error_unit_mismatch", "Heeft een eenheid van de verkeerde grootheid."),
         // other keys
         ("copy_to_clipboard", "Kopieer naar klembord"),
+        ("copy_value", "Waarde met eenheid"),
+        ("copy_table", "Tabel voor Excel"),
+        ("copy_markdown", "Markdown-tabel"),
+        ("copy_latex", "LaTeX-formule"),
+        ("copy_symbol", "Symbool"),
+        ("copy_number", "Waarde"),
+        ("copy_unit", "Eenheid"),
+        ("copy_done", "Gekopieerd naar klembord"),
+        ("copy_failed", "Kopiëren naar klembord mislukt"),
         ("card_direct_link", "Directe link naar deze kaart"),
+        ("card_copy_link", "Kopieer link"),
+        ("card_live_update", "Live bijwerken"),
+        ("history_title", "Geschiedenis"),
+        ("history_empty", "Nog geen berekeningen"),
+        ("history_name", "Naam"),
+        ("history_open", "Openen"),
+        ("history_pin", "Vastzetten"),
+        ("history_unpin", "Losmaken"),
+        ("history_delete", "Verwijderen"),
+        ("card_save_to_workspace", "Opslaan in werkruimte"),
+        ("drawing_download_svg", "SVG downloaden"),
+        ("drawing_download_png", "PNG downloaden"),
+        ("tolerance_hint", "Geef een waarde een tolerantie als 100 ± 0,2."),
+        ("tolerance_range", "Bereik"),
+        ("tolerance_uncertainty", "onzekerheid (RSS)"),
+        // rounding of results
+        ("rounding", "Afronding"),
+        ("rounding_decimals", "Decimalen"),
+        ("rounding_significant", "Significante cijfers"),
+        ("rounding_engineering", "Technische notatie"),
+        ("menu_workspaces", "Werkruimtes"),
+        ("workspaces_page_title", "Werkruimtes"),
+        ("workspaces_page_description", "Groepeer de berekeningen van een project met notities, en archiveer ze als bestand bij het project."),
+        ("workspace_default_name", "Project"),
+        ("workspace_none", "Nog geen werkruimtes."),
+        ("workspace_new", "Nieuwe werkruimte"),
+        ("workspace_select", "Werkruimte"),
+        ("workspace_name", "Naam"),
+        ("workspace_notes", "Notities"),
+        ("workspace_delete", "Werkruimte verwijderen"),
+        ("workspace_export", "Exporteren"),
+        ("workspace_import", "Importeren"),
+        ("workspace_empty", "Sla hier kaarten op met de knop \"Opslaan in werkruimte\" op een kaart."),
+        ("workspace_open", "Openen"),
+        ("workspace_card_note", "Notitie"),
+        ("workspace_remove_card", "Verwijderen"),
+        ("workspace_error_unreadable", "Het bestand kon niet als werkruimte worden gelezen."),
+        ("workspace_error_not_a_workspace", "Het bestand is geen geëxporteerde werkruimte."),
+        ("workspace_error_newer_version", "Het bestand is gemaakt met een nieuwere versie van deze site."),
+        ("workspace_report", "Rapport"),
+        ("report_title", "Rekenrapport"),
+        ("report_made", "gemaakt op"),
+        ("report_print", "Afdrukken"),
+        ("report_empty", "Het rapport bevat geen kaarten."),
+        ("report_inputs", "Invoer"),
+        ("report_calculation", "Berekening"),
+        ("report_results", "Resultaten"),
+        ("report_incomplete", "Niet alle invoer van deze kaart is ingevuld."),
+        ("solve_mode", "Bekende waarden"),
+        ("solve_from_dimensions", "Uit afmetingen"),
+        ("solve_rectangle_width", "Breedte uit oppervlakte en hoogte"),
+        ("solve_rectangle_height", "Hoogte uit oppervlakte en breedte"),
+        ("solve_circle_from_area", "Straal uit oppervlakte"),
+        ("solve_circle_from_circumference", "Straal uit omtrek"),
+        ("circle_circumference", "Omtrek"),
+        ("solve_triangle_sas", "Twee zijden en de ingesloten hoek"),
+        ("solve_triangle_asa", "Twee hoeken en de ingesloten zijde"),
+        ("solve_triangle_base_height", "Gelijkbenig uit basis en hoogte"),
+        ("triangle_angle_gamma", "Hoek tussen a en b"),
+        ("triangle_angles_alpha_beta", "Hoeken aan beide uiteinden van c"),
+        ("triangle_height", "Hoogte op c"),
+        ("solve_trapezoid_height", "Hoogte uit oppervlakte en evenwijdige zijden"),
+        ("solve_ellipse_semi_minor", "Halve korte as uit oppervlakte"),
+        ("solve_polygon_circumradius", "Zijdelengte uit omgeschreven straal"),
+        ("solve_polygon_apothem", "Zijdelengte uit apothema"),
+        ("polygon_circumradius", "Omgeschreven straal (middelpunt tot hoekpunt)"),
+        ("polygon_apothem", "Apothema (middelpunt tot midden van een zijde)"),
+        ("solve_parallelogram_side", "Zijde uit hoogte"),
+        ("solve_solid_height", "Hoogte uit volume"),
+        ("solve_solid_length", "Lengte uit volume"),
+        ("solve_solid_diameter", "Diameter uit volume"),
+        ("solve_sphere_from_surface_area", "Diameter uit oppervlakte"),
         // footer
         ("copyright", "Auteursrecht 2025 engineertools.nl"),
         // ...add more keys
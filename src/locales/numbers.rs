@@ -0,0 +1,455 @@
+use serde::{Deserialize, Serialize};
+
+/// Magnitudes from which numbers are written in scientific notation.
+const SCIENTIFIC_BELOW: f64 = 1e-4;
+const SCIENTIFIC_FROM: f64 = 1e9;
+
+/// Most digits a rounding policy keeps, beyond which `f64` has no more to show.
+const MAX_DIGITS: usize = 15;
+
+/// How results are rounded, chosen by the user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Rounding {
+    /// A fixed number of decimals, trailing zeros included.
+    Decimals(usize),
+    /// A number of significant figures, in scientific notation when very large or small.
+    SignificantFigures(usize),
+    /// A number of significant figures with an exponent that is a multiple of three.
+    Engineering(usize),
+}
+
+impl Default for Rounding {
+    fn default() -> Self {
+        Rounding::SignificantFigures(4)
+    }
+}
+
+impl Rounding {
+    pub const IDS: [&'static str; 3] = ["decimals", "significant", "engineering"];
+
+    pub fn id(&self) -> &'static str {
+        match self {
+            Rounding::Decimals(_) => "decimals",
+            Rounding::SignificantFigures(_) => "significant",
+            Rounding::Engineering(_) => "engineering",
+        }
+    }
+
+    /// The policy with the given id, keeping the number of digits.
+    pub fn with_id(self, id: &str) -> Self {
+        let digits = self.digits();
+        match id {
+            "decimals" => Rounding::Decimals(digits),
+            "engineering" => Rounding::Engineering(digits),
+            _ => Rounding::SignificantFigures(digits),
+        }
+    }
+
+    pub fn digits(&self) -> usize {
+        match self {
+            Rounding::Decimals(digits)
+            | Rounding::SignificantFigures(digits)
+            | Rounding::Engineering(digits) => *digits,
+        }
+    }
+
+    /// The policy keeping `digits` decimals or figures, at least one figure and no
+    /// more than `f64` holds.
+    pub fn with_digits(self, digits: usize) -> Self {
+        match self {
+            Rounding::Decimals(_) => Rounding::Decimals(digits.min(MAX_DIGITS)),
+            Rounding::SignificantFigures(_) => {
+                Rounding::SignificantFigures(digits.clamp(1, MAX_DIGITS))
+            }
+            Rounding::Engineering(_) => Rounding::Engineering(digits.clamp(1, MAX_DIGITS)),
+        }
+    }
+
+    /// The policy keeping at least the given significant figures; decimals stay as
+    /// they are.
+    pub fn at_least(self, significant_figures: usize) -> Self {
+        match self {
+            Rounding::Decimals(_) => self,
+            _ => self.with_digits(self.digits().max(significant_figures)),
+        }
+    }
+}
+
+/// How numbers are read from inputs and written in results for a language.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumberFormat {
+    pub decimal_separator: char,
+    pub grouping_separator: char,
+    pub rounding: Rounding,
+}
+
+impl NumberFormat {
+    pub fn new(lang: &str) -> Self {
+        let (decimal_separator, grouping_separator) = match lang {
+            "nl" => (',', '.'),
+            _ => ('.', ','),
+        };
+        Self {
+            decimal_separator,
+            grouping_separator,
+            rounding: Rounding::default(),
+        }
+    }
+
+    pub fn with_rounding(self, rounding: Rounding) -> Self {
+        Self {
+            rounding: rounding.with_digits(rounding.digits()),
+            ..self
+        }
+    }
+
+    /// Reads a number as typed by the user.
+    ///
+    /// Grouping separators are accepted between groups of three digits. A single
+    /// grouping separator that is not followed by three digits is taken as the
+    /// decimal separator, so `2.5` also reads as two and a half in Dutch.
+    /// Scientific notation such as `1,5e3` is accepted as well.
+    pub fn parse(&self, text: &str) -> Option<f64> {
+        let text: String = text
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '\u{202f}')
+            .collect();
+        let (mantissa, exponent) = match text.find(['e', 'E']) {
+            Some(index) => (&text[..index], Some(&text[index + 1..])),
+            None => (text.as_str(), None),
+        };
+        let (sign, mantissa) = match mantissa.strip_prefix(['-', '+']) {
+            Some(rest) => (&mantissa[..1], rest),
+            None => ("", mantissa),
+        };
+
+        let (integer, fraction) = self.split_mantissa(mantissa)?;
+        if integer.is_empty() && fraction.is_empty()
+            || !integer
+                .chars()
+                .chain(fraction.chars())
+                .all(|c| c.is_ascii_digit())
+        {
+            return None;
+        }
+        let mut normalized = format!("{sign}{integer}.{fraction}");
+        if let Some(exponent) = exponent {
+            exponent.parse::<i32>().ok()?;
+            normalized.push('e');
+            normalized.push_str(exponent);
+        }
+        normalized.parse::<f64>().ok()
+    }
+
+    /// Reads a number followed by an optional tolerance after `±` or `+-`, like
+    /// `100 ± 0,2`; without one the tolerance is zero.
+    pub fn parse_toleranced(&self, text: &str) -> Option<(f64, f64)> {
+        let Some((nominal, tolerance)) = text.split_once('±').or_else(|| text.split_once("+-"))
+        else {
+            return Some((self.parse(text)?, 0.0));
+        };
+        let tolerance = self
+            .parse(tolerance)
+            .filter(|tolerance| *tolerance >= 0.0)?;
+        Some((self.parse(nominal)?, tolerance))
+    }
+
+    /// Splits a mantissa without sign into its integer digits and fraction digits.
+    fn split_mantissa(&self, mantissa: &str) -> Option<(String, String)> {
+        let (integer, fraction) = match mantissa.split_once(self.decimal_separator) {
+            Some((integer, fraction)) => (integer, fraction),
+            None => {
+                let groups: Vec<&str> = mantissa.split(self.grouping_separator).collect();
+                match groups.as_slice() {
+                    [integer, fraction] if fraction.len() != 3 => (*integer, *fraction),
+                    _ => (mantissa, ""),
+                }
+            }
+        };
+        let groups: Vec<&str> = integer.split(self.grouping_separator).collect();
+        let grouped_correctly = groups.len() == 1
+            || (!groups[0].is_empty()
+                && groups[0].len() <= 3
+                && groups[1..].iter().all(|group| group.len() == 3));
+        grouped_correctly.then(|| (groups.concat(), fraction.to_string()))
+    }
+
+    /// Writes a result rounded by the rounding policy, with grouped thousands.
+    pub fn format(&self, value: f64) -> String {
+        self.write(value, Some(self.grouping_separator), "e")
+    }
+
+    /// Writes a number without grouping, for pasting into spreadsheets.
+    pub fn format_plain(&self, value: f64) -> String {
+        self.write(value, None, "E")
+    }
+
+    /// Writes a number in full precision without grouping, so it reads back as the
+    /// same value, for restoring an input.
+    pub fn format_exact(&self, value: f64) -> String {
+        value
+            .to_string()
+            .replace('.', &self.decimal_separator.to_string())
+    }
+
+    /// Writes a number and its tolerance like [`NumberFormat::format_exact`], leaving
+    /// out a tolerance of zero.
+    pub fn format_toleranced(&self, nominal: f64, tolerance: f64) -> String {
+        if tolerance == 0.0 {
+            return self.format_exact(nominal);
+        }
+        format!(
+            "{} ± {}",
+            self.format_exact(nominal),
+            self.format_exact(tolerance)
+        )
+    }
+
+    /// Writes a number for a LaTeX formula, where a comma needs braces to keep
+    /// it from being spaced as punctuation.
+    pub fn format_latex(&self, value: f64) -> String {
+        let brace = |c: char| match c {
+            ',' => "{,}".to_string(),
+            c => c.to_string(),
+        };
+        match self.scientific_parts(value) {
+            Some((mantissa, exponent)) => format!(
+                r"{} \times 10^{{{}}}",
+                self.write(mantissa, None, "e")
+                    .chars()
+                    .map(brace)
+                    .collect::<String>(),
+                exponent
+            ),
+            None => self
+                .write(value, Some(self.grouping_separator), "e")
+                .chars()
+                .map(brace)
+                .collect(),
+        }
+    }
+
+    /// Mantissa and exponent of values written with an exponent: those too large or
+    /// small to write out in full, or in engineering notation all but units.
+    fn scientific_parts(&self, value: f64) -> Option<(f64, i32)> {
+        let magnitude = value.abs();
+        if !value.is_finite() || magnitude == 0.0 {
+            return None;
+        }
+        let rounded = self.round(magnitude);
+        if rounded == 0.0 {
+            return None;
+        }
+        let exponent = rounded.log10().floor() as i32;
+        let exponent = match self.rounding {
+            Rounding::Decimals(_) if rounded < SCIENTIFIC_FROM => return None,
+            Rounding::Decimals(_) | Rounding::SignificantFigures(_)
+                if (SCIENTIFIC_BELOW..SCIENTIFIC_FROM).contains(&rounded) =>
+            {
+                return None
+            }
+            Rounding::Engineering(_) => match exponent.div_euclid(3) * 3 {
+                0 => return None,
+                exponent => exponent,
+            },
+            _ => exponent,
+        };
+        Some((value / 10f64.powi(exponent), exponent))
+    }
+
+    /// Rounds a value by the rounding policy.
+    fn round(&self, value: f64) -> f64 {
+        if !value.is_finite() || value == 0.0 {
+            return value;
+        }
+        let power = match self.rounding {
+            Rounding::Decimals(decimals) => decimals as i32,
+            Rounding::SignificantFigures(figures) | Rounding::Engineering(figures) => {
+                figures as i32 - 1 - value.abs().log10().floor() as i32
+            }
+        };
+        // Scale by an exact power of ten in both directions to avoid stray digits
+        if power >= 0 {
+            let factor = 10f64.powi(power);
+            // Values this large have no decimals left to round
+            let scaled = value * factor;
+            if !scaled.is_finite() {
+                return value;
+            }
+            scaled.round() / factor
+        } else {
+            let factor = 10f64.powi(-power);
+            (value / factor).round() * factor
+        }
+    }
+
+    fn write(&self, value: f64, grouping: Option<char>, exponent_marker: &str) -> String {
+        if !value.is_finite() {
+            return value.to_string();
+        }
+        if let Some((mantissa, exponent)) = self.scientific_parts(value) {
+            return format!(
+                "{}{exponent_marker}{exponent}",
+                self.write(mantissa, None, exponent_marker)
+            );
+        }
+
+        let rounded = self.round(value);
+        let exponent = if rounded == 0.0 {
+            0
+        } else {
+            rounded.abs().log10().floor() as i32
+        };
+        let decimals = match self.rounding {
+            Rounding::Decimals(decimals) => decimals,
+            Rounding::SignificantFigures(figures) | Rounding::Engineering(figures) => {
+                (figures as i32 - 1 - exponent).max(0) as usize
+            }
+        };
+        let fixed = format!("{:.*}", decimals, rounded.abs());
+        let (integer, fraction) = fixed.split_once('.').unwrap_or((&fixed, ""));
+        let fraction = match self.rounding {
+            Rounding::Decimals(_) => fraction,
+            _ => fraction.trim_end_matches('0'),
+        };
+
+        let mut text = String::new();
+        if rounded < 0.0 {
+            text.push('-');
+        }
+        for (index, digit) in integer.chars().enumerate() {
+            let remaining = integer.len() - index;
+            if index > 0 && remaining % 3 == 0 {
+                if let Some(separator) = grouping {
+                    text.push(separator);
+                }
+            }
+            text.push(digit);
+        }
+        if !fraction.is_empty() {
+            text.push(self.decimal_separator);
+            text.push_str(fraction);
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let en = NumberFormat::new("en");
+        let nl = NumberFormat::new("nl");
+        assert_eq!(en.parse(" 2.5 "), Some(2.5));
+        assert_eq!(en.parse("1,234.5"), Some(1234.5));
+        assert_eq!(en.parse("1,000"), Some(1000.0));
+        assert_eq!(en.parse("-1.5e3"), Some(-1500.0));
+        assert_eq!(nl.parse("2,5"), Some(2.5));
+        assert_eq!(nl.parse("2.5"), Some(2.5));
+        assert_eq!(nl.parse("1.234,5"), Some(1234.5));
+        assert_eq!(nl.parse("1.000"), Some(1000.0));
+        assert_eq!(nl.parse("1 000 000"), Some(1e6));
+        assert_eq!(nl.parse("1,5E-3"), Some(0.0015));
+        assert_eq!(nl.parse(",5"), Some(0.5));
+        assert_eq!(nl.parse("12.34.56"), None);
+        assert_eq!(nl.parse("1,5e"), None);
+        assert_eq!(nl.parse("abc"), None);
+        assert_eq!(nl.parse(""), None);
+    }
+
+    #[test]
+    fn test_parse_toleranced() {
+        let nl = NumberFormat::new("nl");
+        assert_eq!(nl.parse_toleranced("100 ± 0,2"), Some((100.0, 0.2)));
+        assert_eq!(nl.parse_toleranced("100+-0,2"), Some((100.0, 0.2)));
+        assert_eq!(nl.parse_toleranced("-1,5"), Some((-1.5, 0.0)));
+        assert_eq!(nl.parse_toleranced("100 ± -0,2"), None);
+        assert_eq!(nl.parse_toleranced("100 ±"), None);
+        assert_eq!(nl.format_toleranced(100.0, 0.2), "100 ± 0,2");
+        assert_eq!(nl.format_toleranced(100.0, 0.0), "100");
+    }
+
+    #[test]
+    fn test_format() {
+        let en = NumberFormat::new("en");
+        let nl = NumberFormat::new("nl");
+        assert_eq!(en.format(1.23456), "1.235");
+        assert_eq!(nl.format(1.23456), "1,235");
+        assert_eq!(nl.format(12345.678), "12.350");
+        assert_eq!(en.format(-1234567.0), "-1,235,000");
+        assert_eq!(nl.format(2.0), "2");
+        assert_eq!(nl.format(0.0), "0");
+        assert_eq!(nl.format(0.000012345), "1,235e-5");
+        assert_eq!(en.format(1.5e12), "1.5e12");
+        assert_eq!(en.format(999_999_999.0), "1e9");
+        assert_eq!(nl.format_plain(12345.678), "12350");
+        assert_eq!(nl.format_plain(1.5e12), "1,5E12");
+        assert_eq!(
+            nl.with_rounding(Rounding::SignificantFigures(6))
+                .format(1.23456789),
+            "1,23457"
+        );
+    }
+
+    #[test]
+    fn test_rounding() {
+        let en = NumberFormat::new("en");
+        let decimals = en.with_rounding(Rounding::Decimals(2));
+        assert_eq!(decimals.format(1.5), "1.50");
+        assert_eq!(decimals.format(1234.5678), "1,234.57");
+        assert_eq!(decimals.format(0.0001), "0.00");
+        assert_eq!(decimals.format(-0.001), "0.00");
+        assert_eq!(decimals.format(2.5e12), "2.50e12");
+        assert_eq!(decimals.format(1e307), "1.00e307");
+        assert_eq!(en.with_rounding(Rounding::Decimals(0)).format(2.5), "3");
+
+        let engineering = en.with_rounding(Rounding::Engineering(4));
+        assert_eq!(engineering.format(12345.0), "12.35e3");
+        assert_eq!(engineering.format(999.96), "1e3");
+        assert_eq!(engineering.format(0.0012345), "1.235e-3");
+        assert_eq!(engineering.format(-123.456), "-123.5");
+        assert_eq!(engineering.format_plain(4.5e7), "45E6");
+        assert_eq!(engineering.format_latex(4.5e7), r"45 \times 10^{6}");
+
+        let significant = en.with_rounding(Rounding::SignificantFigures(2));
+        assert_eq!(significant.format(1234.5), "1,200");
+        assert_eq!(
+            en.with_rounding(Rounding::SignificantFigures(0))
+                .format(7.7),
+            "8"
+        );
+
+        assert_eq!(
+            Rounding::Decimals(2).with_id("engineering"),
+            Rounding::Engineering(2)
+        );
+        assert_eq!(
+            Rounding::Engineering(3).at_least(7),
+            Rounding::Engineering(7)
+        );
+        assert_eq!(Rounding::Decimals(2).at_least(7), Rounding::Decimals(2));
+    }
+
+    #[test]
+    fn test_format_latex() {
+        let en = NumberFormat::new("en");
+        let nl = NumberFormat::new("nl");
+        assert_eq!(nl.format_latex(1234.5), "1.235");
+        assert_eq!(nl.format_latex(2.5), "2{,}5");
+        assert_eq!(en.format_latex(12345.0), "12{,}350");
+        assert_eq!(nl.format_latex(1.5e12), r"1{,}5 \times 10^{12}");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let nl = NumberFormat::new("nl").with_rounding(Rounding::SignificantFigures(6));
+        for value in [0.5, 2.25, 1234.5, 1e-6, 3.5e10] {
+            assert_eq!(nl.parse(&nl.format(value)), Some(value));
+        }
+        for value in [0.1 + 0.2, 1234.5678, -1e-7, 1e21] {
+            assert_eq!(nl.parse(&nl.format_exact(value)), Some(value));
+        }
+    }
+}
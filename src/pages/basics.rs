@@ -1,5 +1,11 @@
 use crate::{
-    cards::area::{CircleCard, RectangleCard},
+    cards::area::{
+        CircleCard, EllipseCard, ParallelogramCard, RectangleCard, RegularPolygonCard,
+        TrapezoidCard, TriangleCard,
+    },
+    cards::composite::CompositeSectionCard,
+    cards::section::SectionPropertiesCard,
+    cards::volume::SolidCard,
     locales::i18n::I18n,
 };
 use leptos::prelude::*;
@@ -24,6 +30,14 @@ pub fn BasicsPage() -> impl IntoView {
             <BasicsContainer>
                 <CircleCard />
                 <RectangleCard />
+                <TriangleCard />
+                <TrapezoidCard />
+                <ParallelogramCard />
+                <EllipseCard />
+                <RegularPolygonCard />
+                <SectionPropertiesCard />
+                <CompositeSectionCard />
+                <SolidCard />
             </BasicsContainer>
         </div>
     }
@@ -0,0 +1,38 @@
+use crate::{
+    cards::conversion::{
+        AngleConversionCard, AreaConversionCard, EnergyConversionCard, FlowRateConversionCard,
+        ForceConversionCard, LengthConversionCard, MassConversionCard, PowerConversionCard,
+        PressureConversionCard, TemperatureConversionCard, TorqueConversionCard,
+        VolumeConversionCard,
+    },
+    locales::i18n::I18n,
+    pages::basics::BasicsContainer,
+};
+use leptos::prelude::*;
+
+#[allow(non_snake_case)]
+#[component]
+pub fn ConversionsPage() -> impl IntoView {
+    let i18n = use_context::<Memo<I18n>>().expect("I18n context not found");
+
+    view! {
+        <div id="conversions-page">
+            <h1>{move || i18n.get().t("conversions_page_title").to_string()}</h1>
+            <p>{move || i18n.get().t("conversions_page_description").to_string()}</p>
+            <BasicsContainer>
+                <LengthConversionCard />
+                <AreaConversionCard />
+                <VolumeConversionCard />
+                <MassConversionCard />
+                <ForceConversionCard />
+                <PressureConversionCard />
+                <EnergyConversionCard />
+                <PowerConversionCard />
+                <TorqueConversionCard />
+                <TemperatureConversionCard />
+                <FlowRateConversionCard />
+                <AngleConversionCard />
+            </BasicsContainer>
+        </div>
+    }
+}
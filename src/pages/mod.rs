@@ -1,2 +1,5 @@
 pub mod basics;
+pub mod conversions;
 pub mod home;
+pub mod report;
+pub mod workspaces;
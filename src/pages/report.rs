@@ -0,0 +1,227 @@
+//! Printable calculation sheet of the cards of a workspace, rendered on the server
+//! from its query so it can be printed to PDF.
+//!
+//! The query holds `project`, `notes` and `made`, and for the card at index `i` its id
+//! under `i`, its note under `i.note` and its state under `i.<key>`.
+
+use crate::cards::calculator::{find_card, CalculatorGroup, SavedCalculation};
+use crate::cards::history::HistoryValue;
+use crate::cards::mathml::latex_to_mathml;
+use crate::cards::workspace::Workspace;
+use crate::locales::i18n::I18n;
+use leptos::prelude::*;
+use leptos_router::hooks::use_query_map;
+use leptos_router::location::Url;
+use std::collections::BTreeMap;
+
+/// A card of a report, in the state it was saved in.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReportCard {
+    pub card: String,
+    pub note: String,
+    pub state: Vec<(String, String)>,
+}
+
+/// Query of the report on a workspace, made at the time in `made`.
+pub fn report_query(workspace: &Workspace, made: &str) -> Vec<(String, String)> {
+    let mut query = vec![
+        ("project".to_string(), workspace.name.clone()),
+        ("notes".to_string(), workspace.notes.clone()),
+        ("made".to_string(), made.to_string()),
+    ];
+    for (index, card) in workspace.cards.iter().enumerate() {
+        query.push((index.to_string(), card.card.clone()));
+        if !card.note.is_empty() {
+            query.push((format!("{index}.note"), card.note.clone()));
+        }
+        for (key, value) in &card.state {
+            query.push((format!("{index}.{key}"), value.clone()));
+        }
+    }
+    query
+}
+
+/// Link to the report on a workspace.
+pub fn report_link(workspace: &Workspace, made: &str) -> String {
+    let query: Vec<String> = report_query(workspace, made)
+        .iter()
+        .map(|(key, value)| format!("{}={}", Url::escape(key), Url::escape(value)))
+        .collect();
+    format!("/report?{}", query.join("&"))
+}
+
+/// The cards in the query of a report, in their order.
+pub fn report_cards(query: impl IntoIterator<Item = (String, String)>) -> Vec<ReportCard> {
+    let mut cards: BTreeMap<usize, ReportCard> = BTreeMap::new();
+    for (key, value) in query {
+        let (index, key) = match key.split_once('.') {
+            Some((index, key)) => (index, Some(key)),
+            None => (key.as_str(), None),
+        };
+        let Ok(index) = index.parse() else {
+            continue;
+        };
+        let card = cards.entry(index).or_default();
+        match key {
+            None => card.card = value,
+            Some("note") => card.note = value,
+            Some(key) => card.state.push((key.to_string(), value)),
+        }
+    }
+    cards
+        .into_values()
+        .filter(|card| !card.card.is_empty())
+        .collect()
+}
+
+#[allow(non_snake_case)]
+#[component]
+pub fn ReportPage() -> impl IntoView {
+    let i18n = use_context::<Memo<I18n>>().expect("I18n context not found");
+    let query = use_query_map();
+
+    let text = move |key: &str| query.get().get(key).unwrap_or_default();
+    let cards = Memo::new(move |_| {
+        report_cards(
+            query
+                .get()
+                .into_iter()
+                .map(|(key, value)| (key.into_owned(), value)),
+        )
+    });
+    let print = move |_| {
+        if let Some(window) = web_sys::window() {
+            let _ = window.print();
+        }
+    };
+
+    view! {
+        <div id="report-page">
+            <header class="report__header">
+                <h1>{move || text("project")}</h1>
+                <p>
+                    {move || i18n.get().t("report_title").to_string()}" · "
+                    {move || i18n.get().t("report_made").to_string()}" "{move || text("made")}
+                </p>
+                <p class="report__notes">{move || text("notes")}</p>
+                <button class="report__print" on:click=print>
+                    {move || i18n.get().t("report_print").to_string()}
+                </button>
+            </header>
+            <Show when=move || cards.get().is_empty()>
+                <p>{move || i18n.get().t("report_empty").to_string()}</p>
+            </Show>
+            {move || {
+                cards
+                    .get()
+                    .into_iter()
+                    .filter_map(|card| Some((find_card(&card.card)?, card)))
+                    .map(|(group, card)| view! { <ReportSection group=group card=card /> })
+                    .collect_view()
+            }}
+        </div>
+    }
+}
+
+/// One card of a report: its inputs, drawing, formula and results.
+#[allow(non_snake_case)]
+#[component]
+fn ReportSection(group: CalculatorGroup, card: ReportCard) -> impl IntoView {
+    let i18n = use_context::<Memo<I18n>>().expect("I18n context not found");
+    let numbers = Memo::new(move |_| i18n.get().number_format());
+
+    let note = (!card.note.is_empty()).then(|| view! { <p class="report__note">{card.note}</p> });
+    let calculation = StoredValue::new(SavedCalculation::read(group, &card.state));
+    let calculator = calculation.with_value(|calculation| calculation.calculator);
+
+    let formula = move || {
+        calculation
+            .with_value(|calculation| calculation.formula(numbers.get()))
+            .map(|formula| latex_to_mathml(&formula))
+    };
+
+    let values_table = move |values: Vec<HistoryValue>| {
+        let rows = values
+            .into_iter()
+            .map(|value| {
+                view! {
+                    <tr>
+                        <td>{value.symbol}</td>
+                        <td>{move || numbers.get().format(value.value)}</td>
+                        <td>{value.unit}</td>
+                    </tr>
+                }
+            })
+            .collect_view();
+        view! { <table class="report__values">{rows}</table> }
+    };
+    let variables = calculation.with_value(|calculation| {
+        let mode = calculation.solution().and_then(|solution| solution.mode);
+        calculator
+            .variables
+            .iter()
+            .chain(mode.map_or(&[][..], |index| calculator.solve_modes[index].variables))
+            .map(|(symbols, key)| {
+                view! { <p>{*symbols}" = "{move || i18n.get().t(key).to_string()}</p> }
+            })
+            .collect_view()
+    });
+    let problem = calculation.with_value(|calculation| match &calculation.outcome {
+        None => Some("report_incomplete"),
+        Some(Err(err)) => Some(err.i18n_key()),
+        Some(Ok(_)) => None,
+    });
+
+    view! {
+        <section class="report__card">
+            <h2>{move || i18n.get().t(calculator.title_key).to_string()}</h2>
+            {note}
+            <div class="report__variables">{variables}</div>
+            {problem
+                .map(|key| {
+                    view! { <p class="report__problem">{move || i18n.get().t(key).to_string()}</p> }
+                })}
+            <h3>{move || i18n.get().t("report_inputs").to_string()}</h3>
+            {values_table(calculation.with_value(SavedCalculation::inputs))}
+            <div
+                class="report__drawing"
+                inner_html=move || calculation.with_value(|calculation| calculation.drawing(numbers.get()))
+            />
+            <h3>{move || i18n.get().t("report_calculation").to_string()}</h3>
+            <div class="report__formula" inner_html=formula></div>
+            <h3>{move || i18n.get().t("report_results").to_string()}</h3>
+            {values_table(calculation.with_value(SavedCalculation::results))}
+        </section>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cards::workspace::SavedCard;
+
+    #[test]
+    fn test_report_query() {
+        let mut workspace = Workspace::new(0, "Hall");
+        for (card, note) in [("rectangle", "Floor"), ("circle", "")] {
+            workspace.add_card(SavedCard {
+                id: 0,
+                card: card.to_string(),
+                title_key: String::new(),
+                path: String::new(),
+                state: vec![("w".to_string(), "2".to_string())],
+                note: note.to_string(),
+                saved_at: 0.0,
+            });
+        }
+        let query = report_query(&workspace, "2024-05-01 14:30");
+        assert!(query.contains(&("0.note".to_string(), "Floor".to_string())));
+
+        let cards = report_cards(query);
+        assert_eq!(cards.len(), 2);
+        assert_eq!(cards[0].card, "rectangle");
+        assert_eq!(cards[0].note, "Floor");
+        assert_eq!(cards[1].card, "circle");
+        assert_eq!(cards[1].state, [("w".to_string(), "2".to_string())]);
+    }
+}
@@ -0,0 +1,186 @@
+use crate::cards::history::format_time;
+use crate::cards::workspace::{
+    export_workspace, import_workspace, SavedCard, Workspace, WorkspaceStore,
+};
+use crate::dom::files::{download_text, read_chosen_file};
+use crate::locales::i18n::I18n;
+use crate::pages::report::report_link;
+use leptos::prelude::*;
+
+#[allow(non_snake_case)]
+#[component]
+pub fn WorkspacesPage() -> impl IntoView {
+    let i18n = use_context::<Memo<I18n>>().expect("I18n context not found");
+    let store = use_context::<WorkspaceStore>().expect("Workspace context not found");
+
+    // Key of the message of the last failed import
+    let import_error = RwSignal::new(None::<&'static str>);
+
+    let active = Memo::new(move |_| store.get().active().cloned());
+
+    let new_workspace = move |_| {
+        let name = i18n.get_untracked().t("workspace_default_name").to_string();
+        store.update(|workspaces| {
+            workspaces.add(Workspace::new(0, &name));
+        });
+    };
+    let export = move |_| {
+        if let Some(workspace) = active.get_untracked() {
+            let file_name = format!("{}.json", workspace.name);
+            download_text(
+                &file_name,
+                "application/json",
+                &export_workspace(&workspace),
+            );
+        }
+    };
+    // The report opens in a new tab, to be printed from there
+    let report = move |_| {
+        let Some(workspace) = active.get_untracked() else {
+            return;
+        };
+        let link = report_link(&workspace, &format_time(js_sys::Date::now()));
+        if let Some(window) = web_sys::window() {
+            let _ = window.open_with_url_and_target(&link, "_blank");
+        }
+    };
+    let import = move |ev: leptos::ev::Event| {
+        read_chosen_file(&ev, move |text| match import_workspace(&text) {
+            Ok(workspace) => {
+                import_error.set(None);
+                store.update(|workspaces| {
+                    workspaces.add(workspace);
+                });
+            }
+            Err(e) => import_error.set(Some(e.i18n_key())),
+        });
+    };
+
+    let card_view = move |card: SavedCard| {
+        let id = card.id;
+        let link = card.link();
+        view! {
+            <li class="workspace__card">
+                <a href=link>{move || i18n.get().t(&card.title_key).to_string()}</a>
+                <input
+                    type="text"
+                    aria-label=move || i18n.get().t("workspace_card_note").to_string()
+                    placeholder=move || i18n.get().t("workspace_card_note").to_string()
+                    prop:value=card.note.clone()
+                    on:change=move |ev| {
+                        let note = event_target_value(&ev);
+                        store.update(|workspaces| workspaces.edit_active(|w| w.set_card_note(id, &note)));
+                    }
+                />
+                <button on:click=move |_| {
+                    store.update(|workspaces| workspaces.edit_active(|w| w.remove_card(id)))
+                }>{move || i18n.get().t("workspace_remove_card").to_string()}</button>
+            </li>
+        }
+    };
+
+    view! {
+        <div id="workspaces-page">
+            <h1>{move || i18n.get().t("workspaces_page_title").to_string()}</h1>
+            <p>{move || i18n.get().t("workspaces_page_description").to_string()}</p>
+            <div class="workspace__toolbar">
+                <select
+                    aria-label=move || i18n.get().t("workspace_select").to_string()
+                    on:change=move |ev| {
+                        if let Ok(id) = event_target_value(&ev).parse() {
+                            store.update(|workspaces| workspaces.select(id));
+                        }
+                    }
+                >
+                    {move || {
+                        let workspaces = store.get();
+                        workspaces
+                            .workspaces
+                            .into_iter()
+                            .map(|workspace| {
+                                let selected = Some(workspace.id) == workspaces.active;
+                                view! {
+                                    <option value=workspace.id.to_string() selected=selected>
+                                        {workspace.name}
+                                    </option>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                </select>
+                <button on:click=new_workspace>
+                    {move || i18n.get().t("workspace_new").to_string()}
+                </button>
+                <label class="workspace__import">
+                    {move || i18n.get().t("workspace_import").to_string()}
+                    <input type="file" accept=".json,application/json" on:change=import />
+                </label>
+            </div>
+            {move || {
+                import_error
+                    .get()
+                    .map(|key| {
+                        view! { <p class="workspace__error">{i18n.get().t(key).to_string()}</p> }
+                    })
+            }}
+            <Show
+                when=move || active.get().is_some()
+                fallback=move || view! { <p>{move || i18n.get().t("workspace_none").to_string()}</p> }
+            >
+                <div class="workspace">
+                    <label>
+                        {move || i18n.get().t("workspace_name").to_string()}
+                        <input
+                            type="text"
+                            prop:value=move || active.get().map(|w| w.name).unwrap_or_default()
+                            on:change=move |ev| {
+                                let name = event_target_value(&ev);
+                                store.update(|workspaces| workspaces.edit_active(|w| w.name = name));
+                            }
+                        />
+                    </label>
+                    <label>
+                        {move || i18n.get().t("workspace_notes").to_string()}
+                        <textarea
+                            rows="4"
+                            prop:value=move || active.get().map(|w| w.notes).unwrap_or_default()
+                            on:change=move |ev| {
+                                let notes = event_target_value(&ev);
+                                store.update(|workspaces| workspaces.edit_active(|w| w.notes = notes));
+                            }
+                        ></textarea>
+                    </label>
+                    <Show when=move || active.get().is_some_and(|w| w.cards.is_empty())>
+                        <p>{move || i18n.get().t("workspace_empty").to_string()}</p>
+                    </Show>
+                    <ul class="workspace__cards">
+                        // Changed cards are keyed anew, so their view shows the change
+                        <For
+                            each=move || active.get().map(|w| w.cards).unwrap_or_default()
+                            key=|card| (card.id, card.note.clone())
+                            children=card_view
+                        />
+                    </ul>
+                    <div class="workspace__actions">
+                        <button on:click=report>
+                            {move || i18n.get().t("workspace_report").to_string()}
+                        </button>
+                        <button on:click=export>
+                            {move || i18n.get().t("workspace_export").to_string()}
+                        </button>
+                        <button
+                            class="workspace__delete"
+                            on:click=move |_| {
+                                if let Some(workspace) = active.get_untracked() {
+                                    store.update(|workspaces| workspaces.delete(workspace.id));
+                                }
+                            }
+                        >
+                            {move || i18n.get().t("workspace_delete").to_string()}
+                        </button>
+                    </div>
+                </div>
+            </Show>
+        </div>
+    }
+}
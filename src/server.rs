@@ -6,6 +6,8 @@ use leptos_wasi::{
 use wasi::exports::http::incoming_handler::Guest;
 use wasi::http::proxy::export;
 
+use crate::api::functions::{CalculateArea, CalculateComposite, CalculateSection, CalculateSolid};
+use crate::api::{openapi, rest};
 use crate::app::{shell, App};
 
 struct LeptosServer;
@@ -33,12 +35,25 @@ async fn handle_request(
 ) -> Result<(), HandlerError> {
     use leptos_wasi::prelude::Handler;
 
+    // The REST API is plain JSON, answered before Leptos sees the request
+    let path = request.path_with_query().unwrap_or_default();
+    let path = path.split('?').next().unwrap_or_default();
+    if path == openapi::PATH {
+        return rest::respond_json(response_out, 200, &openapi::document());
+    }
+    if let Some(id) = path.strip_prefix(rest::PREFIX) {
+        return rest::handle(id, request, response_out);
+    }
+
     let conf = get_configuration(None).unwrap();
     let leptos_options = conf.leptos_options;
 
     Handler::build(request, response_out)?
         // NOTE: Add all server functions here to ensure functionality works as expected!
-        // .with_server_fn::<CalculateSum>()
+        .with_server_fn::<CalculateArea>()
+        .with_server_fn::<CalculateSection>()
+        .with_server_fn::<CalculateSolid>()
+        .with_server_fn::<CalculateComposite>()
         // Fetch all available routes from your App.
         .generate_routes(App)
         // Actually process the request and write the response.